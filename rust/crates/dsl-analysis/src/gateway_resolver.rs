@@ -123,6 +123,7 @@ impl GatewayRefResolver {
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let response = self
@@ -144,6 +145,7 @@ impl GatewayRefResolver {
                 discriminators: std::collections::HashMap::new(),
                 tenant_id: None,
                 cbu_id: None,
+                additional_nicknames: vec![],
             };
 
             let fuzzy_response = self
@@ -235,6 +237,7 @@ impl GatewayRefResolver {
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let response = self
@@ -321,6 +324,7 @@ impl GatewayRefResolver {
             discriminators: discriminators.clone(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let response = self
@@ -342,6 +346,7 @@ impl GatewayRefResolver {
                 discriminators,
                 tenant_id: None,
                 cbu_id: None,
+                additional_nicknames: vec![],
             };
 
             let fuzzy_response = self
@@ -450,6 +455,7 @@ impl GatewayRefResolver {
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let response = self
@@ -507,6 +513,7 @@ impl GatewayRefResolver {
             discriminators: all_discriminators,
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let response = self
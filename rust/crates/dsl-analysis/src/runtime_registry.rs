@@ -60,6 +60,8 @@ pub struct RuntimeVerb {
     /// Phase tags from YAML metadata (e.g., ["kyc"], ["trading"], ["onboarding"]).
     /// Used by planning_facade to group steps into phases for display and code actions.
     pub phase_tags: Vec<String>,
+    /// Natural-language phrases that invoke this verb (from YAML)
+    pub invocation_phrases: Vec<String>,
 }
 
 /// Runtime policy configuration (built from YAML)
@@ -412,6 +414,7 @@ impl RuntimeVerbRegistry {
                 lifecycle: None,
                 policy: None,
                 phase_tags: vec![],
+                invocation_phrases: vec![],
             };
 
             self.verbs.insert(full_name.clone(), runtime_verb);
@@ -579,6 +582,7 @@ impl RuntimeVerbRegistry {
                 .as_ref()
                 .map(|meta| meta.phase_tags.clone())
                 .unwrap_or_default(),
+            invocation_phrases: config.invocation_phrases.clone(),
         }
     }
 
@@ -64,6 +64,8 @@ pub struct UnifiedVerbDef {
     pub custom_op_id: Option<String>,
     pub produces: Option<VerbProduces>,
     pub consumes: Vec<VerbConsumes>,
+    /// Natural-language phrases that invoke this verb
+    pub invocation_phrases: Vec<String>,
 }
 
 impl UnifiedVerbDef {
@@ -146,7 +148,7 @@ impl UnifiedVerbRegistry {
                     name: a.name.clone(),
                     arg_type: format!("{:?}", a.arg_type),
                     required: a.required,
-                    description: String::new(),
+                    description: a.description.clone().unwrap_or_default(),
                     lookup: a.lookup.clone(),
                 })
                 .collect();
@@ -168,6 +170,7 @@ impl UnifiedVerbRegistry {
                 custom_op_id,
                 produces: runtime_verb.produces.clone(),
                 consumes: runtime_verb.consumes.clone(),
+                invocation_phrases: runtime_verb.invocation_phrases.clone(),
             };
 
             verbs.insert(key.clone(), unified);
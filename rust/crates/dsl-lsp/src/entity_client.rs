@@ -23,6 +23,33 @@ pub(crate) struct EntityMatch {
     pub(crate) score: f32,
 }
 
+/// Entity details shown when hovering over a resolved reference.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ResolvedEntity {
+    /// Human-readable display name
+    pub(crate) name: String,
+    /// Entity type the reference was looked up as (e.g., "cbu", "entity")
+    pub(crate) entity_type: String,
+    /// Jurisdiction code, when the lookup source carries one
+    pub(crate) jurisdiction: Option<String>,
+    /// The resolved ID (UUID)
+    pub(crate) id: String,
+}
+
+/// Resolves a single entity reference to its details.
+///
+/// Implemented by [`EntityLookupClient`]; hover goes through this trait so
+/// tests can substitute a canned gateway.
+#[tower_lsp::async_trait]
+pub(crate) trait EntityResolver: Send {
+    /// Resolve `value` as an entity of `entity_type`, returning the best match.
+    async fn resolve(
+        &mut self,
+        entity_type: &str,
+        value: &str,
+    ) -> Result<Option<ResolvedEntity>, tonic::Status>;
+}
+
 /// Client for entity lookups via the EntityGateway service.
 #[derive(Clone)]
 pub(crate) struct EntityLookupClient {
@@ -57,6 +84,7 @@ impl EntityLookupClient {
             discriminators: std::collections::HashMap::new(), // No discriminators for LSP completion
             tenant_id: None,                                  // LSP completion is global context
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let response = self.client.search(request).await?;
@@ -121,6 +149,24 @@ impl EntityLookupClient {
     }
 }
 
+#[tower_lsp::async_trait]
+impl EntityResolver for EntityLookupClient {
+    async fn resolve(
+        &mut self,
+        entity_type: &str,
+        value: &str,
+    ) -> Result<Option<ResolvedEntity>, tonic::Status> {
+        // The gateway's Match carries no jurisdiction column, so it is left unset here.
+        let matches = self.search(entity_type, value, 1).await?;
+        Ok(matches.into_iter().next().map(|m| ResolvedEntity {
+            name: m.display,
+            entity_type: entity_type.to_string(),
+            jurisdiction: None,
+            id: m.id,
+        }))
+    }
+}
+
 /// Default EntityGateway address
 pub(crate) const DEFAULT_GATEWAY_ADDR: &str = "http://[::1]:50051";
 
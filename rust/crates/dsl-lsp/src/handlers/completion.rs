@@ -9,7 +9,7 @@ use crate::entity_client::EntityLookupClient;
 use dsl_analysis::macros::load_macro_registry;
 use dsl_analysis::runtime_registry::runtime_registry;
 use dsl_analysis::suggestions::predict_next_steps;
-use dsl_analysis::verb_registry::{find_unified_verb, registry};
+use dsl_analysis::verb_registry::{find_unified_verb, registry, ArgDef};
 use dsl_core::parse_program;
 use dsl_core::{BindingContext, BindingInfo};
 
@@ -123,47 +123,130 @@ pub(crate) async fn get_completions(
 
 /// Complete verb names - progressively narrows as user types.
 /// e.g., "cbu" -> all cbu.* verbs, "cbu.e" -> cbu.ensure, etc.
+///
+/// Each item inserts a snippet with a tab-stop per argument. The typed token
+/// is also matched against each verb's `invocation_phrases`, so typing `new`
+/// offers verbs whose phrases say "new fund"; name matches rank first.
 fn complete_verb_names(source: &str, prefix: &str, position: Position) -> Vec<CompletionItem> {
     let prefix_lower = prefix.to_lowercase();
     let reg = registry();
 
     let range = replacement_range(source, position, prefix, 0);
 
-    reg.all_verbs()
-        .filter(|verb| {
-            let full_name = verb.full_name().to_lowercase();
-            // Use starts_with for progressive narrowing
-            full_name.starts_with(&prefix_lower)
-        })
-        .map(|verb| {
-            let full_name = verb.full_name();
-            let required: Vec<_> = verb
-                .required_arg_names()
-                .iter()
-                .map(|s| format!(":{}", s))
-                .collect();
-            let detail = if required.is_empty() {
-                format!("[{}]", verb.domain)
-            } else {
-                format!("[{}] requires: {}", verb.domain, required.join(", "))
-            };
+    let mut ranked: Vec<(u8, String, CompletionItem)> = Vec::new();
+    for verb in reg.all_verbs() {
+        let full_name = verb.full_name();
+        let Some(rank) = verb_relevance(&full_name, &verb.invocation_phrases, &prefix_lower) else {
+            continue;
+        };
 
-            CompletionItem {
-                label: full_name.clone(),
-                kind: Some(CompletionItemKind::FUNCTION),
-                detail: Some(detail),
-                documentation: Some(Documentation::String(verb.description.clone())),
-                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
-                    range,
-                    new_text: full_name.clone(),
-                })),
-                insert_text_format: Some(InsertTextFormat::PLAIN_TEXT),
-                filter_text: Some(full_name.clone()),
-                sort_text: Some(format!("0-{}-{}", verb.domain, verb.verb)),
-                ..Default::default()
-            }
-        })
-        .collect()
+        let required: Vec<_> = verb
+            .required_arg_names()
+            .iter()
+            .map(|s| format!(":{}", s))
+            .collect();
+        let detail = if required.is_empty() {
+            format!("[{}]", verb.domain)
+        } else {
+            format!("[{}] requires: {}", verb.domain, required.join(", "))
+        };
+
+        let mut filter_text = full_name.clone();
+        for phrase in &verb.invocation_phrases {
+            filter_text.push(' ');
+            filter_text.push_str(phrase);
+        }
+
+        let item = CompletionItem {
+            label: full_name.clone(),
+            kind: Some(CompletionItemKind::FUNCTION),
+            detail: Some(detail),
+            documentation: Some(Documentation::String(verb.description.clone())),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range,
+                new_text: verb_snippet(&full_name, &verb.args),
+            })),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            filter_text: Some(filter_text),
+            sort_text: Some(format!("{}-{}", rank, full_name)),
+            ..Default::default()
+        };
+        ranked.push((rank, full_name, item));
+    }
+
+    ranked.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+    ranked.into_iter().map(|(_, _, item)| item).collect()
+}
+
+/// How well a verb matches the typed token; lower is better.
+///
+/// 0 = name prefix, 1 = name substring, 2 = invocation phrase; `None` if no match.
+fn verb_relevance(full_name: &str, phrases: &[String], prefix_lower: &str) -> Option<u8> {
+    let name = full_name.to_lowercase();
+    if name.starts_with(prefix_lower) {
+        Some(0)
+    } else if name.contains(prefix_lower) {
+        Some(1)
+    } else if phrases
+        .iter()
+        .any(|phrase| phrase.to_lowercase().contains(prefix_lower))
+    {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Snippet body for a verb: required args become tab-stops labelled with
+/// their description, followed by each optional arg as a removable
+/// placeholder.
+fn verb_snippet(full_name: &str, args: &[ArgDef]) -> String {
+    let mut snippet = escape_snippet(full_name);
+    let mut tab_stop = 1;
+
+    for arg in args.iter().filter(|a| a.required) {
+        snippet.push_str(&format!(
+            " :{} ${{{}:{}}}",
+            arg.name,
+            tab_stop,
+            placeholder_label(arg)
+        ));
+        tab_stop += 1;
+    }
+
+    for arg in args.iter().filter(|a| !a.required) {
+        // Outer placeholder covers the whole `:key value` so it can be deleted in one go.
+        snippet.push_str(&format!(
+            " ${{{}::{} ${{{}:{}}}}}",
+            tab_stop,
+            arg.name,
+            tab_stop + 1,
+            placeholder_label(arg)
+        ));
+        tab_stop += 2;
+    }
+
+    snippet.push_str("$0");
+    snippet
+}
+
+fn placeholder_label(arg: &ArgDef) -> String {
+    match arg.description.trim() {
+        "" => escape_snippet(&arg.name),
+        description => escape_snippet(description),
+    }
+}
+
+/// Escape characters with meaning inside LSP snippet placeholders.
+fn escape_snippet(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '$' | '}' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
 }
 
 /// Complete keyword arguments for a verb.
@@ -603,6 +686,75 @@ mod tests {
     // from the verb registry based on verbs.yaml configuration, so tests
     // need the full config loaded.
 
+    fn arg(name: &str, required: bool, description: Option<&str>) -> ArgDef {
+        ArgDef {
+            name: name.to_string(),
+            arg_type: "string".to_string(),
+            required,
+            description: description.unwrap_or_default().to_string(),
+            lookup: None,
+        }
+    }
+
+    #[test]
+    fn cbu_create_snippet_puts_required_args_first() {
+        let args = vec![
+            arg("jurisdiction", false, Some("Jurisdiction code")),
+            arg("name", true, Some("Name of the CBU")),
+            arg("client-type", true, None),
+        ];
+
+        let snippet = verb_snippet("cbu.create", &args);
+
+        assert!(
+            snippet.starts_with("cbu.create :name ${1:Name of the CBU}"),
+            "{}",
+            snippet
+        );
+        assert!(
+            snippet.contains(":client-type ${2:client-type}"),
+            "{}",
+            snippet
+        );
+        let optional = snippet.find(":jurisdiction").expect("optional arg present");
+        assert!(snippet.find(":name").unwrap() < optional);
+        assert!(snippet.find(":client-type").unwrap() < optional);
+        assert!(snippet.ends_with("$0"));
+    }
+
+    #[test]
+    fn registry_cbu_create_snippet_labels_required_name() {
+        let verb = find_unified_verb("cbu", "create").expect("cbu.create in verb config");
+
+        let snippet = verb_snippet(&verb.full_name(), &verb.args);
+
+        assert!(
+            snippet.starts_with("cbu.create :name ${1:CBU name (typically the fund name)}"),
+            "{}",
+            snippet
+        );
+        let optional = snippet.find(":jurisdiction").expect("optional arg present");
+        assert!(snippet.find(":name").unwrap() < optional);
+    }
+
+    #[test]
+    fn verb_relevance_prefers_name_matches() {
+        let phrases = vec!["new fund".to_string()];
+        assert_eq!(verb_relevance("cbu.create", &phrases, "cbu"), Some(0));
+        assert_eq!(verb_relevance("cbu.create", &phrases, "create"), Some(1));
+        assert_eq!(verb_relevance("cbu.create", &phrases, "new"), Some(2));
+        assert_eq!(verb_relevance("cbu.create", &phrases, "kyc"), None);
+    }
+
+    #[test]
+    fn snippet_placeholders_escape_special_characters() {
+        let args = vec![arg("amount", true, Some("Amount in $ {USD}"))];
+        assert_eq!(
+            verb_snippet("fee.set", &args),
+            "fee.set :amount ${1:Amount in \\$ {USD\\}}$0"
+        );
+    }
+
     #[test]
     fn replacement_range_handles_utf16_before_prefix() {
         let source = "(test.verb :name \"🎉 cbu";
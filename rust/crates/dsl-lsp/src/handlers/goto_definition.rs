@@ -2,6 +2,7 @@
 
 use tower_lsp::lsp_types::*;
 
+use crate::analysis::document::contains_position;
 use crate::analysis::{DocumentState, SymbolTable};
 
 /// Get definition location for symbol at position.
@@ -39,36 +40,72 @@ pub(crate) fn get_definition(
     None
 }
 
-/// Get definition with proper URI (called from server with document URI).
-pub(crate) fn get_definition_with_uri(
+/// Where the symbol under the cursor is bound.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SymbolBinding {
+    /// Bound by an `:as @name` in this document (the binding's range)
+    Local(Range),
+    /// Bound by another open document
+    External(Location),
+    /// Not bound in any open document, so it comes from the session
+    Session(String),
+}
+
+impl SymbolBinding {
+    /// Definition response for a binding; session bindings have no location.
+    pub(crate) fn to_response(&self, uri: &Url) -> Option<GotoDefinitionResponse> {
+        match self {
+            SymbolBinding::Local(range) => Some(GotoDefinitionResponse::Scalar(Location {
+                uri: uri.clone(),
+                range: *range,
+            })),
+            SymbolBinding::External(location) => {
+                Some(GotoDefinitionResponse::Scalar(location.clone()))
+            }
+            SymbolBinding::Session(_) => None,
+        }
+    }
+}
+
+/// Find the binding for the `@symbol` at position.
+///
+/// Bindings in the same document win over same-named bindings elsewhere, so
+/// a jump never leaves the file when the symbol is defined locally.
+pub(crate) fn find_symbol_binding(
     doc: &DocumentState,
     position: Position,
     symbols: &SymbolTable,
-    uri: &Url,
-) -> Option<GotoDefinitionResponse> {
-    let line = doc.get_line(position.line)?;
-    let col = position.character as usize;
+) -> Option<SymbolBinding> {
+    let symbol_name = symbol_name_at(doc, position)?;
 
-    // Find word at cursor
-    let word = find_word_at_position(line, col)?;
+    if let Some(def) = doc.get_symbol_def(&symbol_name) {
+        return Some(SymbolBinding::Local(def.range));
+    }
 
-    // Check if it's a symbol reference
-    if let Some(symbol_name) = strip_symbol_marker(&word) {
-        // Look up in symbol table (cross-document)
-        if let Some(info) = symbols.get(symbol_name) {
-            return Some(GotoDefinitionResponse::Scalar(info.definition.clone()));
-        }
+    // Look up in symbol table (cross-document)
+    if let Some(info) = symbols.get(&symbol_name) {
+        return Some(SymbolBinding::External(info.definition.clone()));
+    }
 
-        // Fall back to document-local symbols
-        if let Some(def) = doc.get_symbol_def(symbol_name) {
-            return Some(GotoDefinitionResponse::Scalar(Location {
-                uri: uri.clone(),
-                range: def.range,
-            }));
-        }
+    Some(SymbolBinding::Session(symbol_name))
+}
+
+/// Name of the symbol at position, preferring parsed symbol spans.
+fn symbol_name_at(doc: &DocumentState, position: Position) -> Option<String> {
+    let parsed = doc
+        .symbol_refs
+        .iter()
+        .map(|r| (&r.name, &r.range))
+        .chain(doc.symbol_defs.iter().map(|d| (&d.name, &d.range)))
+        .find(|(_, range)| contains_position(range, position))
+        .map(|(name, _)| name.clone());
+    if parsed.is_some() {
+        return parsed;
     }
 
-    None
+    let line = doc.get_line(position.line)?;
+    let word = find_word_at_position(line, position.character as usize)?;
+    strip_symbol_marker(&word).map(str::to_string)
 }
 
 /// Get all references to symbol at position.
@@ -189,3 +226,57 @@ fn strip_symbol_marker(word: &str) -> Option<&str> {
 fn is_word_char(c: char) -> bool {
     c.is_alphanumeric() || c == '-' || c == '_' || c == '.' || c == ':' || c == '@' || c == '$'
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::parse_with_v2;
+
+    const SOURCE: &str = "(cbu.create :name \"Fund\" :as @fund)\n(cbu.assign-role :cbu-id @fund :entity-id @session-entity :role \"Mgr\")";
+
+    fn uri() -> Url {
+        Url::parse("file:///tmp/test.dsl").unwrap()
+    }
+
+    fn position_of(needle: &str) -> Position {
+        let (line, text) = SOURCE
+            .lines()
+            .enumerate()
+            .find(|(_, l)| l.contains(needle))
+            .expect("needle in source");
+        Position::new(line as u32, text.find(needle).unwrap() as u32 + 2)
+    }
+
+    #[test]
+    fn local_symbol_jumps_to_binding() {
+        let (doc, _) = parse_with_v2(SOURCE);
+        let symbols = SymbolTable::new();
+
+        let position = position_of("@fund :entity-id");
+        let binding = find_symbol_binding(&doc, position, &symbols);
+        let def_range = doc.get_symbol_def("fund").expect("fund is bound").range;
+        assert_eq!(binding, Some(SymbolBinding::Local(def_range)));
+
+        match binding.and_then(|b| b.to_response(&uri())) {
+            Some(GotoDefinitionResponse::Scalar(location)) => {
+                assert_eq!(location.uri, uri());
+                assert_eq!(location.range, def_range);
+            }
+            other => panic!("expected a jump, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn session_symbol_has_no_jump() {
+        let (doc, _) = parse_with_v2(SOURCE);
+        let symbols = SymbolTable::new();
+
+        let position = position_of("@session-entity");
+        let binding = find_symbol_binding(&doc, position, &symbols);
+        assert_eq!(
+            binding,
+            Some(SymbolBinding::Session("session-entity".to_string()))
+        );
+        assert!(binding.and_then(|b| b.to_response(&uri())).is_none());
+    }
+}
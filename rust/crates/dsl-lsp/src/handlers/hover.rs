@@ -1,9 +1,14 @@
 //! Hover handler for the DSL Language Server.
 
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::sync::RwLock;
 use tower_lsp::lsp_types::*;
 
-use crate::analysis::document::{ExprKind, ParsedArg};
+use crate::analysis::document::{ExprKind, ParsedArg, ParsedExpr};
 use crate::analysis::DocumentState;
+use crate::entity_client::{EntityResolver, ResolvedEntity};
 
 use dsl_analysis::verb_registry::{find_unified_verb, registry, ArgDef, UnifiedVerbDef};
 
@@ -115,6 +120,159 @@ pub(crate) fn get_hover(doc: &DocumentState, position: Position) -> Option<Hover
     None
 }
 
+// =============================================================================
+// Entity Reference Hover
+// =============================================================================
+
+/// Gateway lookups made for entity hovers, valid for one document version.
+///
+/// Editing the document bumps its version and drops every cached entry, so a
+/// reference is looked up at most once per version no matter how often the
+/// cursor lingers over it.
+#[derive(Debug, Default)]
+pub(crate) struct EntityHoverCache {
+    version: Option<i32>,
+    entries: HashMap<(String, String), Option<ResolvedEntity>>,
+}
+
+/// Per-document entity hover caches, shared across hover requests.
+pub(crate) type EntityHoverCaches = RwLock<HashMap<Url, EntityHoverCache>>;
+
+/// Upper bound on a single gateway lookup made for a hover.
+const ENTITY_HOVER_TIMEOUT: Duration = Duration::from_secs(2);
+
+impl EntityHoverCache {
+    /// Cached lookup for `key`, if one was made at `version`.
+    fn get(&self, version: i32, key: &(String, String)) -> Option<Option<ResolvedEntity>> {
+        if self.version != Some(version) {
+            return None;
+        }
+        self.entries.get(key).cloned()
+    }
+
+    /// Record a lookup made at `version`.
+    ///
+    /// Entries from an older version are discarded; a lookup that finished
+    /// after the document moved on to a newer cached version is dropped.
+    fn insert(&mut self, version: i32, key: (String, String), resolved: Option<ResolvedEntity>) {
+        match self.version {
+            Some(current) if current > version => return,
+            Some(current) if current == version => {}
+            _ => {
+                self.version = Some(version);
+                self.entries.clear();
+            }
+        }
+        self.entries.insert(key, resolved);
+    }
+}
+
+/// Hover over an entity reference argument, showing what it resolves to.
+///
+/// Returns `None` when the cursor is not on an entity reference. A missing,
+/// unreachable or slow gateway yields an "unresolved" hover rather than an
+/// error. The cache lock is not held during the gateway call, so one slow
+/// lookup does not hold up hovers elsewhere.
+pub(crate) async fn get_entity_hover<R: EntityResolver>(
+    doc: &DocumentState,
+    position: Position,
+    uri: &Url,
+    version: i32,
+    resolver: Option<&mut R>,
+    caches: &EntityHoverCaches,
+) -> Option<Hover> {
+    let (entity_type, value, range) = find_entity_ref(&doc.expressions, position)?;
+    let key = (entity_type.to_string(), value.to_string());
+
+    let cached = caches
+        .read()
+        .await
+        .get(uri)
+        .and_then(|cache| cache.get(version, &key));
+
+    let resolved = match cached {
+        Some(cached) => cached,
+        None => match resolver {
+            Some(resolver) => {
+                match tokio::time::timeout(
+                    ENTITY_HOVER_TIMEOUT,
+                    resolver.resolve(entity_type, value),
+                )
+                .await
+                {
+                    Ok(Ok(resolved)) => {
+                        caches.write().await.entry(uri.clone()).or_default().insert(
+                            version,
+                            key,
+                            resolved.clone(),
+                        );
+                        resolved
+                    }
+                    // Neither failure is cached: the gateway may be back by the next hover.
+                    Ok(Err(e)) => {
+                        tracing::warn!("{} lookup for hover failed: {}", entity_type, e);
+                        None
+                    }
+                    Err(_) => {
+                        tracing::warn!("{} lookup for hover timed out", entity_type);
+                        None
+                    }
+                }
+            }
+            None => None,
+        },
+    };
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format_entity_hover(entity_type, value, resolved.as_ref()),
+        }),
+        range: Some(range),
+    })
+}
+
+/// Find the entity reference under the cursor, searching nested calls.
+fn find_entity_ref(exprs: &[ParsedExpr], position: Position) -> Option<(&str, &str, Range)> {
+    exprs.iter().find_map(|expr| entity_ref_in(expr, position))
+}
+
+fn entity_ref_in(expr: &ParsedExpr, position: Position) -> Option<(&str, &str, Range)> {
+    match &expr.kind {
+        ExprKind::EntityRef {
+            entity_type, value, ..
+        } if position_in_range(position, &expr.range) => {
+            Some((entity_type.as_str(), value.as_str(), expr.range))
+        }
+        ExprKind::Call { args, .. } => args
+            .iter()
+            .filter_map(|arg| arg.value.as_deref())
+            .find_map(|value| entity_ref_in(value, position)),
+        ExprKind::List { items } => find_entity_ref(items, position),
+        ExprKind::Map { entries } => entries
+            .iter()
+            .find_map(|(_, value)| entity_ref_in(value, position)),
+        _ => None,
+    }
+}
+
+fn format_entity_hover(
+    entity_type: &str,
+    value: &str,
+    resolved: Option<&ResolvedEntity>,
+) -> String {
+    match resolved {
+        Some(entity) => format!(
+            "**{}**\n\nType: `{}`\nJurisdiction: `{}`\nID: `{}`",
+            entity.name,
+            entity.entity_type,
+            entity.jurisdiction.as_deref().unwrap_or("unknown"),
+            entity.id
+        ),
+        None => format!("**{}** `{}`\n\n_unresolved_", entity_type, value),
+    }
+}
+
 /// Hover on a keyword argument - show type, required status, and description.
 fn hover_on_keyword(verb_name: &str, args: &[ParsedArg], position: Position) -> Option<Hover> {
     for arg in args {
@@ -289,3 +447,215 @@ fn position_in_range(position: Position, range: &Range) -> bool {
     }
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Canned gateway: resolves everything to one entity, or fails.
+    struct MockResolver {
+        reachable: bool,
+        calls: usize,
+    }
+
+    #[tower_lsp::async_trait]
+    impl EntityResolver for MockResolver {
+        async fn resolve(
+            &mut self,
+            entity_type: &str,
+            value: &str,
+        ) -> Result<Option<ResolvedEntity>, tonic::Status> {
+            self.calls += 1;
+            if !self.reachable {
+                return Err(tonic::Status::unavailable("gateway down"));
+            }
+            Ok(Some(ResolvedEntity {
+                name: format!("{} S.A.", value),
+                entity_type: entity_type.to_string(),
+                jurisdiction: Some("LU".to_string()),
+                id: "6f1c2a9e-0000-4000-8000-000000000001".to_string(),
+            }))
+        }
+    }
+
+    fn range(start: u32, end: u32) -> Range {
+        Range {
+            start: Position::new(0, start),
+            end: Position::new(0, end),
+        }
+    }
+
+    /// `(cbu.assign-role :entity-id "Acme" ...)` with an entity ref at 29..35.
+    fn doc_with_entity_ref() -> DocumentState {
+        let mut doc = DocumentState::new("(cbu.assign-role :entity-id \"Acme\")".to_string());
+        doc.expressions.push(ParsedExpr {
+            kind: ExprKind::Call {
+                verb_name: "cbu.assign-role".to_string(),
+                verb_range: range(1, 16),
+                args: vec![ParsedArg {
+                    keyword: ":entity-id".to_string(),
+                    keyword_range: range(17, 27),
+                    value: Some(Box::new(ParsedExpr {
+                        kind: ExprKind::EntityRef {
+                            entity_type: "entity".to_string(),
+                            search_column: "name".to_string(),
+                            value: "Acme".to_string(),
+                            resolved: false,
+                        },
+                        range: range(28, 34),
+                    })),
+                }],
+            },
+            range: range(0, 35),
+        });
+        doc
+    }
+
+    fn hover_text(hover: Hover) -> String {
+        match hover.contents {
+            HoverContents::Markup(markup) => markup.value,
+            other => panic!("expected markup hover, got {:?}", other),
+        }
+    }
+
+    fn uri() -> Url {
+        Url::parse("file:///test.dsl").unwrap()
+    }
+
+    #[tokio::test]
+    async fn entity_hover_shows_resolved_name_and_caches_per_version() {
+        let doc = doc_with_entity_ref();
+        let mut resolver = MockResolver {
+            reachable: true,
+            calls: 0,
+        };
+        let caches = EntityHoverCaches::default();
+
+        let hover = get_entity_hover(
+            &doc,
+            Position::new(0, 30),
+            &uri(),
+            1,
+            Some(&mut resolver),
+            &caches,
+        )
+        .await
+        .expect("hover over entity ref");
+        let text = hover_text(hover);
+        assert!(text.contains("Acme S.A."), "{}", text);
+        assert!(text.contains("`LU`"), "{}", text);
+
+        get_entity_hover(
+            &doc,
+            Position::new(0, 31),
+            &uri(),
+            1,
+            Some(&mut resolver),
+            &caches,
+        )
+        .await;
+        assert_eq!(resolver.calls, 1);
+
+        get_entity_hover(
+            &doc,
+            Position::new(0, 31),
+            &uri(),
+            2,
+            Some(&mut resolver),
+            &caches,
+        )
+        .await;
+        assert_eq!(resolver.calls, 2);
+    }
+
+    #[test]
+    fn entity_hover_cache_drops_lookups_for_older_versions() {
+        let key = ("entity".to_string(), "Acme".to_string());
+        let mut cache = EntityHoverCache::default();
+
+        cache.insert(2, key.clone(), None);
+        cache.insert(1, key.clone(), None);
+        assert_eq!(cache.get(2, &key), Some(None));
+        assert_eq!(cache.get(1, &key), None);
+    }
+
+    /// Gateway that checks the hover cache is writable while it is resolving.
+    struct LockProbe {
+        caches: std::sync::Arc<EntityHoverCaches>,
+    }
+
+    #[tower_lsp::async_trait]
+    impl EntityResolver for LockProbe {
+        async fn resolve(
+            &mut self,
+            _entity_type: &str,
+            _value: &str,
+        ) -> Result<Option<ResolvedEntity>, tonic::Status> {
+            assert!(
+                self.caches.try_write().is_ok(),
+                "hover cache locked during gateway call"
+            );
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn entity_hover_releases_cache_during_lookup() {
+        let doc = doc_with_entity_ref();
+        let caches = std::sync::Arc::new(EntityHoverCaches::default());
+        let mut resolver = LockProbe {
+            caches: caches.clone(),
+        };
+
+        let hover = get_entity_hover(
+            &doc,
+            Position::new(0, 30),
+            &uri(),
+            1,
+            Some(&mut resolver),
+            &caches,
+        )
+        .await
+        .expect("hover over entity ref");
+        assert!(hover_text(hover).contains("unresolved"));
+    }
+
+    #[tokio::test]
+    async fn entity_hover_degrades_when_gateway_unreachable() {
+        let doc = doc_with_entity_ref();
+        let mut resolver = MockResolver {
+            reachable: false,
+            calls: 0,
+        };
+        let caches = EntityHoverCaches::default();
+
+        let hover = get_entity_hover(
+            &doc,
+            Position::new(0, 30),
+            &uri(),
+            1,
+            Some(&mut resolver),
+            &caches,
+        )
+        .await
+        .expect("hover over entity ref");
+        assert!(hover_text(hover).contains("unresolved"));
+
+        let hover =
+            get_entity_hover::<MockResolver>(&doc, Position::new(0, 30), &uri(), 1, None, &caches)
+                .await
+                .expect("hover without a client");
+        assert!(hover_text(hover).contains("unresolved"));
+    }
+
+    #[tokio::test]
+    async fn entity_hover_ignores_other_positions() {
+        let doc = doc_with_entity_ref();
+        let caches = EntityHoverCaches::default();
+
+        let hover =
+            get_entity_hover::<MockResolver>(&doc, Position::new(0, 20), &uri(), 1, None, &caches)
+                .await;
+        assert!(hover.is_none());
+    }
+}
@@ -8,9 +8,11 @@
 #![allow(dead_code)]
 
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::Instant;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
 use tokio::time::Duration;
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
@@ -20,6 +22,8 @@ use crate::analysis::{DocumentState, SymbolTable};
 use crate::encoding::{position_to_offset, PositionEncoding};
 use crate::entity_client::{gateway_addr, EntityLookupClient};
 use crate::handlers;
+use crate::handlers::goto_definition::SymbolBinding;
+use crate::handlers::hover::EntityHoverCaches;
 use dsl_analysis::planning_facade::PlanningOutput;
 use dsl_analysis::validation::Diagnostic as SemanticDiagnostic;
 
@@ -41,6 +45,82 @@ fn file_type(uri: &Url) -> FileType {
     }
 }
 
+/// Default delay between the last edit and re-analysis.
+const DEFAULT_DIAGNOSTICS_DEBOUNCE_MS: u64 = 300;
+
+/// Initialization option overriding [`DEFAULT_DIAGNOSTICS_DEBOUNCE_MS`].
+const DEBOUNCE_OPTION: &str = "diagnosticsDebounceMs";
+
+/// Per-document debounce for re-analysis after edits.
+///
+/// Each scheduled analysis waits out the interval before running; scheduling
+/// another one for the same document aborts the previous task, whether it is
+/// still waiting or already analysing. The most recent edit is never
+/// superseded, so it always produces diagnostics.
+pub(crate) struct AnalysisDebouncer {
+    interval_ms: AtomicU64,
+    pending: Mutex<HashMap<Url, JoinHandle<()>>>,
+}
+
+impl AnalysisDebouncer {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval_ms: AtomicU64::new(interval.as_millis() as u64),
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn set_interval(&self, interval: Duration) {
+        self.interval_ms
+            .store(interval.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Run `analysis` for `uri` once the interval passes without another edit.
+    pub(crate) fn schedule<F>(&self, uri: Url, analysis: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let interval = self.interval();
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(interval).await;
+            analysis.await;
+        });
+
+        let superseded = self
+            .pending
+            .lock()
+            .expect("debounce lock poisoned")
+            .insert(uri, handle);
+        if let Some(superseded) = superseded {
+            superseded.abort();
+        }
+    }
+
+    /// Drop any analysis still pending for `uri`.
+    pub(crate) fn cancel(&self, uri: &Url) {
+        let pending = self
+            .pending
+            .lock()
+            .expect("debounce lock poisoned")
+            .remove(uri);
+        if let Some(pending) = pending {
+            pending.abort();
+        }
+    }
+}
+
+/// Debounce interval requested in the client's initialization options.
+fn debounce_from_options(options: Option<&serde_json::Value>) -> Option<Duration> {
+    options?
+        .get(DEBOUNCE_OPTION)?
+        .as_u64()
+        .map(Duration::from_millis)
+}
+
 /// DSL Language Server state.
 pub(crate) struct DslLanguageServer {
     /// LSP client for sending notifications
@@ -55,8 +135,12 @@ pub(crate) struct DslLanguageServer {
     symbols: Arc<RwLock<SymbolTable>>,
     /// Entity Gateway client for lookups (replaces direct DB access)
     entity_client: Arc<RwLock<Option<EntityLookupClient>>>,
-    /// Pending changes for debouncing (uri -> timestamp)
-    pending_changes: Arc<RwLock<HashMap<Url, Instant>>>,
+    /// Debounced re-analysis after edits
+    debouncer: Arc<AnalysisDebouncer>,
+    /// Latest version reported by the client for each open document
+    document_versions: Arc<RwLock<HashMap<Url, i32>>>,
+    /// Entity lookups behind hovers, scoped to the document version
+    entity_hover_caches: Arc<EntityHoverCaches>,
 }
 
 impl DslLanguageServer {
@@ -69,7 +153,11 @@ impl DslLanguageServer {
             semantic_diagnostics: Arc::new(RwLock::new(HashMap::new())),
             symbols: Arc::new(RwLock::new(SymbolTable::new())),
             entity_client: Arc::new(RwLock::new(None)),
-            pending_changes: Arc::new(RwLock::new(HashMap::new())),
+            debouncer: Arc::new(AnalysisDebouncer::new(Duration::from_millis(
+                DEFAULT_DIAGNOSTICS_DEBOUNCE_MS,
+            ))),
+            document_versions: Arc::new(RwLock::new(HashMap::new())),
+            entity_hover_caches: Arc::new(EntityHoverCaches::default()),
         }
     }
 
@@ -238,9 +326,14 @@ impl DslLanguageServer {
 
 #[tower_lsp::async_trait]
 impl LanguageServer for DslLanguageServer {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
         tracing::info!("Initializing DSL Language Server");
 
+        if let Some(interval) = debounce_from_options(params.initialization_options.as_ref()) {
+            tracing::info!("Diagnostics debounce set to {:?}", interval);
+            self.debouncer.set_interval(interval);
+        }
+
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 // Incremental sync for efficiency
@@ -324,6 +417,11 @@ impl LanguageServer for DslLanguageServer {
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
         tracing::debug!("Document opened: {}", params.text_document.uri);
+        self.debouncer.cancel(&params.text_document.uri);
+        self.document_versions.write().await.insert(
+            params.text_document.uri.clone(),
+            params.text_document.version,
+        );
         self.analyze_document(&params.text_document.uri, &params.text_document.text)
             .await;
     }
@@ -332,6 +430,10 @@ impl LanguageServer for DslLanguageServer {
         tracing::debug!("Document changed: {}", params.text_document.uri);
 
         let uri = params.text_document.uri.clone();
+        self.document_versions
+            .write()
+            .await
+            .insert(uri.clone(), params.text_document.version);
 
         // Get full text from incremental changes
         let text = if let Some(doc) = self.get_document(&uri).await {
@@ -355,37 +457,31 @@ impl LanguageServer for DslLanguageServer {
                 .unwrap_or_default()
         };
 
-        // Debounce: record timestamp and spawn delayed analysis
-        let now = Instant::now();
-        self.pending_changes.write().await.insert(uri.clone(), now);
+        // Keep the text current even while analysis is debounced, so the next
+        // incremental change applies to this edit rather than the last analysed one.
+        if let Some(doc) = self.documents.write().await.get_mut(&uri) {
+            doc.text = text.clone();
+        }
 
-        let pending = self.pending_changes.clone();
+        // Debounce: analyse once edits pause, superseding any pending analysis
         let docs = self.documents.clone();
         let client = self.client.clone();
         let planning_outputs = self.planning_outputs.clone();
         let semantic_diagnostics = self.semantic_diagnostics.clone();
         let symbols = self.symbols.clone();
         let uri2 = uri.clone();
-        let text2 = text.clone();
-
-        tokio::spawn(async move {
-            tokio::time::sleep(Duration::from_millis(100)).await;
-
-            // Check if this is still the most recent change
-            let pending_time = pending.read().await.get(&uri2).cloned();
-            if pending_time == Some(now) {
-                // Still current - run analysis
-                Self::analyze_document_static(
-                    &uri2,
-                    &text2,
-                    &docs,
-                    &planning_outputs,
-                    &semantic_diagnostics,
-                    &symbols,
-                    &client,
-                )
-                .await;
-            }
+
+        self.debouncer.schedule(uri, async move {
+            Self::analyze_document_static(
+                &uri2,
+                &text,
+                &docs,
+                &planning_outputs,
+                &semantic_diagnostics,
+                &symbols,
+                &client,
+            )
+            .await;
         });
     }
 
@@ -409,10 +505,16 @@ impl LanguageServer for DslLanguageServer {
             sem_diags.remove(&params.text_document.uri);
         }
 
-        {
-            let mut pending = self.pending_changes.write().await;
-            pending.remove(&params.text_document.uri);
-        }
+        self.debouncer.cancel(&params.text_document.uri);
+
+        self.document_versions
+            .write()
+            .await
+            .remove(&params.text_document.uri);
+        self.entity_hover_caches
+            .write()
+            .await
+            .remove(&params.text_document.uri);
 
         {
             let mut symbols = self.symbols.write().await;
@@ -455,6 +557,27 @@ impl LanguageServer for DslLanguageServer {
         let position = params.text_document_position_params.position;
 
         if let Some(doc) = self.get_document(uri).await {
+            let version = self
+                .document_versions
+                .read()
+                .await
+                .get(uri)
+                .copied()
+                .unwrap_or_default();
+            // Only use an established connection; hovering shouldn't dial the gateway.
+            let mut entity_client = self.entity_client.read().await.clone();
+            let entity_hover = handlers::hover::get_entity_hover(
+                &doc,
+                position,
+                uri,
+                version,
+                entity_client.as_mut(),
+                &self.entity_hover_caches,
+            )
+            .await;
+            if entity_hover.is_some() {
+                return Ok(entity_hover);
+            }
             return Ok(handlers::hover::get_hover(&doc, position));
         }
 
@@ -469,10 +592,22 @@ impl LanguageServer for DslLanguageServer {
         let position = params.text_document_position_params.position;
 
         if let Some(doc) = self.get_document(uri).await {
-            let symbols = self.symbols.read().await;
-            return Ok(handlers::goto_definition::get_definition_with_uri(
-                &doc, position, &symbols, uri,
-            ));
+            let binding = {
+                let symbols = self.symbols.read().await;
+                handlers::goto_definition::find_symbol_binding(&doc, position, &symbols)
+            };
+            if let Some(SymbolBinding::Session(name)) = &binding {
+                self.client
+                    .show_message(
+                        MessageType::INFO,
+                        format!(
+                            "@{} is a session binding; it has no definition in this document",
+                            name
+                        ),
+                    )
+                    .await;
+            }
+            return Ok(binding.and_then(|b| b.to_response(uri)));
         }
 
         Ok(None)
@@ -586,3 +721,53 @@ impl LanguageServer for DslLanguageServer {
 fn offset_from_position(text: &str, position: Position) -> usize {
     position_to_offset(text, position, PositionEncoding::Utf16).unwrap_or(text.len())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn rapid_edits_publish_only_the_last_analysis() {
+        let debouncer = AnalysisDebouncer::new(Duration::from_millis(30));
+        let uri = Url::parse("file:///tmp/edits.dsl").unwrap();
+        let published = Arc::new(Mutex::new(Vec::new()));
+
+        for edit in 0..5 {
+            let published = published.clone();
+            debouncer.schedule(uri.clone(), async move {
+                published.lock().unwrap().push(edit);
+            });
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert_eq!(*published.lock().unwrap(), vec![4]);
+    }
+
+    #[tokio::test]
+    async fn cancel_drops_pending_analysis() {
+        let debouncer = AnalysisDebouncer::new(Duration::from_millis(20));
+        let uri = Url::parse("file:///tmp/closed.dsl").unwrap();
+        let published = Arc::new(Mutex::new(Vec::new()));
+
+        let sink = published.clone();
+        debouncer.schedule(uri.clone(), async move {
+            sink.lock().unwrap().push(());
+        });
+        debouncer.cancel(&uri);
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert!(published.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn debounce_interval_comes_from_initialization_options() {
+        let options = serde_json::json!({ "diagnosticsDebounceMs": 120 });
+        assert_eq!(
+            debounce_from_options(Some(&options)),
+            Some(Duration::from_millis(120))
+        );
+        assert_eq!(debounce_from_options(Some(&serde_json::json!({}))), None);
+        assert_eq!(debounce_from_options(None), None);
+    }
+}
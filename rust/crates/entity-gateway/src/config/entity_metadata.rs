@@ -8,7 +8,7 @@
 //! 2. Verb YAML lookup blocks - Extracted from DSL verb definitions
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Root configuration structure for the entity gateway
 #[derive(Debug, Clone, Deserialize)]
@@ -73,6 +73,13 @@ pub struct EntityConfig {
     /// Optional WHERE clause filter (e.g., "is_active = true")
     #[serde(default)]
     pub filter: Option<String>,
+    /// Optional last-modified timestamp column (e.g., "updated_at")
+    ///
+    /// When set, the refresh pipeline can reload only rows changed since
+    /// the last watermark instead of the whole table. Must be `timestamptz`;
+    /// any other type fails the refresh.
+    #[serde(default)]
+    pub updated_at_column: Option<String>,
     /// Composite search schema (s-expression) for disambiguation
     /// e.g., "(search_name (nationality :selectivity 0.7) (date_of_birth :selectivity 0.95))"
     #[serde(default)]
@@ -82,6 +89,14 @@ pub struct EntityConfig {
     /// Discriminator fields for filtering/scoring (used with composite_search)
     #[serde(default)]
     pub discriminators: Vec<EntityDiscriminatorConfig>,
+    /// Per-search-key relevance boosts for fuzzy search (e.g., name: 2.0, alias: 1.0)
+    ///
+    /// When set, a fuzzy query on any key is run as one query over every
+    /// search key, each weighted by its boost (unlisted keys weigh 1.0), so a
+    /// hit on the primary name outranks a hit on an alias. Boosts must be
+    /// positive.
+    #[serde(default)]
+    pub field_boosts: HashMap<String, f32>,
     /// Sharding configuration
     #[serde(default)]
     pub shard: Option<ShardConfig>,
@@ -202,17 +217,106 @@ impl GatewayConfig {
     /// Load configuration from a YAML file
     pub fn from_file(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let content = std::fs::read_to_string(path)?;
-        let config: GatewayConfig = serde_yaml::from_str(&content)?;
-        Ok(config)
+        Self::from_yaml(&content)
     }
 
     /// Load configuration from a YAML string
     pub fn from_yaml(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let config: GatewayConfig = serde_yaml::from_str(content)?;
+        config.validate().map_err(InvalidConfig)?;
         Ok(config)
     }
+
+    /// Check the configuration for problems serde can't catch
+    ///
+    /// Reports every problem found rather than stopping at the first, in a
+    /// stable order (refresh settings first, then entities by key).
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+
+        if self.refresh.interval_secs == 0 {
+            errors.push(ConfigError::ZeroRefreshInterval);
+        }
+
+        let mut keys: Vec<&String> = self.entities.keys().collect();
+        keys.sort();
+
+        // Nicknames are looked up case-insensitively (callers uppercase them)
+        let mut by_nickname: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for key in &keys {
+            by_nickname
+                .entry(self.entities[*key].nickname.to_uppercase())
+                .or_default()
+                .push((*key).clone());
+        }
+        for (nickname, entries) in by_nickname {
+            if entries.len() > 1 {
+                errors.push(ConfigError::DuplicateNickname { nickname, entries });
+            }
+        }
+
+        for key in keys {
+            let entity = &self.entities[key];
+            if entity.search_keys.is_empty() {
+                errors.push(ConfigError::EmptySearchKeys {
+                    entity: key.clone(),
+                });
+            }
+
+            let mut boosts: Vec<(&String, &f32)> = entity.field_boosts.iter().collect();
+            boosts.sort_by(|a, b| a.0.cmp(b.0));
+            for (search_key, boost) in boosts {
+                if entity.get_search_key(search_key).is_none() {
+                    errors.push(ConfigError::UnknownBoostKey {
+                        entity: key.clone(),
+                        search_key: search_key.clone(),
+                    });
+                }
+                if boost.is_nan() || *boost <= 0.0 {
+                    errors.push(ConfigError::NonPositiveBoost {
+                        entity: key.clone(),
+                        search_key: search_key.clone(),
+                        boost: *boost,
+                    });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
+/// A single problem found by `GatewayConfig::validate`
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ConfigError {
+    #[error("refresh.interval_secs must be nonzero")]
+    ZeroRefreshInterval,
+    #[error("nickname '{nickname}' is used by more than one entity: {}", .entries.join(", "))]
+    DuplicateNickname {
+        nickname: String,
+        entries: Vec<String>,
+    },
+    #[error("entity '{entity}' has no search_keys")]
+    EmptySearchKeys { entity: String },
+    #[error("entity '{entity}': field_boosts references unknown search key '{search_key}'")]
+    UnknownBoostKey { entity: String, search_key: String },
+    #[error("entity '{entity}': field boost for '{search_key}' must be positive, got {boost}")]
+    NonPositiveBoost {
+        entity: String,
+        search_key: String,
+        boost: f32,
+    },
+}
+
+/// Every validation problem in a config, reported together
+#[derive(Debug, thiserror::Error)]
+#[error("invalid gateway config: {}", .0.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; "))]
+struct InvalidConfig(Vec<ConfigError>);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -254,6 +358,120 @@ entities:
         assert!(person.shard.as_ref().map(|s| s.enabled).unwrap_or(false));
     }
 
+    #[test]
+    fn test_field_boosts_must_be_positive() {
+        let yaml = |boost: &str| {
+            format!(
+                r#"
+refresh:
+  interval_secs: 300
+  startup_mode: async
+database:
+  connection_string_env: "DATABASE_URL"
+entities:
+  fund:
+    nickname: "FUND"
+    source_table: "funds"
+    return_key: "fund_id"
+    search_keys:
+      - name: "name"
+        column: "name"
+        default: true
+      - name: "alias"
+        column: "alias"
+    field_boosts:
+      name: {}
+      alias: 1.0
+"#,
+                boost
+            )
+        };
+
+        let config = GatewayConfig::from_yaml(&yaml("2.5")).unwrap();
+        assert_eq!(config.entities["fund"].field_boosts["name"], 2.5);
+
+        let err = GatewayConfig::from_yaml(&yaml("0")).unwrap_err();
+        assert!(err.to_string().contains("must be positive"));
+        assert!(GatewayConfig::from_yaml(&yaml("-1.0")).is_err());
+    }
+
+    const VALID_HEADER: &str = r#"
+refresh:
+  interval_secs: 300
+  startup_mode: async
+database:
+  connection_string_env: "DATABASE_URL"
+"#;
+
+    #[test]
+    fn test_validate_rejects_duplicate_nicknames() {
+        let yaml = format!(
+            r#"{}
+entities:
+  fund:
+    nickname: "FUND"
+    source_table: "funds"
+    return_key: "fund_id"
+    search_keys:
+      - name: "name"
+        column: "name"
+  fund_legacy:
+    nickname: "fund"
+    source_table: "legacy_funds"
+    return_key: "fund_id"
+    search_keys:
+      - name: "name"
+        column: "name"
+"#,
+            VALID_HEADER
+        );
+
+        let config: GatewayConfig = serde_yaml::from_str(&yaml).unwrap();
+        let errors = config.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![ConfigError::DuplicateNickname {
+                nickname: "FUND".to_string(),
+                entries: vec!["fund".to_string(), "fund_legacy".to_string()],
+            }]
+        );
+        assert!(GatewayConfig::from_yaml(&yaml).is_err());
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let yaml = r#"
+refresh:
+  interval_secs: 0
+  startup_mode: async
+database:
+  connection_string_env: "DATABASE_URL"
+entities:
+  person:
+    nickname: "PERSON"
+    source_table: "persons"
+    return_key: "person_id"
+    search_keys: []
+"#;
+
+        let config: GatewayConfig = serde_yaml::from_str(yaml).unwrap();
+        let errors = config.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ConfigError::ZeroRefreshInterval,
+                ConfigError::EmptySearchKeys {
+                    entity: "person".to_string()
+                },
+            ]
+        );
+
+        // The loader surfaces all of them in one error
+        let message = GatewayConfig::from_yaml(yaml).unwrap_err().to_string();
+        assert!(message.contains("interval_secs"));
+        assert!(message.contains("no search_keys"));
+    }
+
     #[test]
     fn test_all_columns() {
         let entity = EntityConfig {
@@ -264,6 +482,8 @@ entities:
             display_template_full: None,
             index_mode: IndexMode::Trigram,
             filter: None,
+            updated_at_column: None,
+            field_boosts: HashMap::new(),
             composite_search: None,
             search_keys: vec![SearchKeyConfig {
                 name: "name".to_string(),
@@ -293,6 +513,8 @@ entities:
             ),
             index_mode: IndexMode::Trigram,
             filter: None,
+            updated_at_column: None,
+            field_boosts: HashMap::new(),
             composite_search: Some(
                 "(search_name (nationality :selectivity 0.7) (date_of_birth :selectivity 0.95))"
                     .to_string(),
@@ -5,3 +5,4 @@ mod traits;
 pub use registry::*;
 pub use tantivy_index::*;
 pub(crate) use traits::*;
+pub use traits::{merge_ranked, MatchMode, SearchMatch};
@@ -46,6 +46,24 @@ impl IndexRegistry {
         self.indexes.write().await.insert(nickname, index);
     }
 
+    /// Atomically swap the index for a single nickname
+    ///
+    /// Returns the previous index, if any. The registry lock is held only
+    /// for the map insert, so searches on other nicknames are not blocked
+    /// while a replacement index is being built. Searches already in
+    /// flight hold their own `Arc` to the old index and complete against
+    /// it; only lookups made after the swap see the new index.
+    pub async fn replace(
+        &self,
+        nickname: &str,
+        index: Arc<dyn SearchIndex>,
+    ) -> Option<Arc<dyn SearchIndex>> {
+        self.indexes
+            .write()
+            .await
+            .insert(nickname.to_string(), index)
+    }
+
     /// Get all registered nicknames
     pub fn nicknames(&self) -> Vec<&str> {
         self.configs.keys().map(|s| s.as_str()).collect()
@@ -92,6 +110,8 @@ impl IndexRegistry {
 mod tests {
     use super::*;
     use crate::config::{SearchKeyConfig, ShardConfig};
+    use crate::index::traits::{IndexRecord, MatchMode, SearchQuery};
+    use crate::index::TantivyIndex;
 
     fn sample_config() -> EntityConfig {
         EntityConfig {
@@ -102,6 +122,8 @@ mod tests {
             display_template_full: None,
             index_mode: crate::config::IndexMode::Trigram,
             filter: None,
+            updated_at_column: None,
+            field_boosts: HashMap::new(),
             composite_search: None,
             search_keys: vec![SearchKeyConfig {
                 name: "name".to_string(),
@@ -130,6 +152,101 @@ mod tests {
         assert!(nicknames.contains(&"fund"));
     }
 
+    fn record(token: &str, name: &str) -> IndexRecord {
+        IndexRecord {
+            token: token.to_string(),
+            display: name.to_string(),
+            search_values: HashMap::from([("name".to_string(), name.to_lowercase())]),
+            discriminator_values: HashMap::new(),
+            tenant_id: None,
+            cbu_ids: vec![],
+        }
+    }
+
+    fn name_query(value: &str) -> SearchQuery {
+        SearchQuery {
+            values: vec![value.to_string()],
+            search_key: "name".to_string(),
+            mode: MatchMode::Fuzzy,
+            limit: 10,
+            discriminators: HashMap::new(),
+            tenant_id: None,
+            cbu_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replace_swaps_single_index() {
+        let mut configs = HashMap::new();
+        configs.insert("fund".to_string(), sample_config());
+        configs.insert("person".to_string(), sample_config());
+        let registry = Arc::new(IndexRegistry::new(configs));
+
+        let old_fund = TantivyIndex::new(sample_config()).unwrap();
+        old_fund
+            .refresh(vec![record("fund-1", "Apex Growth Fund")])
+            .await
+            .unwrap();
+        registry
+            .register("fund".to_string(), Arc::new(old_fund))
+            .await;
+
+        let person = TantivyIndex::new(sample_config()).unwrap();
+        person
+            .refresh(vec![record("person-1", "Alice Apex")])
+            .await
+            .unwrap();
+        registry
+            .register("person".to_string(), Arc::new(person))
+            .await;
+
+        // A reader that grabbed the index before the swap keeps a
+        // consistent view while concurrently re-searching it
+        let in_flight = registry.get("fund").await.unwrap();
+        let reader = tokio::spawn(async move {
+            let mut seen = Vec::new();
+            for _ in 0..20 {
+                let tokens: Vec<String> = in_flight
+                    .search(&name_query("apex"))
+                    .await
+                    .into_iter()
+                    .map(|m| m.token)
+                    .collect();
+                seen.push(tokens);
+                tokio::task::yield_now().await;
+            }
+            seen
+        });
+
+        let new_fund = TantivyIndex::new(sample_config()).unwrap();
+        new_fund
+            .refresh(vec![record("fund-2", "Apex Income Fund")])
+            .await
+            .unwrap();
+        let previous = registry.replace("fund", Arc::new(new_fund)).await;
+        assert!(previous.is_some());
+
+        for tokens in reader.await.unwrap() {
+            assert_eq!(tokens, vec!["fund-1".to_string()]);
+        }
+
+        let after = registry.get("fund").await.unwrap();
+        let tokens: Vec<String> = after
+            .search(&name_query("apex"))
+            .await
+            .into_iter()
+            .map(|m| m.token)
+            .collect();
+        assert_eq!(tokens, vec!["fund-2".to_string()]);
+
+        // Other nicknames are untouched by the swap
+        let person = registry.get("person").await.unwrap();
+        assert_eq!(
+            person.search(&name_query("alice")).await[0].token,
+            "person-1"
+        );
+    }
+
     #[tokio::test]
     async fn test_get_nonexistent() {
         let registry = IndexRegistry::new(HashMap::new());
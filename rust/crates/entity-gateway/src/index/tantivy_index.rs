@@ -18,17 +18,19 @@ use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use tantivy::collector::TopDocs;
-use tantivy::query::{BooleanQuery, FuzzyTermQuery, Query, QueryParser, TermQuery};
+use tantivy::query::{
+    BooleanQuery, BoostQuery, FuzzyTermQuery, Occur, Query, QueryParser, TermQuery,
+};
 use tantivy::schema::{
     Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, Value, STORED, STRING,
 };
 use tantivy::tokenizer::{LowerCaser, NgramTokenizer, TextAnalyzer};
-use tantivy::{Index, IndexReader, IndexWriter, Term};
+use tantivy::{Index, IndexReader, IndexWriter, Searcher, Term};
 use tokio::sync::{Mutex, RwLock};
 
 use crate::config::{EntityConfig, IndexMode};
 use crate::index::traits::{
-    IndexError, IndexRecord, MatchMode, SearchIndex, SearchMatch, SearchQuery,
+    normalize_scores, IndexError, IndexRecord, MatchMode, SearchIndex, SearchMatch, SearchQuery,
 };
 
 /// Custom tokenizer name for ngram-based substring search
@@ -198,18 +200,46 @@ impl TantivyIndex {
         Ok(())
     }
 
+    /// Fields a fuzzy query on `search_key` runs over, as
+    /// `(search_field, exact_field, boost)`
+    ///
+    /// Without `field_boosts` only the requested key is searched. With boosts,
+    /// every search key is searched in one query, weighted by its boost, so
+    /// hits on the primary name outrank hits on an alias.
+    fn fuzzy_fields(
+        &self,
+        search_key: &str,
+        search_field: Field,
+        exact_field: Field,
+    ) -> Vec<(Field, Field, f32)> {
+        let boost = |key: &str| self.config.field_boosts.get(key).copied().unwrap_or(1.0);
+        let mut fields = vec![(search_field, exact_field, boost(search_key))];
+
+        if !self.config.field_boosts.is_empty() {
+            for key in &self.config.search_keys {
+                if key.name == search_key {
+                    continue;
+                }
+                if let Some(field) = self.search_fields.get(&key.name) {
+                    let exact = self.exact_fields.get(&key.name).copied().unwrap_or(*field);
+                    fields.push((*field, exact, boost(&key.name)));
+                }
+            }
+        }
+
+        fields
+    }
+
     /// Build a fuzzy substring query that handles:
     /// - Single token: ngram lookup via QueryParser (applies ngram tokenizer)
     /// - Multiple tokens: boolean AND of ngram lookups
     /// - Typo tolerance via fuzzy term query fallback for short inputs
     ///
+    /// `fields` come from [`Self::fuzzy_fields`]; the first entry is the
+    /// requested key and each field's score is scaled by its boost.
+    ///
     /// Note: `input` should already be normalized (uppercase for exact mode, lowercase for trigram mode)
-    fn build_fuzzy_query(
-        &self,
-        search_field: Field,
-        exact_field: Field,
-        input: &str,
-    ) -> Box<dyn Query> {
+    fn build_fuzzy_query(&self, fields: &[(Field, Field, f32)], input: &str) -> Box<dyn Query> {
         // Input is already normalized by caller - don't change case here
         let input_trimmed = input.trim().to_string();
 
@@ -218,22 +248,38 @@ impl TantivyIndex {
             return Box::new(BooleanQuery::new(vec![]));
         }
 
-        // For short inputs (< 3 chars total), use fuzzy prefix on exact field
+        // For short inputs (< 3 chars total), use fuzzy prefix on exact fields
         if input_trimmed.len() < 3 {
-            let term = Term::from_field_text(exact_field, &input_trimmed);
-            return Box::new(FuzzyTermQuery::new_prefix(term, 1, true));
+            let mut clauses: Vec<(Occur, Box<dyn Query>)> = fields
+                .iter()
+                .map(|&(_, exact_field, boost)| {
+                    let term = Term::from_field_text(exact_field, &input_trimmed);
+                    let fuzzy: Box<dyn Query> = Box::new(FuzzyTermQuery::new_prefix(term, 1, true));
+                    let query: Box<dyn Query> = Box::new(BoostQuery::new(fuzzy, boost));
+                    (Occur::Should, query)
+                })
+                .collect();
+            return if clauses.len() == 1 {
+                clauses.remove(0).1
+            } else {
+                Box::new(BooleanQuery::new(clauses))
+            };
         }
 
         // Use QueryParser to properly tokenize with ngrams
         // This ensures "pacific" gets broken into ngrams that match indexed ngrams
-        let mut query_parser = QueryParser::for_index(&self.index, vec![search_field]);
+        let search_fields = fields.iter().map(|&(field, _, _)| field).collect();
+        let mut query_parser = QueryParser::for_index(&self.index, search_fields);
         query_parser.set_conjunction_by_default(); // AND semantics for multiple tokens
+        for &(field, _, boost) in fields {
+            query_parser.set_field_boost(field, boost);
+        }
 
         match query_parser.parse_query(&input_trimmed) {
             Ok(query) => query,
             Err(e) => {
                 tracing::warn!(error = %e, "Query parse failed, falling back to exact match");
-                let term = Term::from_field_text(exact_field, &input_trimmed);
+                let term = Term::from_field_text(fields[0].1, &input_trimmed);
                 Box::new(TermQuery::new(term, Default::default()))
             }
         }
@@ -252,8 +298,6 @@ impl TantivyIndex {
         tenant_id: Option<&str>,
         cbu_id: Option<&str>,
     ) -> Box<dyn Query> {
-        use tantivy::query::Occur;
-
         let mut must_clauses: Vec<(Occur, Box<dyn Query>)> = vec![(Occur::Must, base_query)];
 
         // Tenant isolation: require exact tenant_id match
@@ -398,40 +442,68 @@ impl TantivyIndex {
 
         None
     }
-}
 
-#[async_trait]
-impl SearchIndex for TantivyIndex {
-    async fn search(&self, query: &SearchQuery) -> Vec<SearchMatch> {
-        let generation = self.generation.load(Ordering::SeqCst);
+    /// Build the Tantivy document for a single record
+    fn build_document(&self, record: &IndexRecord) -> tantivy::TantivyDocument {
+        let mut doc = tantivy::TantivyDocument::new();
+        doc.add_text(self.token_field, &record.token);
+        doc.add_text(self.display_field, &record.display);
+
+        // Add search values to fields
+        // IndexMode::Exact (lookup tables): store uppercase - codes like DIRECTOR, US
+        // IndexMode::Trigram (entity tables): preserve original case for names
+        for (key, value) in &record.search_values {
+            let is_exact_mode = self.exact_fields.get(key) == self.search_fields.get(key);
+            let indexed_value = if is_exact_mode {
+                value.to_uppercase()
+            } else {
+                value.to_string() // preserve original case
+            };
 
-        tracing::debug!(
-            nickname = %self.config.nickname,
-            search_key = %query.search_key,
-            values = ?query.values,
-            mode = ?query.mode,
-            generation = generation,
-            "Starting search"
-        );
+            if let Some(field) = self.search_fields.get(key) {
+                doc.add_text(*field, &indexed_value);
+            }
 
-        let reader_guard = self.reader.read().await;
-        let reader = match reader_guard.as_ref() {
-            Some(r) => r,
-            None => {
-                tracing::warn!(nickname = %self.config.nickname, "No reader available");
-                return vec![];
+            // For Trigram mode, also add to separate exact_field
+            if !is_exact_mode {
+                if let Some(field) = self.exact_fields.get(key) {
+                    doc.add_text(*field, &indexed_value);
+                }
             }
-        };
+        }
 
-        let searcher = reader.searcher();
-        tracing::debug!(
-            nickname = %self.config.nickname,
-            num_docs = searcher.num_docs(),
-            num_segments = searcher.segment_readers().len(),
-            generation = generation,
-            "Searcher ready"
-        );
+        // Add discriminator values (stored only, for post-search filtering)
+        for (disc_name, disc_value) in &record.discriminator_values {
+            if let Some(field) = self.discriminator_fields.get(disc_name) {
+                doc.add_text(*field, disc_value);
+            }
+        }
+
+        // Add tenant ID for multi-tenant isolation
+        if let Some(tenant_id) = &record.tenant_id {
+            doc.add_text(self.tenant_field, tenant_id);
+        }
 
+        // Add CBU IDs for entity universe scoping
+        // Store each CBU ID as a separate term for efficient filtering
+        if !record.cbu_ids.is_empty() {
+            // Join CBU IDs with space - allows term queries to match individual IDs
+            let cbu_ids_str = record.cbu_ids.join(" ");
+            doc.add_text(self.cbu_ids_field, &cbu_ids_str);
+        }
+
+        doc
+    }
+
+    /// Run a single query against an already-acquired searcher
+    ///
+    /// Shared by `search` and `search_batch` so both paths rank and filter
+    /// identically. Returns `SearchFailed` for an unknown search key.
+    fn search_with_searcher(
+        &self,
+        searcher: &Searcher,
+        query: &SearchQuery,
+    ) -> Result<Vec<SearchMatch>, IndexError> {
         // Get the search fields
         let search_field = match self.search_fields.get(&query.search_key) {
             Some(f) => *f,
@@ -442,7 +514,10 @@ impl SearchIndex for TantivyIndex {
                     available_keys = ?self.search_fields.keys().collect::<Vec<_>>(),
                     "Unknown search key"
                 );
-                return vec![];
+                return Err(IndexError::SearchFailed(format!(
+                    "unknown search key '{}' for '{}'",
+                    query.search_key, self.config.nickname
+                )));
             }
         };
 
@@ -468,133 +543,154 @@ impl SearchIndex for TantivyIndex {
                 input.to_string() // preserve original case
             };
 
-            let tantivy_query: Box<dyn Query> = match query.mode {
-                MatchMode::Fuzzy => {
-                    if input_normalized.is_empty() {
-                        // Empty fuzzy query - return top results (for pre-resolution)
-                        Box::new(tantivy::query::AllQuery)
-                    } else {
-                        self.build_fuzzy_query(search_field, exact_field, &input_normalized)
+            // ExactThenFuzzy runs an exact pass first and only falls back to
+            // fuzzy when that input produced no exact hits.
+            for &mode in query.mode.passes() {
+                let hits_before = results.len();
+                let tantivy_query: Box<dyn Query> = match mode {
+                    // `passes()` never yields ExactThenFuzzy; it folds into fuzzy
+                    MatchMode::Fuzzy | MatchMode::ExactThenFuzzy => {
+                        if input_normalized.is_empty() {
+                            // Empty fuzzy query - return top results (for pre-resolution)
+                            Box::new(tantivy::query::AllQuery)
+                        } else {
+                            let fields =
+                                self.fuzzy_fields(&query.search_key, search_field, exact_field);
+                            self.build_fuzzy_query(&fields, &input_normalized)
+                        }
                     }
-                }
-                MatchMode::Exact => {
-                    if input_normalized.is_empty() {
-                        // Empty query - match all
-                        Box::new(tantivy::query::AllQuery)
-                    } else if is_exact_index_mode {
-                        // IndexMode::Exact: raw tokenizer, full string match
-                        let term = Term::from_field_text(exact_field, &input_normalized);
-                        Box::new(TermQuery::new(term, Default::default()))
-                    } else {
-                        // IndexMode::Trigram: word tokenizer, use QueryParser for word matching
-                        let query_parser = QueryParser::for_index(&self.index, vec![exact_field]);
-                        match query_parser.parse_query(&input_normalized) {
-                            Ok(q) => q,
-                            Err(_) => {
-                                let term = Term::from_field_text(exact_field, &input_normalized);
-                                Box::new(TermQuery::new(term, Default::default()))
+                    MatchMode::Exact => {
+                        if input_normalized.is_empty() {
+                            // Empty query - match all
+                            Box::new(tantivy::query::AllQuery)
+                        } else if is_exact_index_mode {
+                            // IndexMode::Exact: raw tokenizer, full string match
+                            let term = Term::from_field_text(exact_field, &input_normalized);
+                            Box::new(TermQuery::new(term, Default::default()))
+                        } else {
+                            // IndexMode::Trigram: word tokenizer, use QueryParser for word matching
+                            let query_parser =
+                                QueryParser::for_index(&self.index, vec![exact_field]);
+                            match query_parser.parse_query(&input_normalized) {
+                                Ok(q) => q,
+                                Err(_) => {
+                                    let term =
+                                        Term::from_field_text(exact_field, &input_normalized);
+                                    Box::new(TermQuery::new(term, Default::default()))
+                                }
                             }
                         }
                     }
-                }
-            };
+                };
 
-            // Wrap with scope constraints (tenant/CBU) for query-time enforcement
-            // This is more efficient and secure than post-search filtering
-            let scoped_query = self.build_scoped_query(
-                tantivy_query,
-                query.tenant_id.as_deref(),
-                query.cbu_id.as_deref(),
-            );
-
-            // Request more results if we have discriminators to filter by
-            let fetch_limit = if query.discriminators.is_empty() {
-                query.limit
-            } else {
-                query.limit * 3 // Fetch more candidates for filtering
-            };
+                // Wrap with scope constraints (tenant/CBU) for query-time enforcement
+                // This is more efficient and secure than post-search filtering
+                let scoped_query = self.build_scoped_query(
+                    tantivy_query,
+                    query.tenant_id.as_deref(),
+                    query.cbu_id.as_deref(),
+                );
 
-            let top_docs = match searcher.search(&scoped_query, &TopDocs::with_limit(fetch_limit)) {
-                Ok(docs) => docs,
-                Err(e) => {
-                    tracing::error!(error = %e, "Search failed");
-                    continue;
-                }
-            };
+                // Request more results if we have discriminators to filter by
+                let fetch_limit = if query.discriminators.is_empty() {
+                    query.limit
+                } else {
+                    query.limit * 3 // Fetch more candidates for filtering
+                };
 
-            for (score, doc_addr) in top_docs {
-                match searcher.doc::<tantivy::TantivyDocument>(doc_addr) {
-                    Ok(doc) => {
-                        // Note: Tenant and CBU scope filtering is now done at QUERY TIME
-                        // via build_scoped_query(). The checks below are kept as defense-in-depth
-                        // but should never filter anything since the query already enforces scope.
-
-                        // Defense-in-depth: Tenant isolation check
-                        if let Some(ref query_tenant) = query.tenant_id {
-                            let doc_tenant = doc
-                                .get_first(self.tenant_field)
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("");
-                            if doc_tenant != query_tenant {
-                                tracing::warn!(
-                                    "Defense-in-depth: tenant mismatch slipped through query filter"
-                                );
-                                continue;
-                            }
+                let top_docs =
+                    match searcher.search(&scoped_query, &TopDocs::with_limit(fetch_limit)) {
+                        Ok(docs) => docs,
+                        Err(e) => {
+                            tracing::error!(error = %e, ?mode, "Search failed");
+                            continue;
                         }
+                    };
+
+                for (score, doc_addr) in top_docs {
+                    match searcher.doc::<tantivy::TantivyDocument>(doc_addr) {
+                        Ok(doc) => {
+                            // Note: Tenant and CBU scope filtering is now done at QUERY TIME
+                            // via build_scoped_query(). The checks below are kept as defense-in-depth
+                            // but should never filter anything since the query already enforces scope.
+
+                            // Defense-in-depth: Tenant isolation check
+                            if let Some(ref query_tenant) = query.tenant_id {
+                                let doc_tenant = doc
+                                    .get_first(self.tenant_field)
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or("");
+                                if doc_tenant != query_tenant {
+                                    tracing::warn!(
+                                        "Defense-in-depth: tenant mismatch slipped through query filter"
+                                    );
+                                    continue;
+                                }
+                            }
+
+                            // Defense-in-depth: CBU scope check
+                            if let Some(ref query_cbu) = query.cbu_id {
+                                let doc_cbu_ids = doc
+                                    .get_first(self.cbu_ids_field)
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or("");
+                                // CBU IDs are stored space-separated
+                                let cbu_list: Vec<&str> = doc_cbu_ids.split_whitespace().collect();
+                                if !cbu_list.contains(&query_cbu.as_str()) {
+                                    tracing::warn!(
+                                        "Defense-in-depth: CBU scope mismatch slipped through query filter"
+                                    );
+                                    continue;
+                                }
+                            }
 
-                        // Defense-in-depth: CBU scope check
-                        if let Some(ref query_cbu) = query.cbu_id {
-                            let doc_cbu_ids = doc
-                                .get_first(self.cbu_ids_field)
+                            let token = doc
+                                .get_first(self.token_field)
                                 .and_then(|v| v.as_str())
-                                .unwrap_or("");
-                            // CBU IDs are stored space-separated
-                            let cbu_list: Vec<&str> = doc_cbu_ids.split_whitespace().collect();
-                            if !cbu_list.contains(&query_cbu.as_str()) {
-                                tracing::warn!(
-                                    "Defense-in-depth: CBU scope mismatch slipped through query filter"
-                                );
+                                .unwrap_or("")
+                                .to_string();
+
+                            // Deduplicate by token
+                            if seen_tokens.contains(&token) {
                                 continue;
                             }
-                        }
-
-                        let token = doc
-                            .get_first(self.token_field)
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("")
-                            .to_string();
+                            seen_tokens.insert(token.clone());
 
-                        // Deduplicate by token
-                        if seen_tokens.contains(&token) {
-                            continue;
+                            let display = doc
+                                .get_first(self.display_field)
+                                .and_then(|v| v.as_str())
+                                .unwrap_or("")
+                                .to_string();
+
+                            // Calculate discriminator boost if we have query discriminators
+                            let final_score = if query.discriminators.is_empty() {
+                                score
+                            } else {
+                                self.calculate_discriminator_score(
+                                    score,
+                                    &doc,
+                                    &query.discriminators,
+                                )
+                            };
+
+                            results.push(SearchMatch {
+                                input: input.clone(),
+                                display,
+                                token,
+                                score: final_score,
+                                normalized_score: 0.0,
+                                mode,
+                            });
+                        }
+                        Err(e) => {
+                            tracing::error!(error = %e, "Failed to retrieve document");
                         }
-                        seen_tokens.insert(token.clone());
-
-                        let display = doc
-                            .get_first(self.display_field)
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("")
-                            .to_string();
-
-                        // Calculate discriminator boost if we have query discriminators
-                        let final_score = if query.discriminators.is_empty() {
-                            score
-                        } else {
-                            self.calculate_discriminator_score(score, &doc, &query.discriminators)
-                        };
-
-                        results.push(SearchMatch {
-                            input: input.clone(),
-                            display,
-                            token,
-                            score: final_score,
-                        });
-                    }
-                    Err(e) => {
-                        tracing::error!(error = %e, "Failed to retrieve document");
                     }
                 }
+
+                if results.len() > hits_before {
+                    break;
+                }
             }
         }
 
@@ -607,8 +703,76 @@ impl SearchIndex for TantivyIndex {
 
         // Limit total results
         results.truncate(query.limit);
+        normalize_scores(&mut results);
 
-        results
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl SearchIndex for TantivyIndex {
+    async fn search(&self, query: &SearchQuery) -> Vec<SearchMatch> {
+        let generation = self.generation.load(Ordering::SeqCst);
+
+        tracing::debug!(
+            nickname = %self.config.nickname,
+            search_key = %query.search_key,
+            values = ?query.values,
+            mode = ?query.mode,
+            generation = generation,
+            "Starting search"
+        );
+
+        let reader_guard = self.reader.read().await;
+        let reader = match reader_guard.as_ref() {
+            Some(r) => r,
+            None => {
+                tracing::warn!(nickname = %self.config.nickname, "No reader available");
+                return vec![];
+            }
+        };
+
+        let searcher = reader.searcher();
+        tracing::debug!(
+            nickname = %self.config.nickname,
+            num_docs = searcher.num_docs(),
+            num_segments = searcher.segment_readers().len(),
+            generation = generation,
+            "Searcher ready"
+        );
+
+        self.search_with_searcher(&searcher, query)
+            .unwrap_or_default()
+    }
+
+    async fn search_batch(
+        &self,
+        queries: &[SearchQuery],
+    ) -> Vec<Result<Vec<SearchMatch>, IndexError>> {
+        let reader_guard = self.reader.read().await;
+        let reader = match reader_guard.as_ref() {
+            Some(r) => r,
+            None => {
+                tracing::warn!(nickname = %self.config.nickname, "No reader available");
+                return queries.iter().map(|_| Err(IndexError::NotReady)).collect();
+            }
+        };
+
+        // One searcher for the whole batch: every query sees the same
+        // point-in-time view of the index and we skip per-call setup.
+        let searcher = reader.searcher();
+        tracing::debug!(
+            nickname = %self.config.nickname,
+            queries = queries.len(),
+            num_docs = searcher.num_docs(),
+            generation = self.generation.load(Ordering::SeqCst),
+            "Starting batch search"
+        );
+
+        queries
+            .iter()
+            .map(|query| self.search_with_searcher(&searcher, query))
+            .collect()
     }
 
     async fn refresh(&self, data: Vec<IndexRecord>) -> Result<(), IndexError> {
@@ -635,56 +799,9 @@ impl SearchIndex for TantivyIndex {
             .map_err(|e| IndexError::BuildFailed(e.to_string()))?;
 
         // Index new data
-        for record in data {
-            let mut doc = tantivy::TantivyDocument::new();
-            doc.add_text(self.token_field, &record.token);
-            doc.add_text(self.display_field, &record.display);
-
-            // Add search values to fields
-            // IndexMode::Exact (lookup tables): store uppercase - codes like DIRECTOR, US
-            // IndexMode::Trigram (entity tables): preserve original case for names
-            for (key, value) in &record.search_values {
-                let is_exact_mode = self.exact_fields.get(key) == self.search_fields.get(key);
-                let indexed_value = if is_exact_mode {
-                    value.to_uppercase()
-                } else {
-                    value.to_string() // preserve original case
-                };
-
-                if let Some(field) = self.search_fields.get(key) {
-                    doc.add_text(*field, &indexed_value);
-                }
-
-                // For Trigram mode, also add to separate exact_field
-                if !is_exact_mode {
-                    if let Some(field) = self.exact_fields.get(key) {
-                        doc.add_text(*field, &indexed_value);
-                    }
-                }
-            }
-
-            // Add discriminator values (stored only, for post-search filtering)
-            for (disc_name, disc_value) in &record.discriminator_values {
-                if let Some(field) = self.discriminator_fields.get(disc_name) {
-                    doc.add_text(*field, disc_value);
-                }
-            }
-
-            // Add tenant ID for multi-tenant isolation
-            if let Some(tenant_id) = &record.tenant_id {
-                doc.add_text(self.tenant_field, tenant_id);
-            }
-
-            // Add CBU IDs for entity universe scoping
-            // Store each CBU ID as a separate term for efficient filtering
-            if !record.cbu_ids.is_empty() {
-                // Join CBU IDs with space - allows term queries to match individual IDs
-                let cbu_ids_str = record.cbu_ids.join(" ");
-                doc.add_text(self.cbu_ids_field, &cbu_ids_str);
-            }
-
+        for record in &data {
             writer
-                .add_document(doc)
+                .add_document(self.build_document(record))
                 .map_err(|e| IndexError::BuildFailed(e.to_string()))?;
         }
 
@@ -724,6 +841,50 @@ impl SearchIndex for TantivyIndex {
         Ok(())
     }
 
+    async fn upsert(&self, data: Vec<IndexRecord>) -> Result<(), IndexError> {
+        // Nothing indexed yet: an upsert is just the initial load
+        if self.reader.read().await.is_none() {
+            return self.refresh(data).await;
+        }
+
+        tracing::info!(
+            nickname = %self.config.nickname,
+            records = data.len(),
+            "Upserting index records"
+        );
+
+        let _write_guard = self.write_lock.lock().await;
+
+        let mut writer: IndexWriter = self
+            .index
+            .writer(50_000_000) // 50MB buffer
+            .map_err(|e| IndexError::BuildFailed(e.to_string()))?;
+
+        for record in &data {
+            // Token is indexed as STRING, so a term delete removes exactly
+            // the previous version of this record
+            writer.delete_term(Term::from_field_text(self.token_field, &record.token));
+            writer
+                .add_document(self.build_document(record))
+                .map_err(|e| IndexError::BuildFailed(e.to_string()))?;
+        }
+
+        writer
+            .commit()
+            .map_err(|e| IndexError::BuildFailed(e.to_string()))?;
+
+        // Reload now rather than waiting for OnCommitWithDelay so callers
+        // see their writes immediately
+        if let Some(reader) = self.reader.read().await.as_ref() {
+            reader
+                .reload()
+                .map_err(|e| IndexError::BuildFailed(format!("Reload failed: {}", e)))?;
+        }
+        self.generation.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+    }
+
     fn is_ready(&self) -> bool {
         self.ready.load(Ordering::SeqCst)
     }
@@ -742,6 +903,8 @@ mod tests {
             display_template: Some("{name}".to_string()),
             index_mode: IndexMode::Trigram,
             filter: None,
+            updated_at_column: None,
+            field_boosts: HashMap::new(),
             search_keys: vec![SearchKeyConfig {
                 name: "name".to_string(),
                 column: "name".to_string(),
@@ -953,6 +1116,221 @@ mod tests {
         assert_eq!(results[0].display, "Apex Fund Services");
     }
 
+    #[tokio::test]
+    async fn test_exact_then_fuzzy_prefers_exact_hit() {
+        let config = sample_config();
+        let index = TantivyIndex::new(config).unwrap();
+        index.refresh(sample_records()).await.unwrap();
+
+        let query = SearchQuery {
+            values: vec!["apex".to_string()],
+            search_key: "name".to_string(),
+            mode: MatchMode::ExactThenFuzzy,
+            limit: 10,
+            discriminators: HashMap::new(),
+            tenant_id: None,
+            cbu_id: None,
+        };
+
+        let results = index.search(&query).await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].display, "Apex Fund Services");
+        assert_eq!(results[0].mode, MatchMode::Exact);
+    }
+
+    #[tokio::test]
+    async fn test_exact_then_fuzzy_falls_back_to_fuzzy() {
+        let config = sample_config();
+        let index = TantivyIndex::new(config).unwrap();
+        index.refresh(sample_records()).await.unwrap();
+
+        // "pacif" is not a whole word, so only the fuzzy pass can find it
+        let query = SearchQuery {
+            values: vec!["pacif".to_string()],
+            search_key: "name".to_string(),
+            mode: MatchMode::ExactThenFuzzy,
+            limit: 10,
+            discriminators: HashMap::new(),
+            tenant_id: None,
+            cbu_id: None,
+        };
+
+        let results = index.search(&query).await;
+
+        assert!(!results.is_empty(), "Fuzzy fallback should find 'pacif'");
+        assert!(results.iter().all(|r| r.mode == MatchMode::Fuzzy));
+        assert!(results
+            .iter()
+            .any(|r| r.display == "Pacific Capital Partners"));
+    }
+
+    #[tokio::test]
+    async fn test_upsert_only_touches_changed_records() {
+        let config = sample_config();
+        let index = TantivyIndex::new(config).unwrap();
+        index.refresh(sample_records()).await.unwrap();
+        let generation = index.generation();
+
+        // What an incremental source returns: only the rows changed since
+        // the watermark (one renamed, one new)
+        let changed = vec![
+            IndexRecord {
+                token: "uuid-4".to_string(),
+                display: "Apex Fund Administration".to_string(),
+                search_values: HashMap::from([(
+                    "name".to_string(),
+                    "apex fund administration".to_string(),
+                )]),
+                discriminator_values: HashMap::new(),
+                tenant_id: None,
+                cbu_ids: vec![],
+            },
+            IndexRecord {
+                token: "uuid-5".to_string(),
+                display: "Nordic Pension Trust".to_string(),
+                search_values: HashMap::from([(
+                    "name".to_string(),
+                    "nordic pension trust".to_string(),
+                )]),
+                discriminator_values: HashMap::new(),
+                tenant_id: None,
+                cbu_ids: vec![],
+            },
+        ];
+        index.upsert(changed).await.unwrap();
+        assert!(index.generation() > generation);
+
+        let query = |value: &str| SearchQuery {
+            values: vec![value.to_string()],
+            search_key: "name".to_string(),
+            mode: MatchMode::Fuzzy,
+            limit: 10,
+            discriminators: HashMap::new(),
+            tenant_id: None,
+            cbu_id: None,
+        };
+
+        // Updated record replaced in place, not duplicated
+        let apex = index.search(&query("apex")).await;
+        assert_eq!(apex.len(), 1);
+        assert_eq!(apex[0].display, "Apex Fund Administration");
+
+        // New record is searchable
+        let nordic = index.search(&query("nordic")).await;
+        assert_eq!(nordic[0].token, "uuid-5");
+
+        // Unchanged records are untouched
+        let pacific = index.search(&query("pacific")).await;
+        let displays: Vec<_> = pacific.iter().map(|r| r.display.as_str()).collect();
+        assert!(displays.contains(&"Asia Pacific Growth Fund"));
+        assert!(displays.contains(&"Pacific Capital Partners"));
+        let lux = index.search(&query("luxembourg")).await;
+        assert_eq!(lux[0].token, "uuid-2");
+    }
+
+    /// Index with `name` and `alias` keys, boosting `name` over `alias`
+    async fn boosted_alias_index() -> TantivyIndex {
+        let mut config = sample_config();
+        config.search_keys.push(SearchKeyConfig {
+            name: "alias".to_string(),
+            column: "alias".to_string(),
+            default: false,
+        });
+        config.field_boosts =
+            HashMap::from([("name".to_string(), 5.0), ("alias".to_string(), 1.0)]);
+        let index = TantivyIndex::new(config).unwrap();
+
+        let record = |token: &str, name: &str, alias: &str| IndexRecord {
+            token: token.to_string(),
+            display: name.to_string(),
+            search_values: HashMap::from([
+                ("name".to_string(), name.to_string()),
+                ("alias".to_string(), alias.to_string()),
+            ]),
+            discriminator_values: HashMap::new(),
+            tenant_id: None,
+            cbu_ids: vec![],
+        };
+        index
+            .refresh(vec![
+                // Short alias field: unboosted BM25 would favour this hit
+                record("alias-hit", "orion holdings", "zenith"),
+                record("name-hit", "zenith capital group holdings", "orion"),
+            ])
+            .await
+            .unwrap();
+        index
+    }
+
+    fn fuzzy_query(search_key: &str, value: &str) -> SearchQuery {
+        SearchQuery {
+            values: vec![value.to_string()],
+            search_key: search_key.to_string(),
+            mode: MatchMode::Fuzzy,
+            limit: 10,
+            discriminators: HashMap::new(),
+            tenant_id: None,
+            cbu_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_field_boost_ranks_name_over_alias() {
+        let index = boosted_alias_index().await;
+
+        for search_key in ["name", "alias"] {
+            let results = index.search(&fuzzy_query(search_key, "zenith")).await;
+
+            let tokens: Vec<_> = results.iter().map(|r| r.token.as_str()).collect();
+            assert_eq!(tokens, vec!["name-hit", "alias-hit"], "key {search_key}");
+            assert_eq!(results[0].normalized_score, 1.0);
+            assert!(results[1].normalized_score < 1.0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_field_boost_ranks_short_input_name_over_alias() {
+        let index = boosted_alias_index().await;
+
+        let results = index.search(&fuzzy_query("alias", "ze")).await;
+
+        let tokens: Vec<_> = results.iter().map(|r| r.token.as_str()).collect();
+        assert_eq!(tokens, vec!["name-hit", "alias-hit"]);
+        assert!(results[0].score > results[1].score);
+    }
+
+    #[tokio::test]
+    async fn test_fuzzy_without_boosts_searches_requested_key_only() {
+        let mut config = sample_config();
+        config.search_keys.push(SearchKeyConfig {
+            name: "alias".to_string(),
+            column: "alias".to_string(),
+            default: false,
+        });
+        let index = TantivyIndex::new(config).unwrap();
+        index
+            .refresh(vec![IndexRecord {
+                token: "uuid-1".to_string(),
+                display: "orion holdings".to_string(),
+                search_values: HashMap::from([
+                    ("name".to_string(), "orion holdings".to_string()),
+                    ("alias".to_string(), "zenith".to_string()),
+                ]),
+                discriminator_values: HashMap::new(),
+                tenant_id: None,
+                cbu_ids: vec![],
+            }])
+            .await
+            .unwrap();
+
+        assert!(index
+            .search(&fuzzy_query("name", "zenith"))
+            .await
+            .is_empty());
+        assert_eq!(index.search(&fuzzy_query("alias", "zenith")).await.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_search_performance() {
         let config = sample_config();
@@ -984,6 +1362,65 @@ mod tests {
             avg_ms
         );
     }
+
+    #[tokio::test]
+    async fn test_search_batch_matches_individual_searches() {
+        let config = sample_config();
+        let index = TantivyIndex::new(config).unwrap();
+        index.refresh(sample_records()).await.unwrap();
+
+        let make_query = |value: &str, search_key: &str, mode: MatchMode| SearchQuery {
+            values: vec![value.to_string()],
+            search_key: search_key.to_string(),
+            mode,
+            limit: 10,
+            discriminators: HashMap::new(),
+            tenant_id: None,
+            cbu_id: None,
+        };
+        let queries = vec![
+            make_query("pacific", "name", MatchMode::Fuzzy),
+            make_query("apex", "name", MatchMode::Exact),
+            make_query("pacific", "no_such_key", MatchMode::Fuzzy),
+            make_query("lux invest", "name", MatchMode::Fuzzy),
+        ];
+
+        let batch = index.search_batch(&queries).await;
+
+        assert_eq!(batch.len(), queries.len());
+
+        // The bad search key fails on its own without poisoning the batch
+        assert!(matches!(batch[2], Err(IndexError::SearchFailed(_))));
+
+        for (i, query) in queries.iter().enumerate() {
+            if i == 2 {
+                continue;
+            }
+            let individual = index.search(query).await;
+            let batched = batch[i].as_ref().expect("query should succeed");
+            let tokens = |m: &[SearchMatch]| m.iter().map(|r| r.token.clone()).collect::<Vec<_>>();
+            assert!(!batched.is_empty(), "query {} should find matches", i);
+            assert_eq!(tokens(batched), tokens(&individual), "query {} diverged", i);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_batch_not_ready() {
+        let index = TantivyIndex::new(sample_config()).unwrap();
+        let query = SearchQuery {
+            values: vec!["pacific".to_string()],
+            search_key: "name".to_string(),
+            mode: MatchMode::Fuzzy,
+            limit: 10,
+            discriminators: HashMap::new(),
+            tenant_id: None,
+            cbu_id: None,
+        };
+
+        let batch = index.search_batch(&[query.clone(), query]).await;
+        assert_eq!(batch.len(), 2);
+        assert!(batch.iter().all(|r| matches!(r, Err(IndexError::NotReady))));
+    }
 }
 
 #[tokio::test]
@@ -999,6 +1436,8 @@ async fn test_exact_search_with_underscore() {
         display_template: Some("{name} ({product_code})".to_string()),
         index_mode: crate::config::IndexMode::Exact,
         filter: None,
+        updated_at_column: None,
+        field_boosts: HashMap::new(),
         search_keys: vec![
             SearchKeyConfig {
                 name: "name".to_string(),
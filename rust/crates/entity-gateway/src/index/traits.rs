@@ -18,6 +18,13 @@ pub struct SearchMatch {
     pub token: String,
     /// Relevance score (meaningful in fuzzy mode)
     pub score: f32,
+    /// `score` divided by the top score in the same index's result set
+    ///
+    /// Raw BM25 scores are not comparable across indexes; this is, so use
+    /// it when merging results from several nicknames.
+    pub normalized_score: f32,
+    /// Which matching pass produced this match (`Exact` or `Fuzzy`)
+    pub mode: MatchMode,
 }
 
 /// Matching mode for search queries
@@ -28,6 +35,50 @@ pub enum MatchMode {
     Fuzzy,
     /// Exact match only
     Exact,
+    /// Exact match first; fuzzy only for inputs with zero exact matches
+    ExactThenFuzzy,
+}
+
+impl MatchMode {
+    /// The concrete passes to run, in order, for this mode
+    ///
+    /// Only `Exact` and `Fuzzy` are ever returned.
+    pub fn passes(self) -> &'static [MatchMode] {
+        match self {
+            MatchMode::Fuzzy => &[MatchMode::Fuzzy],
+            MatchMode::Exact => &[MatchMode::Exact],
+            MatchMode::ExactThenFuzzy => &[MatchMode::Exact, MatchMode::Fuzzy],
+        }
+    }
+}
+
+/// Scale each match's score into `normalized_score` relative to the top hit
+///
+/// Expects `matches` to come from a single index. The best match gets 1.0;
+/// if the top score is not positive every match gets 0.0.
+pub(crate) fn normalize_scores(matches: &mut [SearchMatch]) {
+    let top = matches
+        .iter()
+        .map(|m| m.score)
+        .fold(f32::NEG_INFINITY, f32::max);
+    for m in matches.iter_mut() {
+        m.normalized_score = if top > 0.0 { m.score / top } else { 0.0 };
+    }
+}
+
+/// Merge per-index result sets into one list ranked by `normalized_score`
+///
+/// Ties keep the order of `results` (earlier index first), so the merge is
+/// deterministic. At most `limit` matches are returned.
+pub fn merge_ranked(results: Vec<Vec<SearchMatch>>, limit: usize) -> Vec<SearchMatch> {
+    let mut merged: Vec<SearchMatch> = results.into_iter().flatten().collect();
+    merged.sort_by(|a, b| {
+        b.normalized_score
+            .partial_cmp(&a.normalized_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    merged.truncate(limit);
+    merged
 }
 
 /// A search query to execute against an index
@@ -61,11 +112,36 @@ pub trait SearchIndex: Send + Sync {
     /// Returns matches sorted by relevance (highest score first).
     async fn search(&self, query: &SearchQuery) -> Vec<SearchMatch>;
 
+    /// Execute several queries against this index in one call
+    ///
+    /// Results are returned in the same order as `queries`. Each query
+    /// succeeds or fails on its own, so one bad query (e.g. an unknown
+    /// search key) does not fail the rest of the batch.
+    ///
+    /// The default implementation simply calls `search` per query;
+    /// implementations should override it to share per-call setup.
+    async fn search_batch(
+        &self,
+        queries: &[SearchQuery],
+    ) -> Vec<Result<Vec<SearchMatch>, IndexError>> {
+        let mut results = Vec::with_capacity(queries.len());
+        for query in queries {
+            results.push(Ok(self.search(query).await));
+        }
+        results
+    }
+
     /// Rebuild the index from source data
     ///
     /// This replaces all existing index data with the provided records.
     async fn refresh(&self, data: Vec<IndexRecord>) -> Result<(), IndexError>;
 
+    /// Insert or update individual records, keyed by token
+    ///
+    /// Existing documents with the same token are replaced; every other
+    /// document is left untouched. Used for incremental refresh.
+    async fn upsert(&self, data: Vec<IndexRecord>) -> Result<(), IndexError>;
+
     /// Check if the index is ready to serve queries
     ///
     /// Returns false if the index hasn't been populated yet.
@@ -100,3 +176,46 @@ pub enum IndexError {
     #[error("Search failed: {0}")]
     SearchFailed(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(token: &str, score: f32) -> SearchMatch {
+        SearchMatch {
+            input: "q".to_string(),
+            display: token.to_string(),
+            token: token.to_string(),
+            score,
+            normalized_score: 0.0,
+            mode: MatchMode::Fuzzy,
+        }
+    }
+
+    #[test]
+    fn test_merge_ranked_interleaves_by_normalized_score() {
+        // Index A scores in the tens, index B in the hundredths
+        let mut a = vec![hit("a1", 40.0), hit("a2", 20.0), hit("a3", 10.0)];
+        let mut b = vec![hit("b1", 0.09), hit("b2", 0.06), hit("b3", 0.03)];
+        normalize_scores(&mut a);
+        normalize_scores(&mut b);
+
+        assert_eq!(a[0].normalized_score, 1.0);
+        assert_eq!(b[0].normalized_score, 1.0);
+
+        let merged = merge_ranked(vec![a, b], 10);
+        let tokens: Vec<&str> = merged.iter().map(|m| m.token.as_str()).collect();
+
+        // a: 1.0, 0.5, 0.25 / b: 1.0, 0.667, 0.333
+        assert_eq!(tokens, vec!["a1", "b1", "b2", "a2", "b3", "a3"]);
+    }
+
+    #[test]
+    fn test_merge_ranked_respects_limit() {
+        let mut a = vec![hit("a1", 3.0), hit("a2", 1.0)];
+        normalize_scores(&mut a);
+        let merged = merge_ranked(vec![a, vec![]], 1);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].token, "a1");
+    }
+}
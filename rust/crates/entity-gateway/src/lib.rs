@@ -67,7 +67,7 @@ mod server;
 // parser + SearchQuery/SearchSchema/DiscriminatorDef + DateMatchMode)
 // were deleted 2026-05-14 — see git history — once dead-code sweep
 // confirmed zero consumers inside or outside the crate.
-pub use config::{EntityConfig, GatewayConfig, RefreshConfig, StartupMode};
-pub use index::{IndexRegistry, TantivyIndex};
+pub use config::{ConfigError, EntityConfig, GatewayConfig, RefreshConfig, StartupMode};
+pub use index::{merge_ranked, IndexRegistry, MatchMode, SearchMatch, TantivyIndex};
 pub use refresh::{run_refresh_loop, RefreshPipeline};
 pub use server::EntityGatewayService;
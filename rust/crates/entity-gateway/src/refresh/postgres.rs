@@ -3,18 +3,70 @@
 //! This module handles loading entity data from PostgreSQL and
 //! converting it into `IndexRecord`s for the search index.
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
+use chrono::{DateTime, TimeZone, Utc};
 use sqlx::{PgPool, Row};
 
 use crate::config::{EntityConfig, GatewayConfig};
-use crate::index::{IndexRecord, IndexRegistry};
+use crate::index::{IndexRecord, IndexRegistry, SearchIndex, TantivyIndex};
 
 /// Pipeline for refreshing indexes from Postgres
 pub struct RefreshPipeline {
     pool: PgPool,
     config: GatewayConfig,
+    /// Per-nickname high-water mark of `updated_at_column` seen so far
+    watermarks: Mutex<HashMap<String, Watermark>>,
+}
+
+/// Incremental refresh position for one nickname
+///
+/// Incremental loads select rows with `updated_at >= at`, so rows sharing
+/// the boundary timestamp are never skipped; `tokens` lists the rows
+/// already loaded at exactly `at`, which are the ones left out.
+#[derive(Debug, Clone, PartialEq)]
+struct Watermark {
+    at: DateTime<Utc>,
+    tokens: HashSet<String>,
+}
+
+impl Watermark {
+    /// Position before every row, for tables that were empty when loaded
+    ///
+    /// 0001-01-01 rather than `DateTime::MIN_UTC`, which Postgres can't bind.
+    fn start() -> Self {
+        Self {
+            at: Utc.with_ymd_and_hms(1, 1, 1, 0, 0, 0).unwrap(),
+            tokens: HashSet::new(),
+        }
+    }
+
+    /// Whether a row was already loaded at this position
+    fn covers(&self, token: &str, updated_at: Option<DateTime<Utc>>) -> bool {
+        updated_at == Some(self.at) && self.tokens.contains(token)
+    }
+
+    /// The latest timestamp among `rows` and the tokens stamped with it
+    fn of<'a>(rows: impl IntoIterator<Item = (&'a str, Option<DateTime<Utc>>)>) -> Option<Self> {
+        let mut high: Option<Self> = None;
+        for (token, updated_at) in rows {
+            let Some(at) = updated_at else { continue };
+            match &mut high {
+                Some(mark) if at < mark.at => {}
+                Some(mark) if at == mark.at => {
+                    mark.tokens.insert(token.to_string());
+                }
+                _ => {
+                    high = Some(Self {
+                        at,
+                        tokens: HashSet::from([token.to_string()]),
+                    })
+                }
+            }
+        }
+        high
+    }
 }
 
 impl RefreshPipeline {
@@ -25,12 +77,16 @@ impl RefreshPipeline {
 
         let pool = PgPool::connect(&db_url).await?;
 
-        Ok(Self { pool, config })
+        Ok(Self::with_pool(pool, config))
     }
 
     /// Create a pipeline with an existing pool
     pub fn with_pool(pool: PgPool, config: GatewayConfig) -> Self {
-        Self { pool, config }
+        Self {
+            pool,
+            config,
+            watermarks: Mutex::new(HashMap::new()),
+        }
     }
 
     /// Refresh a single entity's index
@@ -38,6 +94,27 @@ impl RefreshPipeline {
         &self,
         entity_config: &EntityConfig,
     ) -> Result<Vec<IndexRecord>, sqlx::Error> {
+        let (records, high_water) = self.load_records(entity_config, None).await?;
+        if entity_config.updated_at_column.is_some() {
+            // An empty table still gets a watermark, so the next pass is
+            // incremental rather than another full reload
+            self.advance_watermark(
+                &entity_config.nickname,
+                high_water.unwrap_or_else(Watermark::start),
+            );
+        }
+        Ok(records)
+    }
+
+    /// Load records for an entity, optionally only those changed since `since`
+    ///
+    /// Returns the records plus the watermark of the latest of them (None
+    /// when the entity has no `updated_at_column` or no new rows came back).
+    async fn load_records(
+        &self,
+        entity_config: &EntityConfig,
+        since: Option<&Watermark>,
+    ) -> Result<(Vec<IndexRecord>, Option<Watermark>), sqlx::Error> {
         // Build column list
         let mut columns = vec![entity_config.return_key.clone()];
 
@@ -66,14 +143,28 @@ impl RefreshPipeline {
             }
         }
 
+        // Add the last-modified column so we can track the watermark
+        if let Some(updated_at) = &entity_config.updated_at_column {
+            if !columns.contains(updated_at) {
+                columns.push(updated_at.clone());
+            }
+        }
+
         // Build query
         let column_list = columns.join(", ");
         let mut query = format!("SELECT {} FROM {}", column_list, entity_config.source_table);
 
-        // Add filter if present
+        let mut conditions = Vec::new();
         if let Some(filter) = &entity_config.filter {
+            conditions.push(format!("({})", filter));
+        }
+        let since = since.filter(|_| entity_config.updated_at_column.is_some());
+        if let (Some(updated_at), Some(_)) = (&entity_config.updated_at_column, since) {
+            conditions.push(format!("{} >= $1", updated_at));
+        }
+        if !conditions.is_empty() {
             query.push_str(" WHERE ");
-            query.push_str(filter);
+            query.push_str(&conditions.join(" AND "));
         }
 
         tracing::debug!(
@@ -83,12 +174,27 @@ impl RefreshPipeline {
         );
 
         // Execute query
-        let rows = sqlx::query(&query).fetch_all(&self.pool).await?;
+        let mut sql = sqlx::query(&query);
+        if let Some(since) = since {
+            sql = sql.bind(since.at);
+        }
+        let rows = sql.fetch_all(&self.pool).await?;
+
+        // A column that doesn't decode as timestamptz is a config error: skipping
+        // it would leave the watermark stuck and make every refresh a full reload
+        let mut stamps = Vec::with_capacity(rows.len());
+        for row in &rows {
+            stamps.push(match &entity_config.updated_at_column {
+                Some(col) => row.try_get::<Option<DateTime<Utc>>, _>(col.as_str())?,
+                None => None,
+            });
+        }
 
         // Convert rows to IndexRecords
-        let records: Vec<IndexRecord> = rows
+        let records: Vec<(IndexRecord, Option<DateTime<Utc>>)> = rows
             .into_iter()
-            .filter_map(|row| {
+            .zip(stamps)
+            .filter_map(|(row, updated_at)| {
                 // Get token (primary key or code)
                 let token: String =
                     match row.try_get::<uuid::Uuid, _>(entity_config.return_key.as_str()) {
@@ -160,26 +266,144 @@ impl RefreshPipeline {
                     }
                 }
 
-                Some(IndexRecord {
-                    token,
-                    display,
-                    search_values,
-                    discriminator_values,
-                    // TODO: Load tenant_id and cbu_ids from database when available
-                    // For now, these are not populated - tenant isolation happens at query time
-                    tenant_id: None,
-                    cbu_ids: vec![],
-                })
+                Some((
+                    IndexRecord {
+                        token,
+                        display,
+                        search_values,
+                        discriminator_values,
+                        // TODO: Load tenant_id and cbu_ids from database when available
+                        // For now, these are not populated - tenant isolation happens at query time
+                        tenant_id: None,
+                        cbu_ids: vec![],
+                    },
+                    updated_at,
+                ))
+            })
+            // Rows at the boundary timestamp that the last pass already loaded
+            .filter(|(record, updated_at)| {
+                !since.is_some_and(|mark| mark.covers(&record.token, *updated_at))
             })
             .collect();
 
+        let high_water = Watermark::of(
+            records
+                .iter()
+                .map(|(record, updated_at)| (record.token.as_str(), *updated_at)),
+        );
+        let records: Vec<IndexRecord> = records.into_iter().map(|(record, _)| record).collect();
+
         tracing::info!(
             nickname = %entity_config.nickname,
             records = records.len(),
+            incremental = since.is_some(),
             "Loaded records from database"
         );
 
-        Ok(records)
+        Ok((records, high_water))
+    }
+
+    /// Reload only the rows of one nickname changed since `since`
+    ///
+    /// Changed rows are upserted into the existing index; every other
+    /// document is left as-is. Rows deleted at the source are not seen by
+    /// an incremental pass, so a periodic `refresh_all` is still needed.
+    /// Returns the new high-water mark (unchanged when nothing changed),
+    /// which is also stored as this nickname's watermark.
+    ///
+    /// Entities without an `updated_at_column` fall back to a full reload.
+    pub async fn refresh_incremental(
+        &self,
+        registry: &IndexRegistry,
+        nickname: &str,
+        since: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>, Box<dyn std::error::Error + Send + Sync>> {
+        let entity_config = registry
+            .get_config(nickname)
+            .ok_or_else(|| format!("Unknown entity nickname: {}", nickname))?;
+        let index = registry
+            .get(nickname)
+            .await
+            .ok_or_else(|| format!("No index registered for: {}", nickname))?;
+
+        if entity_config.updated_at_column.is_none() {
+            let (records, _) = self.load_records(entity_config, None).await?;
+            index.refresh(records).await?;
+            return Ok(since);
+        }
+
+        // Tokens already loaded at `since` are only known for the stored mark
+        let since = match self.stored_watermark(nickname) {
+            Some(mark) if mark.at == since => mark,
+            _ => Watermark {
+                at: since,
+                tokens: HashSet::new(),
+            },
+        };
+        let (records, high_water) = self.load_records(entity_config, Some(&since)).await?;
+        if !records.is_empty() {
+            index.upsert(records).await?;
+        }
+
+        Ok(self.advance_watermark(nickname, high_water.unwrap_or(since)))
+    }
+
+    /// Incrementally refresh every nickname from its stored watermark
+    ///
+    /// Nicknames with no known watermark yet get a full refresh, which
+    /// also establishes their watermark. A failing nickname doesn't stop
+    /// the others; the failures are returned together once all have run.
+    pub async fn refresh_all_incremental(
+        &self,
+        registry: &IndexRegistry,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut failures = Vec::new();
+        for nickname in registry.nicknames() {
+            let result = match self.watermark(nickname) {
+                Some(since) => self
+                    .refresh_incremental(registry, nickname, since)
+                    .await
+                    .map(|_| ()),
+                None => self.refresh_nickname(registry, nickname).await,
+            };
+            if let Err(e) = result {
+                tracing::error!(nickname = nickname, error = %e, "Incremental refresh failed");
+                failures.push(format!("{}: {}", nickname, e));
+            }
+        }
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Incremental refresh failed for {}", failures.join("; ")).into())
+        }
+    }
+
+    /// The stored high-water mark for a nickname, if one is known
+    pub fn watermark(&self, nickname: &str) -> Option<DateTime<Utc>> {
+        self.stored_watermark(nickname).map(|mark| mark.at)
+    }
+
+    fn stored_watermark(&self, nickname: &str) -> Option<Watermark> {
+        self.watermarks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(nickname)
+            .cloned()
+    }
+
+    /// Move a nickname's watermark forward (never backward); returns the new value
+    ///
+    /// A candidate at the same timestamp adds its tokens to the stored ones.
+    fn advance_watermark(&self, nickname: &str, candidate: Watermark) -> DateTime<Utc> {
+        let mut watermarks = self.watermarks.lock().unwrap_or_else(|e| e.into_inner());
+        match watermarks.get_mut(nickname) {
+            Some(entry) if candidate.at == entry.at => entry.tokens.extend(candidate.tokens),
+            Some(entry) if candidate.at < entry.at => {}
+            _ => {
+                watermarks.insert(nickname.to_string(), candidate);
+            }
+        }
+        watermarks[nickname].at
     }
 
     /// Refresh all entity indexes
@@ -214,6 +438,30 @@ impl RefreshPipeline {
         Ok(())
     }
 
+    /// Refresh a single nickname without touching the others
+    ///
+    /// Builds a fresh index off to the side and then swaps it into the
+    /// registry via `IndexRegistry::replace`, so searches keep hitting the
+    /// old index until the new one is fully populated.
+    pub async fn refresh_nickname(
+        &self,
+        registry: &IndexRegistry,
+        nickname: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let entity_config = registry
+            .get_config(nickname)
+            .ok_or_else(|| format!("Unknown entity nickname: {}", nickname))?;
+
+        let records = self.refresh_entity(entity_config).await?;
+
+        let index = TantivyIndex::new(entity_config.clone())?;
+        index.refresh(records).await?;
+        registry.replace(nickname, Arc::new(index)).await;
+
+        tracing::info!(nickname = nickname, "Replaced index");
+        Ok(())
+    }
+
     /// Get the database pool
     pub fn pool(&self) -> &PgPool {
         &self.pool
@@ -250,9 +498,15 @@ fn build_display_from_template(
     result.trim().to_string()
 }
 
+/// Every this many ticks the refresh loop does a full reload instead of an
+/// incremental one, to drop rows deleted at the source
+const FULL_REFRESH_EVERY: u64 = 12;
+
 /// Background refresh loop
 ///
-/// Runs periodically to keep indexes fresh.
+/// Runs periodically to keep indexes fresh. Most ticks only reload rows
+/// changed since each nickname's watermark; every `FULL_REFRESH_EVERY`th
+/// tick rebuilds every index from scratch.
 pub async fn run_refresh_loop(
     pipeline: RefreshPipeline,
     registry: Arc<IndexRegistry>,
@@ -260,12 +514,18 @@ pub async fn run_refresh_loop(
 ) {
     let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(interval_secs));
 
-    loop {
+    for tick in 0u64.. {
         interval.tick().await;
 
-        tracing::info!("Starting scheduled index refresh");
+        let full = tick % FULL_REFRESH_EVERY == 0;
+        tracing::info!(full, "Starting scheduled index refresh");
 
-        if let Err(e) = pipeline.refresh_all(&registry).await {
+        let result = if full {
+            pipeline.refresh_all(&registry).await
+        } else {
+            pipeline.refresh_all_incremental(&registry).await
+        };
+        if let Err(e) = result {
             tracing::error!(error = %e, "Scheduled index refresh failed");
         } else {
             tracing::info!("Scheduled index refresh complete");
@@ -275,6 +535,248 @@ pub async fn run_refresh_loop(
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    fn lazy_pipeline() -> RefreshPipeline {
+        let config = GatewayConfig::from_yaml(
+            r#"
+refresh:
+  interval_secs: 60
+  startup_mode: async
+database:
+  connection_string_env: "DATABASE_URL"
+entities: {}
+"#,
+        )
+        .unwrap();
+        // connect_lazy never touches the network until a query runs
+        let pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        RefreshPipeline::with_pool(pool, config)
+    }
+
+    #[tokio::test]
+    async fn test_watermark_only_moves_forward() {
+        let pipeline = lazy_pipeline();
+        let t1 = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap();
+
+        let mark = |at, token: &str| Watermark {
+            at,
+            tokens: HashSet::from([token.to_string()]),
+        };
+
+        assert_eq!(pipeline.watermark("fund"), None);
+        assert_eq!(pipeline.advance_watermark("fund", mark(t2, "a")), t2);
+        assert_eq!(pipeline.advance_watermark("fund", mark(t1, "b")), t2);
+        assert_eq!(pipeline.advance_watermark("fund", mark(t2, "c")), t2);
+        assert_eq!(pipeline.watermark("fund"), Some(t2));
+        assert_eq!(
+            pipeline.stored_watermark("fund").unwrap().tokens,
+            HashSet::from(["a".to_string(), "c".to_string()])
+        );
+        assert_eq!(pipeline.watermark("person"), None);
+    }
+
+    #[test]
+    fn test_watermark_keeps_tokens_at_latest_timestamp() {
+        let t1 = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap();
+
+        let mark = Watermark::of([
+            ("a", Some(t2)),
+            ("b", Some(t1)),
+            ("c", Some(t2)),
+            ("d", None),
+        ])
+        .unwrap();
+        assert_eq!(mark.at, t2);
+        assert_eq!(
+            mark.tokens,
+            HashSet::from(["a".to_string(), "c".to_string()])
+        );
+        assert!(mark.covers("a", Some(t2)));
+        assert!(!mark.covers("b", Some(t2)));
+        assert!(!mark.covers("a", Some(t1)));
+        assert_eq!(Watermark::of([("d", None)]), None);
+    }
+
+    /// Pipeline over a scratch `fund` table with an `updated_at` watermark column
+    ///
+    /// Requires a database:
+    ///   DATABASE_URL="postgresql:///data_designer" cargo test -p entity-gateway -- --ignored
+    async fn scratch_pipeline(updated_at_type: &str) -> (RefreshPipeline, IndexRegistry, String) {
+        let url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| panic!("DATABASE_URL must be set for integration tests"));
+        let pool = PgPool::connect(&url).await.unwrap();
+        let table = format!("gw_refresh_test_{}", uuid::Uuid::new_v4().simple());
+        sqlx::query(&format!(
+            "CREATE TABLE {} (id uuid PRIMARY KEY, name text NOT NULL, updated_at {} NOT NULL)",
+            table, updated_at_type
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let config = GatewayConfig::from_yaml(&format!(
+            r#"
+refresh:
+  interval_secs: 60
+  startup_mode: async
+database:
+  connection_string_env: "DATABASE_URL"
+entities:
+  fund:
+    nickname: "fund"
+    source_table: "{}"
+    return_key: "id"
+    updated_at_column: "updated_at"
+    search_keys:
+      - name: "name"
+        column: "name"
+        default: true
+"#,
+            table
+        ))
+        .unwrap();
+        let registry = IndexRegistry::new(config.entities.clone());
+        let index = TantivyIndex::new(config.entities["fund"].clone()).unwrap();
+        registry.register("fund".to_string(), Arc::new(index)).await;
+
+        (RefreshPipeline::with_pool(pool, config), registry, table)
+    }
+
+    async fn insert_fund(pipeline: &RefreshPipeline, table: &str, name: &str, at: &str) {
+        sqlx::query(&format!(
+            "INSERT INTO {} (id, name, updated_at) VALUES ($1, $2, $3::text::timestamptz)",
+            table
+        ))
+        .bind(uuid::Uuid::new_v4())
+        .bind(name)
+        .bind(at)
+        .execute(pipeline.pool())
+        .await
+        .unwrap();
+    }
+
+    async fn find(registry: &IndexRegistry, name: &str) -> usize {
+        let query = crate::index::SearchQuery {
+            values: vec![name.to_string()],
+            search_key: "name".to_string(),
+            mode: crate::index::MatchMode::Exact,
+            limit: 10,
+            discriminators: HashMap::new(),
+            tenant_id: None,
+            cbu_id: None,
+        };
+        registry
+            .get("fund")
+            .await
+            .unwrap()
+            .search(&query)
+            .await
+            .len()
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires database
+    async fn test_incremental_refresh_picks_up_changed_rows() {
+        let (pipeline, registry, table) = scratch_pipeline("timestamptz").await;
+        insert_fund(&pipeline, &table, "Alpha", "2026-01-01T00:00:00Z").await;
+
+        // First pass has no watermark yet, so it is a full reload
+        pipeline.refresh_all_incremental(&registry).await.unwrap();
+        let t1 = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(pipeline.watermark("fund"), Some(t1));
+        assert_eq!(find(&registry, "Alpha").await, 1);
+
+        insert_fund(&pipeline, &table, "Beta", "2026-02-01T00:00:00Z").await;
+        pipeline.refresh_all_incremental(&registry).await.unwrap();
+        let t2 = Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(pipeline.watermark("fund"), Some(t2));
+        assert_eq!(find(&registry, "Alpha").await, 1);
+        assert_eq!(find(&registry, "Beta").await, 1);
+
+        sqlx::query(&format!("DROP TABLE {}", table))
+            .execute(pipeline.pool())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires database
+    async fn test_incremental_refresh_picks_up_rows_at_the_watermark() {
+        let (pipeline, registry, table) = scratch_pipeline("timestamptz").await;
+        insert_fund(&pipeline, &table, "Alpha", "2026-01-01T00:00:00Z").await;
+        pipeline.refresh_all_incremental(&registry).await.unwrap();
+
+        // Same timestamp as the watermark, committed after the last pass
+        insert_fund(&pipeline, &table, "Beta", "2026-01-01T00:00:00Z").await;
+        pipeline.refresh_all_incremental(&registry).await.unwrap();
+        assert_eq!(find(&registry, "Beta").await, 1);
+        assert_eq!(
+            pipeline.stored_watermark("fund").unwrap().tokens.len(),
+            2,
+            "both rows at the boundary are remembered"
+        );
+
+        sqlx::query(&format!("DROP TABLE {}", table))
+            .execute(pipeline.pool())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires database
+    async fn test_empty_table_gets_a_watermark() {
+        let (pipeline, registry, table) = scratch_pipeline("timestamptz").await;
+
+        pipeline.refresh_all_incremental(&registry).await.unwrap();
+        assert_eq!(pipeline.watermark("fund"), Some(Watermark::start().at));
+
+        insert_fund(&pipeline, &table, "Alpha", "2026-01-01T00:00:00Z").await;
+        pipeline.refresh_all_incremental(&registry).await.unwrap();
+        let t1 = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(pipeline.watermark("fund"), Some(t1));
+        assert_eq!(find(&registry, "Alpha").await, 1);
+
+        sqlx::query(&format!("DROP TABLE {}", table))
+            .execute(pipeline.pool())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires database
+    async fn test_incremental_refresh_returns_failures() {
+        let (pipeline, registry, table) = scratch_pipeline("timestamptz").await;
+        sqlx::query(&format!("DROP TABLE {}", table))
+            .execute(pipeline.pool())
+            .await
+            .unwrap();
+
+        let err = pipeline
+            .refresh_all_incremental(&registry)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("fund"), "{err}");
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires database
+    async fn test_refresh_fails_on_non_timestamptz_watermark_column() {
+        let (pipeline, registry, table) = scratch_pipeline("timestamp").await;
+        insert_fund(&pipeline, &table, "Alpha", "2026-01-01T00:00:00Z").await;
+
+        let config = registry.get_config("fund").unwrap();
+        assert!(pipeline.refresh_entity(config).await.is_err());
+        assert_eq!(pipeline.watermark("fund"), None);
+
+        sqlx::query(&format!("DROP TABLE {}", table))
+            .execute(pipeline.pool())
+            .await
+            .unwrap();
+    }
+
     #[test]
     fn test_display_template() {
         // This test would require a mock row, which is complex
@@ -7,8 +7,8 @@ use std::sync::Arc;
 
 use tonic::{Request, Response, Status};
 
-use crate::config::IndexMode;
-use crate::index::{IndexRegistry, MatchMode, SearchQuery};
+use crate::config::{EntityConfig, IndexMode};
+use crate::index::{merge_ranked, IndexRegistry, MatchMode, SearchIndex, SearchQuery};
 use crate::proto::ob::gateway::v1::{
     entity_gateway_server::EntityGateway, DiscriminatorInfo, DiscriminatorType, EnumValue,
     GetEntityConfigRequest, GetEntityConfigResponse, Match, ResolutionModeHint, SearchKeyInfo,
@@ -25,6 +25,26 @@ impl EntityGatewayService {
     pub fn new(registry: Arc<IndexRegistry>) -> Self {
         Self { registry }
     }
+
+    /// Look up the config and a ready index for a nickname
+    async fn ready_index(
+        &self,
+        nickname: &str,
+    ) -> Result<(&EntityConfig, Arc<dyn SearchIndex>), Status> {
+        let entity_config = self
+            .registry
+            .get_config(nickname)
+            .ok_or_else(|| Status::not_found(format!("Unknown entity type: {}", nickname)))?;
+
+        let index = self
+            .registry
+            .get(nickname)
+            .await
+            .filter(|index| index.is_ready())
+            .ok_or_else(|| Status::unavailable(format!("Index not ready for: {}", nickname)))?;
+
+        Ok((entity_config, index))
+    }
 }
 
 #[tonic::async_trait]
@@ -35,25 +55,7 @@ impl EntityGateway for EntityGatewayService {
     ) -> Result<Response<SearchResponse>, Status> {
         let req = request.into_inner();
 
-        // Validate nickname
-        let entity_config = self
-            .registry
-            .get_config(&req.nickname)
-            .ok_or_else(|| Status::not_found(format!("Unknown entity type: {}", req.nickname)))?;
-
-        // Get index
-        let index =
-            self.registry.get(&req.nickname).await.ok_or_else(|| {
-                Status::unavailable(format!("Index not ready for: {}", req.nickname))
-            })?;
-
-        // Check if index is ready
-        if !index.is_ready() {
-            return Err(Status::unavailable(format!(
-                "Index not ready for: {}",
-                req.nickname
-            )));
-        }
+        let (entity_config, index) = self.ready_index(&req.nickname).await?;
 
         // Resolve search key (use default if not specified)
         let search_key = req
@@ -86,21 +88,38 @@ impl EntityGateway for EntityGatewayService {
         let mode = match SearchMode::try_from(req.mode).unwrap_or(SearchMode::Fuzzy) {
             SearchMode::Fuzzy => MatchMode::Fuzzy,
             SearchMode::Exact => MatchMode::Exact,
+            SearchMode::ExactThenFuzzy => MatchMode::ExactThenFuzzy,
         };
 
         // Build query with discriminators and tenant scope from request
+        let limit = req.limit.unwrap_or(10) as usize;
         let query = SearchQuery {
             values: req.values,
             search_key,
             mode,
-            limit: req.limit.unwrap_or(10) as usize,
+            limit,
             discriminators: req.discriminators,
             tenant_id: req.tenant_id,
             cbu_id: req.cbu_id,
         };
 
-        // Execute search
-        let matches = index.search(&query).await;
+        // Execute search against the primary index, then any additional
+        // nicknames (using their default key when they lack the requested one)
+        let mut results = vec![index.search(&query).await];
+        for nickname in &req.additional_nicknames {
+            let (config, index) = self.ready_index(nickname).await?;
+            let search_key = if config.get_search_key(&query.search_key).is_some() {
+                query.search_key.clone()
+            } else {
+                config.default_search_key().name.clone()
+            };
+            let query = SearchQuery {
+                search_key,
+                ..query.clone()
+            };
+            results.push(index.search(&query).await);
+        }
+        let matches = merge_ranked(results, limit);
 
         // Convert to proto response
         let response = SearchResponse {
@@ -111,6 +130,12 @@ impl EntityGateway for EntityGatewayService {
                     display: m.display,
                     token: m.token,
                     score: m.score,
+                    normalized_score: m.normalized_score,
+                    matched_mode: match m.mode {
+                        MatchMode::Exact => SearchMode::Exact,
+                        MatchMode::Fuzzy | MatchMode::ExactThenFuzzy => SearchMode::Fuzzy,
+                    }
+                    .into(),
                 })
                 .collect(),
         };
@@ -265,6 +290,8 @@ mod tests {
             display_template: None,
             index_mode: crate::config::IndexMode::Trigram,
             filter: None,
+            updated_at_column: None,
+            field_boosts: HashMap::new(),
             search_keys: vec![SearchKeyConfig {
                 name: "name".to_string(),
                 column: "name".to_string(),
@@ -294,6 +321,7 @@ mod tests {
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         });
 
         let result = service.search(request).await;
@@ -339,10 +367,75 @@ mod tests {
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         });
 
         let result = service.search(request).await;
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
+
+    #[tokio::test]
+    async fn test_additional_nicknames_merge_by_normalized_score() {
+        use crate::index::{IndexRecord, SearchIndex};
+
+        fn record(token: &str, name: &str) -> IndexRecord {
+            IndexRecord {
+                token: token.to_string(),
+                display: name.to_string(),
+                search_values: HashMap::from([("name".to_string(), name.to_string())]),
+                discriminator_values: HashMap::new(),
+                tenant_id: None,
+                cbu_ids: vec![],
+            }
+        }
+
+        let mut configs = HashMap::new();
+        configs.insert("people".to_string(), sample_config());
+        configs.insert("funds".to_string(), sample_config());
+        let registry = Arc::new(IndexRegistry::new(configs));
+
+        let people = TantivyIndex::new(sample_config()).unwrap();
+        people.refresh(vec![record("p-1", "apex")]).await.unwrap();
+        let funds = TantivyIndex::new(sample_config()).unwrap();
+        funds
+            .refresh(vec![
+                record("f-1", "apex capital"),
+                record("f-2", "apex capital partners"),
+                record("f-3", "north capital"),
+            ])
+            .await
+            .unwrap();
+        registry
+            .register("people".to_string(), Arc::new(people))
+            .await;
+        registry
+            .register("funds".to_string(), Arc::new(funds))
+            .await;
+
+        let service = EntityGatewayService::new(registry);
+        let request = Request::new(SearchRequest {
+            nickname: "people".to_string(),
+            values: vec!["apex".to_string()],
+            search_key: None,
+            mode: SearchMode::Fuzzy as i32,
+            limit: None,
+            discriminators: HashMap::new(),
+            tenant_id: None,
+            cbu_id: None,
+            additional_nicknames: vec!["funds".to_string()],
+        });
+
+        let matches = service.search(request).await.unwrap().into_inner().matches;
+        let tokens: Vec<&str> = matches.iter().map(|m| m.token.as_str()).collect();
+        assert!(tokens.contains(&"p-1"), "{tokens:?}");
+        assert!(tokens.contains(&"f-1"), "{tokens:?}");
+
+        // Each index's best hit normalizes to 1.0 and ranks ahead of the rest
+        assert_eq!(matches[0].normalized_score, 1.0);
+        assert_eq!(matches[1].normalized_score, 1.0);
+        assert!(matches
+            .windows(2)
+            .all(|w| w[0].normalized_score >= w[1].normalized_score));
+    }
 }
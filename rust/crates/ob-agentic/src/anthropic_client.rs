@@ -5,18 +5,82 @@
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use serde::Deserialize;
+use std::sync::{Arc, Mutex};
 
-use super::llm_client::{LlmClient, ToolCallResult, ToolDefinition};
+use super::llm_client::{
+    ApiError, LlmClient, OnToken, SseDecoder, TokenUsage, ToolCallResult, ToolDefinition,
+};
+
+/// Usage block on a Messages API response
+#[derive(Debug, Deserialize)]
+struct ApiUsage {
+    input_tokens: u64,
+    output_tokens: u64,
+}
+
+impl From<ApiUsage> for TokenUsage {
+    fn from(usage: ApiUsage) -> Self {
+        TokenUsage {
+            prompt: usage.input_tokens,
+            completion: usage.output_tokens,
+            total: usage.input_tokens + usage.output_tokens,
+        }
+    }
+}
+
+/// Server-sent event on a streaming Messages API response
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum StreamEvent {
+    MessageStart {
+        message: StreamMessage,
+    },
+    ContentBlockDelta {
+        delta: StreamDelta,
+    },
+    MessageDelta {
+        usage: StreamUsage,
+    },
+    Error {
+        error: serde_json::Value,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct StreamMessage {
+    usage: ApiUsage,
+}
+
+#[derive(Deserialize)]
+struct StreamDelta {
+    /// Set on `text_delta`; absent for tool input deltas
+    #[serde(default)]
+    text: Option<String>,
+}
+
+/// `message_delta` carries the cumulative output token count
+#[derive(Deserialize)]
+struct StreamUsage {
+    output_tokens: u64,
+}
 
 /// Default Anthropic model
 const DEFAULT_MODEL: &str = "claude-sonnet-4-6";
 
+/// Default Messages API endpoint
+const API_URL: &str = "https://api.anthropic.com/v1/messages";
+
 /// Anthropic Claude API client
 #[derive(Clone)]
 pub struct AnthropicClient {
     api_key: String,
     client: reqwest::Client,
     model: String,
+    endpoint: String,
+    /// Running token total; shared between clones of this client
+    usage: Arc<Mutex<TokenUsage>>,
 }
 
 impl AnthropicClient {
@@ -27,6 +91,8 @@ impl AnthropicClient {
             api_key,
             client: reqwest::Client::new(),
             model,
+            endpoint: API_URL.to_string(),
+            usage: Arc::default(),
         }
     }
 
@@ -36,9 +102,17 @@ impl AnthropicClient {
             api_key,
             client: reqwest::Client::new(),
             model: model.to_string(),
+            endpoint: API_URL.to_string(),
+            usage: Arc::default(),
         }
     }
 
+    /// Send requests to a different endpoint (proxy, gateway or test server)
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
     /// Create from environment variables
     pub fn from_env() -> Result<Self> {
         let api_key = std::env::var("ANTHROPIC_API_KEY")
@@ -46,11 +120,24 @@ impl AnthropicClient {
         Ok(Self::new(api_key))
     }
 
+    /// Add a response's usage block to the running total, returning it
+    fn record_usage(&self, usage: Option<ApiUsage>) -> TokenUsage {
+        let usage: TokenUsage = usage.map(Into::into).unwrap_or_default();
+        if let Ok(mut total) = self.usage.lock() {
+            *total += usage;
+        }
+        usage
+    }
+
     /// Internal API call implementation for plain chat
-    async fn call_api(&self, system_prompt: &str, user_prompt: &str) -> Result<String> {
+    async fn call_api(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+    ) -> Result<(String, TokenUsage)> {
         let response = self
             .client
-            .post("https://api.anthropic.com/v1/messages")
+            .post(&self.endpoint)
             .header("x-api-key", &self.api_key)
             .header("anthropic-version", "2023-06-01")
             .header("content-type", "application/json")
@@ -67,7 +154,12 @@ impl AnthropicClient {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(anyhow!("Anthropic API error {}: {}", status, body));
+            return Err(ApiError {
+                provider: "Anthropic",
+                status,
+                body,
+            }
+            .into());
         }
 
         #[derive(Deserialize)]
@@ -77,14 +169,83 @@ impl AnthropicClient {
         #[derive(Deserialize)]
         struct ApiResponse {
             content: Vec<ContentBlock>,
+            #[serde(default)]
+            usage: Option<ApiUsage>,
         }
 
         let api_response: ApiResponse = response.json().await?;
+        let usage = self.record_usage(api_response.usage);
         api_response
             .content
             .first()
             .and_then(|c| c.text.clone())
             .ok_or_else(|| anyhow!("Empty response from Anthropic"))
+            .map(|text| (text, usage))
+    }
+
+    /// Internal API call for plain chat over server-sent events
+    async fn call_api_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &mut OnToken<'_>,
+    ) -> Result<String> {
+        let mut response = self
+            .client
+            .post(&self.endpoint)
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("content-type", "application/json")
+            .json(&serde_json::json!({
+                "model": &self.model,
+                "max_tokens": 4096,
+                "temperature": 0,
+                "stream": true,
+                "system": system_prompt,
+                "messages": [{"role": "user", "content": user_prompt}]
+            }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(ApiError {
+                provider: "Anthropic",
+                status,
+                body,
+            }
+            .into());
+        }
+
+        let mut decoder = SseDecoder::default();
+        let mut text = String::new();
+        let mut usage: Option<ApiUsage> = None;
+        while let Some(chunk) = response.chunk().await? {
+            for data in decoder.push(&chunk) {
+                match serde_json::from_str::<StreamEvent>(&data)? {
+                    StreamEvent::MessageStart { message } => usage = Some(message.usage),
+                    StreamEvent::ContentBlockDelta { delta } => {
+                        if let Some(delta) = delta.text {
+                            on_token(&delta);
+                            text.push_str(&delta);
+                        }
+                    }
+                    StreamEvent::MessageDelta { usage: delta } => {
+                        if let Some(usage) = usage.as_mut() {
+                            usage.output_tokens = delta.output_tokens;
+                        }
+                    }
+                    StreamEvent::Error { error } => {
+                        return Err(anyhow!("Anthropic stream error: {}", error));
+                    }
+                    StreamEvent::Other => {}
+                }
+            }
+        }
+
+        self.record_usage(usage);
+        Ok(text)
     }
 
     /// Internal API call with tool_use for structured output
@@ -96,7 +257,7 @@ impl AnthropicClient {
     ) -> Result<ToolCallResult> {
         let response = self
             .client
-            .post("https://api.anthropic.com/v1/messages")
+            .post(&self.endpoint)
             .header("x-api-key", &self.api_key)
             .header("anthropic-version", "2023-06-01")
             .header("content-type", "application/json")
@@ -119,7 +280,12 @@ impl AnthropicClient {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(anyhow!("Anthropic API error {}: {}", status, body));
+            return Err(ApiError {
+                provider: "Anthropic",
+                status,
+                body,
+            }
+            .into());
         }
 
         // Parse tool_use response
@@ -134,9 +300,12 @@ impl AnthropicClient {
         #[derive(Deserialize)]
         struct ApiResponse {
             content: Vec<ContentBlock>,
+            #[serde(default)]
+            usage: Option<ApiUsage>,
         }
 
         let api_response: ApiResponse = response.json().await?;
+        self.record_usage(api_response.usage);
 
         // Find the tool_use block
         for block in api_response.content {
@@ -157,16 +326,36 @@ impl AnthropicClient {
 #[async_trait]
 impl LlmClient for AnthropicClient {
     async fn chat(&self, system_prompt: &str, user_prompt: &str) -> Result<String> {
+        let (text, _) = self.call_api(system_prompt, user_prompt).await?;
+        Ok(text)
+    }
+
+    async fn chat_with_usage(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+    ) -> Result<(String, TokenUsage)> {
         self.call_api(system_prompt, user_prompt).await
     }
 
+    async fn chat_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &mut OnToken<'_>,
+    ) -> Result<String> {
+        self.call_api_streaming(system_prompt, user_prompt, on_token)
+            .await
+    }
+
     async fn chat_json(&self, system_prompt: &str, user_prompt: &str) -> Result<String> {
         // Anthropic doesn't have json_object mode, rely on prompt engineering
         let json_system = format!(
             "{}\n\nIMPORTANT: Respond with valid JSON only. No markdown code blocks, no explanations.",
             system_prompt
         );
-        self.call_api(&json_system, user_prompt).await
+        let (text, _) = self.call_api(&json_system, user_prompt).await?;
+        Ok(text)
     }
 
     async fn chat_with_tool(
@@ -186,6 +375,10 @@ impl LlmClient for AnthropicClient {
     fn provider_name(&self) -> &str {
         "Anthropic"
     }
+
+    fn token_usage(&self) -> TokenUsage {
+        self.usage.lock().map(|usage| *usage).unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -204,4 +397,82 @@ mod tests {
         let client = AnthropicClient::with_model("test-key".to_string(), "claude-3-opus");
         assert_eq!(client.model_name(), "claude-3-opus");
     }
+
+    #[test]
+    fn test_usage_block_accumulates() {
+        let usage: ApiUsage =
+            serde_json::from_str(r#"{"input_tokens": 1200, "output_tokens": 340}"#).unwrap();
+        assert_eq!(
+            TokenUsage::from(usage),
+            TokenUsage {
+                prompt: 1200,
+                completion: 340,
+                total: 1540,
+            }
+        );
+
+        let client = AnthropicClient::new("test-key".to_string());
+        let usage = r#"{"input_tokens": 10, "output_tokens": 5, "cache_read_input_tokens": 0}"#;
+        client.record_usage(serde_json::from_str(usage).ok());
+        client
+            .clone()
+            .record_usage(serde_json::from_str(usage).ok());
+        client.record_usage(None);
+        assert_eq!(client.token_usage().total, 30);
+    }
+
+    #[tokio::test]
+    async fn test_chat_with_usage_reports_this_request() {
+        let client = AnthropicClient::new("test-key".to_string());
+        client
+            .record_usage(serde_json::from_str(r#"{"input_tokens": 10, "output_tokens": 5}"#).ok());
+
+        let url = crate::llm_client::test_support::serve_json_once(serde_json::json!({
+            "content": [{"type": "text", "text": "Hello"}],
+            "usage": {"input_tokens": 25, "output_tokens": 3}
+        }))
+        .await;
+        // Clones share the running total
+        let (text, usage) = client
+            .clone()
+            .with_endpoint(url)
+            .chat_with_usage("system", "hi")
+            .await
+            .unwrap();
+
+        assert_eq!(text, "Hello");
+        assert_eq!(usage.total, 28);
+        assert_eq!(client.token_usage().total, 43);
+    }
+
+    #[tokio::test]
+    async fn test_chat_streaming_emits_each_delta() {
+        let events = [
+            r#"{"type":"message_start","message":{"id":"msg_1","usage":{"input_tokens":25,"output_tokens":1}}}"#,
+            r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+            r#"{"type":"ping"}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hel"}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"lo"}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":" world"}}"#,
+            r#"{"type":"content_block_stop","index":0}"#,
+            r#"{"type":"message_delta","delta":{"stop_reason":"end_turn"},"usage":{"output_tokens":3}}"#,
+            r#"{"type":"message_stop"}"#,
+        ]
+        .iter()
+        .map(|data| format!("event: message\ndata: {data}\n\n"))
+        .collect();
+        let url = crate::llm_client::test_support::serve_sse_once(events).await;
+        let client =
+            AnthropicClient::with_model("test-key".to_string(), "claude-test").with_endpoint(url);
+
+        let mut tokens = Vec::new();
+        let text = client
+            .chat_streaming("system", "hi", &mut |t: &str| tokens.push(t.to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(tokens, vec!["Hel", "lo", " world"]);
+        assert_eq!(text, tokens.concat());
+        assert_eq!(client.token_usage().total, 28);
+    }
 }
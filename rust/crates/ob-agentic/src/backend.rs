@@ -29,6 +29,24 @@ impl AgentBackend {
             .map_err(|e: ParseBackendError| anyhow!("{}", e))
     }
 
+    /// Fallback order from AGENT_BACKEND_FALLBACK (comma-separated)
+    ///
+    /// Empty if not set. Accepts the same names as AGENT_BACKEND.
+    pub fn fallbacks_from_env() -> Result<Vec<Self>> {
+        let value = std::env::var("AGENT_BACKEND_FALLBACK").unwrap_or_default();
+        Self::parse_list(&value).map_err(|e| anyhow!("AGENT_BACKEND_FALLBACK: {}", e))
+    }
+
+    /// Parse a comma-separated list of backends, ignoring blank entries
+    pub fn parse_list(value: &str) -> std::result::Result<Vec<Self>, ParseBackendError> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::parse)
+            .collect()
+    }
+
     /// Get display name
     pub fn name(&self) -> &'static str {
         match self {
@@ -109,6 +127,16 @@ mod tests {
         assert!("invalid".parse::<AgentBackend>().is_err());
     }
 
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            AgentBackend::parse_list(" openai, claude-code-cli ,").unwrap(),
+            vec![AgentBackend::OpenAi, AgentBackend::ClaudeCodeCli]
+        );
+        assert!(AgentBackend::parse_list("").unwrap().is_empty());
+        assert!(AgentBackend::parse_list("openai,bogus").is_err());
+    }
+
     #[test]
     fn test_default() {
         assert_eq!(AgentBackend::default(), AgentBackend::Anthropic);
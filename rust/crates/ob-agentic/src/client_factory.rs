@@ -8,6 +8,7 @@ use std::sync::Arc;
 use super::anthropic_client::AnthropicClient;
 use super::backend::AgentBackend;
 use super::claude_code_cli_client::ClaudeCodeCliClient;
+use super::fallback_client::FallbackClient;
 use super::llm_client::LlmClient;
 use super::openai_client::OpenAiClient;
 
@@ -17,22 +18,14 @@ use super::openai_client::OpenAiClient;
 /// - Anthropic: ANTHROPIC_API_KEY
 /// - OpenAI: OPENAI_API_KEY
 /// - Claude Code CLI: local Claude Code auth, no API key required
+///
+/// If AGENT_BACKEND_FALLBACK lists further backends (e.g.
+/// `openai,claude-code-cli`), requests that are rate-limited or fail with a
+/// 5xx error fail over to them in order.
 pub fn create_llm_client() -> Result<Arc<dyn LlmClient>> {
-    let backend = AgentBackend::from_env()?;
-    match backend {
-        AgentBackend::Anthropic => {
-            let client = AnthropicClient::from_env()?;
-            Ok(Arc::new(client))
-        }
-        AgentBackend::OpenAi => {
-            let client = OpenAiClient::from_env()?;
-            Ok(Arc::new(client))
-        }
-        AgentBackend::ClaudeCodeCli => {
-            let client = ClaudeCodeCliClient::from_env()?;
-            Ok(Arc::new(client))
-        }
-    }
+    let mut order = vec![AgentBackend::from_env()?];
+    order.extend(AgentBackend::fallbacks_from_env()?);
+    create_llm_client_chain(&order)
 }
 
 /// Create an LLM client with explicit API key
@@ -80,6 +73,40 @@ pub fn create_llm_client_for_backend(backend: AgentBackend) -> Result<Arc<dyn Ll
     }
 }
 
+/// Create an LLM client that fails over from `primary` to `fallback`
+///
+/// The fallback serves a request only when the primary is rate-limited or
+/// returns a 5xx error.
+pub fn create_llm_client_with_fallback(
+    primary: AgentBackend,
+    fallback: AgentBackend,
+) -> Result<Arc<dyn LlmClient>> {
+    create_llm_client_chain(&[primary, fallback])
+}
+
+/// Create an LLM client that tries `backends` in order
+///
+/// Repeated backends are dropped; a single backend yields its plain client.
+pub fn create_llm_client_chain(backends: &[AgentBackend]) -> Result<Arc<dyn LlmClient>> {
+    let mut order: Vec<AgentBackend> = Vec::new();
+    for backend in backends {
+        if !order.contains(backend) {
+            order.push(*backend);
+        }
+    }
+    match order.as_slice() {
+        [] => Err(anyhow!("No LLM backends configured")),
+        [only] => create_llm_client_for_backend(*only),
+        _ => {
+            let clients = order
+                .into_iter()
+                .map(create_llm_client_for_backend)
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Arc::new(FallbackClient::new(clients)?))
+        }
+    }
+}
+
 /// Get the currently configured backend from environment
 pub fn current_backend() -> Result<AgentBackend> {
     AgentBackend::from_env()
@@ -126,4 +153,9 @@ mod tests {
         let _ = has_api_key_for(AgentBackend::OpenAi);
         let _ = has_api_key_for(AgentBackend::ClaudeCodeCli);
     }
+
+    #[test]
+    fn test_empty_chain_is_rejected() {
+        assert!(create_llm_client_chain(&[]).is_err());
+    }
 }
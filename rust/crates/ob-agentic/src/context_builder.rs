@@ -4,6 +4,7 @@
 //! - Executed bindings from session history
 //! - Bootstrap hints when no CBU exists
 //! - Dataflow-aware suggestions
+//! - Recent conversation, verb schemas and examples, trimmed to a token budget
 //!
 //! This replaces hardcoded examples in prompts with context-aware guidance.
 
@@ -12,8 +13,12 @@ use uuid::Uuid;
 
 use dsl_core::BindingContext;
 
+use crate::backend::AgentBackend;
+
 /// Context for agent DSL generation
-#[derive(Debug, Clone)]
+///
+/// The default is an empty context with no session state.
+#[derive(Debug, Clone, Default)]
 pub struct AgentContext {
     /// Executed bindings available for reference
     pub bindings: Vec<BindingDisplay>,
@@ -23,6 +28,83 @@ pub struct AgentContext {
     pub cbu_id: Option<Uuid>,
     /// Suggested next actions based on current state
     pub suggestions: Vec<String>,
+    /// Recent conversation turns, oldest first
+    pub recent_messages: Vec<String>,
+    /// Verb schemas and reference data, each a self-contained markdown block
+    pub schema_hints: Vec<String>,
+    /// Worked DSL examples, each a self-contained markdown block
+    pub examples: Vec<String>,
+}
+
+/// Sections of the prompt context, highest priority first
+///
+/// Active scope (bootstrap hint, bindings) outranks recent messages, which
+/// outrank schema hints, which outrank examples. When the context must fit a
+/// token budget, sections are dropped from the lowest priority upwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ContextSection {
+    /// New-CBU instructions (the active scope is empty)
+    BootstrapHint,
+    /// Session bindings the LLM may reference
+    Bindings,
+    /// Recent conversation turns
+    RecentMessages,
+    /// Verb schemas and reference data
+    SchemaHints,
+    /// Worked DSL examples
+    Examples,
+    /// Suggested next actions
+    Suggestions,
+}
+
+/// Rough token estimate from character count
+///
+/// Defaults to 4 chars per token; tokenizers differ, so callers can set a
+/// ratio per backend.
+#[derive(Debug, Clone, Copy)]
+pub struct TokenEstimator {
+    chars_per_token: usize,
+}
+
+impl TokenEstimator {
+    pub fn new(chars_per_token: usize) -> Self {
+        Self {
+            chars_per_token: chars_per_token.max(1),
+        }
+    }
+
+    /// Estimator for a backend's tokenizer
+    ///
+    /// Claude models split English text into more tokens than OpenAI's
+    /// tokenizer does, so they get a lower ratio to keep budgets safe.
+    pub fn for_backend(backend: AgentBackend) -> Self {
+        match backend {
+            AgentBackend::Anthropic | AgentBackend::ClaudeCodeCli => Self::new(3),
+            AgentBackend::OpenAi => Self::new(4),
+        }
+    }
+
+    /// Estimated tokens in `text`, rounded up
+    pub fn estimate(&self, text: &str) -> usize {
+        text.chars().count().div_ceil(self.chars_per_token)
+    }
+}
+
+impl Default for TokenEstimator {
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
+
+/// Prompt context fitted to a token budget
+#[derive(Debug, Clone)]
+pub struct BudgetedContext {
+    /// The rendered context
+    pub prompt: String,
+    /// Estimated tokens in `prompt`
+    pub estimated_tokens: usize,
+    /// Sections dropped to fit, lowest priority first
+    pub trimmed: Vec<ContextSection>,
 }
 
 /// A binding formatted for LLM display
@@ -59,6 +141,9 @@ impl BindingDisplay {
 pub struct AgentContextBuilder {
     bindings: Vec<BindingDisplay>,
     cbu_id: Option<Uuid>,
+    recent_messages: Vec<String>,
+    schema_hints: Vec<String>,
+    examples: Vec<String>,
 }
 
 impl AgentContextBuilder {
@@ -66,6 +151,9 @@ impl AgentContextBuilder {
         Self {
             bindings: Vec::new(),
             cbu_id: None,
+            recent_messages: Vec::new(),
+            schema_hints: Vec::new(),
+            examples: Vec::new(),
         }
     }
 
@@ -119,6 +207,32 @@ impl AgentContextBuilder {
         self
     }
 
+    /// Add recent conversation turns, oldest first
+    pub fn with_recent_messages(mut self, messages: impl IntoIterator<Item = String>) -> Self {
+        self.recent_messages.extend(messages);
+        self
+    }
+
+    /// Add a verb schema or reference data block
+    pub fn with_schema_hint(mut self, hint: impl Into<String>) -> Self {
+        self.schema_hints.push(hint.into());
+        self
+    }
+
+    /// Add a worked DSL example block
+    pub fn with_example(mut self, example: impl Into<String>) -> Self {
+        self.examples.push(example.into());
+        self
+    }
+
+    /// Build the context and render it fitted to `budget_tokens`
+    ///
+    /// See [`AgentContext::to_prompt_context_within`].
+    pub fn build_within(self, budget_tokens: usize, estimator: TokenEstimator) -> BudgetedContext {
+        self.build()
+            .to_prompt_context_within(budget_tokens, estimator)
+    }
+
     /// Build the final context
     pub fn build(self) -> AgentContext {
         let has_cbu =
@@ -133,6 +247,9 @@ impl AgentContextBuilder {
             needs_bootstrap,
             cbu_id: self.cbu_id,
             suggestions,
+            recent_messages: self.recent_messages,
+            schema_hints: self.schema_hints,
+            examples: self.examples,
         }
     }
 
@@ -208,29 +325,85 @@ All other commands depend on having a CBU binding. Do NOT reference @cbu or any
 
     /// Get full context string for agent prompt
     pub fn to_prompt_context(&self) -> String {
-        let mut parts = Vec::new();
+        join_sections(&self.sections())
+    }
+
+    /// Get context string for agent prompt, fitted to `budget_tokens`
+    ///
+    /// Drops whole sections, lowest priority first, until the estimate fits.
+    /// If even the highest-priority section alone is over budget the prompt
+    /// is empty.
+    pub fn to_prompt_context_within(
+        &self,
+        budget_tokens: usize,
+        estimator: TokenEstimator,
+    ) -> BudgetedContext {
+        let mut sections = self.sections();
+        let mut trimmed = Vec::new();
+        let mut prompt = join_sections(&sections);
+        while estimator.estimate(&prompt) > budget_tokens {
+            let Some((section, _)) = sections.pop() else {
+                break;
+            };
+            trimmed.push(section);
+            prompt = join_sections(&sections);
+        }
+
+        BudgetedContext {
+            estimated_tokens: estimator.estimate(&prompt),
+            prompt,
+            trimmed,
+        }
+    }
+
+    /// Non-empty sections in priority order
+    fn sections(&self) -> Vec<(ContextSection, String)> {
+        let mut sections = Vec::new();
 
         // Bootstrap hint if needed
         if let Some(hint) = self.format_bootstrap_hint() {
-            parts.push(hint);
+            sections.push((ContextSection::BootstrapHint, hint));
         }
 
         // Available bindings
         let bindings_str = self.format_bindings_for_llm();
         if !bindings_str.is_empty() {
-            parts.push(bindings_str);
+            sections.push((ContextSection::Bindings, bindings_str));
+        }
+
+        if !self.recent_messages.is_empty() {
+            sections.push((
+                ContextSection::RecentMessages,
+                format!("## Recent Messages\n\n{}", self.recent_messages.join("\n")),
+            ));
+        }
+
+        if !self.schema_hints.is_empty() {
+            sections.push((ContextSection::SchemaHints, self.schema_hints.join("\n\n")));
+        }
+
+        if !self.examples.is_empty() {
+            sections.push((ContextSection::Examples, self.examples.join("\n\n")));
         }
 
         // Suggestions
         if !self.suggestions.is_empty() {
             let suggestions_str = format!("[SUGGESTIONS: {}]", self.suggestions.join(" | "));
-            parts.push(suggestions_str);
+            sections.push((ContextSection::Suggestions, suggestions_str));
         }
 
-        parts.join("\n\n")
+        sections
     }
 }
 
+fn join_sections(sections: &[(ContextSection, String)]) -> String {
+    sections
+        .iter()
+        .map(|(_, text)| text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
 /// Infer binding type from common naming patterns
 fn infer_binding_type(name: &str) -> (String, Option<String>) {
     let lower = name.to_lowercase();
@@ -301,6 +474,116 @@ mod tests {
         assert!(ctx.format_bootstrap_hint().is_none());
     }
 
+    fn context_with_cbu_and_entity() -> AgentContext {
+        let mut bindings = HashMap::new();
+        bindings.insert("fund".to_string(), Uuid::new_v4());
+        bindings.insert("john".to_string(), Uuid::new_v4());
+        AgentContextBuilder::new()
+            .with_bindings_map(&bindings)
+            .build()
+    }
+
+    #[test]
+    fn test_context_within_budget_is_untrimmed() {
+        let ctx = context_with_cbu_and_entity();
+        let budgeted = ctx.to_prompt_context_within(10_000, TokenEstimator::default());
+        assert!(budgeted.trimmed.is_empty());
+        assert_eq!(budgeted.prompt, ctx.to_prompt_context());
+    }
+
+    #[test]
+    fn test_context_over_budget_drops_suggestions_first() {
+        let ctx = context_with_cbu_and_entity();
+        assert!(!ctx.suggestions.is_empty());
+        let estimator = TokenEstimator::default();
+        let bindings_tokens = estimator.estimate(&ctx.format_bindings_for_llm());
+
+        let budgeted = ctx.to_prompt_context_within(bindings_tokens, estimator);
+
+        assert_eq!(budgeted.trimmed, vec![ContextSection::Suggestions]);
+        assert!(budgeted.prompt.contains("@fund"));
+        assert!(!budgeted.prompt.contains("SUGGESTIONS"));
+        assert!(budgeted.estimated_tokens <= bindings_tokens);
+    }
+
+    #[test]
+    fn test_bootstrap_hint_outlasts_suggestions() {
+        let ctx = AgentContextBuilder::new().build();
+        let estimator = TokenEstimator::new(1);
+        let hint_tokens = estimator.estimate(&ctx.format_bootstrap_hint().unwrap());
+
+        let budgeted = ctx.to_prompt_context_within(hint_tokens, estimator);
+        assert_eq!(budgeted.trimmed, vec![ContextSection::Suggestions]);
+        assert!(budgeted.prompt.contains("NEW CBU MODE"));
+
+        let starved = ctx.to_prompt_context_within(0, estimator);
+        assert!(starved.prompt.is_empty());
+        assert_eq!(
+            starved.trimmed,
+            vec![ContextSection::Suggestions, ContextSection::BootstrapHint]
+        );
+    }
+
+    #[test]
+    fn test_build_within_keeps_scope_and_messages_over_schema_and_examples() {
+        let builder = || {
+            let mut bindings = HashMap::new();
+            bindings.insert("fund".to_string(), Uuid::new_v4());
+            AgentContextBuilder::new()
+                .with_bindings_map(&bindings)
+                .with_recent_messages(["user: add a director".to_string()])
+                .with_schema_hint(format!("## Available Verbs\n\n{}", "verb ".repeat(200)))
+                .with_example(format!("## Example\n\n{}", "(cbu.ensure) ".repeat(200)))
+        };
+        let estimator = TokenEstimator::default();
+        let full = builder().build_within(usize::MAX, estimator);
+        assert!(full.trimmed.is_empty());
+
+        // Room for everything except the examples and suggestions
+        let without_examples = builder()
+            .build()
+            .sections()
+            .into_iter()
+            .filter(|(section, _)| *section < ContextSection::Examples)
+            .collect::<Vec<_>>();
+        let budget = estimator.estimate(&join_sections(&without_examples));
+        let budgeted = builder().build_within(budget, estimator);
+        assert_eq!(
+            budgeted.trimmed,
+            vec![ContextSection::Suggestions, ContextSection::Examples]
+        );
+        assert!(budgeted.prompt.contains("Available Verbs"));
+        assert!(!budgeted.prompt.contains("## Example"));
+
+        // Just the active scope and the conversation
+        let budget = estimator.estimate(&join_sections(&without_examples[..2]));
+        let budgeted = builder().build_within(budget, estimator);
+        assert_eq!(
+            budgeted.trimmed,
+            vec![
+                ContextSection::Suggestions,
+                ContextSection::Examples,
+                ContextSection::SchemaHints
+            ]
+        );
+        assert!(budgeted.prompt.contains("@fund"));
+        assert!(budgeted.prompt.contains("add a director"));
+        assert!(budgeted.estimated_tokens <= budget);
+    }
+
+    #[test]
+    fn test_estimator_per_backend() {
+        let text = "a".repeat(12);
+        assert_eq!(
+            TokenEstimator::for_backend(AgentBackend::Anthropic).estimate(&text),
+            4
+        );
+        assert_eq!(
+            TokenEstimator::for_backend(AgentBackend::OpenAi).estimate(&text),
+            3
+        );
+    }
+
     #[test]
     fn test_binding_display_format() {
         let binding = BindingDisplay {
@@ -0,0 +1,273 @@
+//! Fallback Client
+//!
+//! LLM client that fails over across backends in a fixed order.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use std::future::Future;
+use std::sync::Arc;
+
+use super::llm_client::{ApiError, LlmClient, OnToken, TokenUsage, ToolCallResult, ToolDefinition};
+
+/// Tries each client in order, moving to the next only when a request fails
+/// with a rate limit or 5xx [`ApiError`]. Any other error is returned as-is,
+/// since a malformed request or bad response would fail on every backend.
+pub struct FallbackClient {
+    clients: Vec<Arc<dyn LlmClient>>,
+}
+
+impl FallbackClient {
+    /// Create from clients in priority order
+    pub fn new(clients: Vec<Arc<dyn LlmClient>>) -> Result<Self> {
+        if clients.is_empty() {
+            return Err(anyhow!("FallbackClient needs at least one client"));
+        }
+        Ok(Self { clients })
+    }
+
+    fn primary(&self) -> &dyn LlmClient {
+        self.clients[0].as_ref()
+    }
+
+    /// Whether `err` should move the request on to the next client
+    fn should_fail_over(err: &anyhow::Error) -> bool {
+        err.downcast_ref::<ApiError>()
+            .is_some_and(ApiError::is_transient)
+    }
+
+    /// Run `call` against each client until one succeeds or fails permanently
+    ///
+    /// `on_token` is lent to each attempt and handed back with its outcome,
+    /// so streaming and plain requests share this one failover loop.
+    async fn with_failover<'a, 's, 'o, T, F, Fut>(
+        &'a self,
+        mut on_token: &'s mut OnToken<'o>,
+        call: F,
+    ) -> Result<T>
+    where
+        F: Fn(&'a dyn LlmClient, &'s mut OnToken<'o>) -> Fut,
+        Fut: Future<Output = (Result<T>, &'s mut OnToken<'o>)>,
+    {
+        let last = self.clients.len() - 1;
+        for (i, client) in self.clients.iter().enumerate() {
+            let (outcome, returned) = call(client.as_ref(), on_token).await;
+            on_token = returned;
+            match outcome {
+                Ok(value) => {
+                    tracing::info!("LLM request served by {}", client.provider_name());
+                    return Ok(value);
+                }
+                Err(err) if i < last && Self::should_fail_over(&err) => {
+                    tracing::warn!(
+                        "{} failed ({}), falling back to {}",
+                        client.provider_name(),
+                        err,
+                        self.clients[i + 1].provider_name()
+                    );
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        unreachable!("loop returns on the last client")
+    }
+
+    /// [`Self::with_failover`] for requests that don't stream tokens
+    async fn with_failover_unstreamed<'a, T, F, Fut>(&'a self, call: F) -> Result<T>
+    where
+        F: Fn(&'a dyn LlmClient) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut ignore = |_: &str| {};
+        self.with_failover(&mut ignore, |client, on_token| {
+            let attempt = call(client);
+            async move { (attempt.await, on_token) }
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl LlmClient for FallbackClient {
+    async fn chat(&self, system_prompt: &str, user_prompt: &str) -> Result<String> {
+        self.with_failover_unstreamed(|client| client.chat(system_prompt, user_prompt))
+            .await
+    }
+
+    async fn chat_with_usage(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+    ) -> Result<(String, TokenUsage)> {
+        self.with_failover_unstreamed(|client| client.chat_with_usage(system_prompt, user_prompt))
+            .await
+    }
+
+    async fn chat_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &mut OnToken<'_>,
+    ) -> Result<String> {
+        // ApiError is raised before the first delta, so a failed-over
+        // stream never delivers duplicate tokens.
+        self.with_failover(on_token, |client, on_token| async move {
+            let outcome = client
+                .chat_streaming(system_prompt, user_prompt, on_token)
+                .await;
+            (outcome, on_token)
+        })
+        .await
+    }
+
+    async fn chat_json(&self, system_prompt: &str, user_prompt: &str) -> Result<String> {
+        self.with_failover_unstreamed(|client| client.chat_json(system_prompt, user_prompt))
+            .await
+    }
+
+    async fn chat_with_tool(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        tool: &ToolDefinition,
+    ) -> Result<ToolCallResult> {
+        self.with_failover_unstreamed(|client| {
+            client.chat_with_tool(system_prompt, user_prompt, tool)
+        })
+        .await
+    }
+
+    fn model_name(&self) -> &str {
+        self.primary().model_name()
+    }
+
+    fn provider_name(&self) -> &str {
+        self.primary().provider_name()
+    }
+
+    fn token_usage(&self) -> TokenUsage {
+        let mut total = TokenUsage::default();
+        for client in &self.clients {
+            total += client.token_usage();
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    /// Client that always fails with the given status, or answers with its name
+    struct Scripted {
+        name: &'static str,
+        status: Option<StatusCode>,
+    }
+
+    impl Scripted {
+        fn reply(&self) -> Result<String> {
+            match self.status {
+                Some(status) => Err(ApiError {
+                    provider: self.name,
+                    status,
+                    body: "{}".to_string(),
+                }
+                .into()),
+                None => Ok(format!("reply from {}", self.name)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LlmClient for Scripted {
+        async fn chat(&self, _: &str, _: &str) -> Result<String> {
+            self.reply()
+        }
+
+        async fn chat_json(&self, _: &str, _: &str) -> Result<String> {
+            self.reply()
+        }
+
+        async fn chat_with_tool(
+            &self,
+            _: &str,
+            _: &str,
+            tool: &ToolDefinition,
+        ) -> Result<ToolCallResult> {
+            self.reply().map(|_| ToolCallResult {
+                tool_name: tool.name.clone(),
+                arguments: serde_json::json!({ "served_by": self.name }),
+            })
+        }
+
+        fn model_name(&self) -> &str {
+            "scripted"
+        }
+
+        fn provider_name(&self) -> &str {
+            self.name
+        }
+    }
+
+    fn chain(primary: Option<StatusCode>) -> FallbackClient {
+        FallbackClient::new(vec![
+            Arc::new(Scripted {
+                name: "primary",
+                status: primary,
+            }),
+            Arc::new(Scripted {
+                name: "fallback",
+                status: None,
+            }),
+        ])
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_primary_falls_back() {
+        let client = chain(Some(StatusCode::TOO_MANY_REQUESTS));
+        assert_eq!(client.chat("s", "u").await.unwrap(), "reply from fallback");
+
+        let mut tokens = Vec::new();
+        let text = client
+            .chat_streaming("s", "u", &mut |t: &str| tokens.push(t.to_string()))
+            .await
+            .unwrap();
+        assert_eq!(text, "reply from fallback");
+        assert_eq!(tokens, vec!["reply from fallback"]);
+    }
+
+    #[tokio::test]
+    async fn test_server_error_falls_back_for_tool_calls() {
+        let client = chain(Some(StatusCode::SERVICE_UNAVAILABLE));
+        let tool = ToolDefinition {
+            name: "generate_dsl_intents".to_string(),
+            description: String::new(),
+            parameters: serde_json::json!({}),
+        };
+        let result = client.chat_with_tool("s", "u", &tool).await.unwrap();
+        assert_eq!(result.arguments["served_by"], "fallback");
+    }
+
+    #[tokio::test]
+    async fn test_client_error_is_not_retried() {
+        let client = chain(Some(StatusCode::BAD_REQUEST));
+        let err = client.chat("s", "u").await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ApiError>().map(|e| e.provider),
+            Some("primary")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_healthy_primary_serves_request() {
+        let client = chain(None);
+        assert_eq!(client.chat("s", "u").await.unwrap(), "reply from primary");
+        assert_eq!(client.provider_name(), "primary");
+    }
+
+    #[test]
+    fn test_empty_chain_is_rejected() {
+        assert!(FallbackClient::new(Vec::new()).is_err());
+    }
+}
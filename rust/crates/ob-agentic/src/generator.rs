@@ -5,43 +5,95 @@
 use anyhow::{anyhow, Result};
 use std::sync::Arc;
 
+use crate::backend::AgentBackend;
 use crate::client_factory::{create_llm_client, create_llm_client_with_key};
+use crate::context_builder::{AgentContext, ContextSection, TokenEstimator};
 use crate::llm_client::LlmClient;
 use crate::patterns::OnboardingPattern;
 use crate::planner::OnboardingPlan;
 
+/// Default token budget for a whole request (system and user prompt)
+const PROMPT_BUDGET_TOKENS: usize = 8_000;
+
+/// System and user prompt for one generation request
+#[derive(Debug, Clone)]
+pub struct GenerationPrompts {
+    pub system: String,
+    pub user: String,
+    /// Estimated tokens across both prompts
+    pub estimated_tokens: usize,
+    /// Context sections dropped to fit the budget, lowest priority first
+    pub trimmed: Vec<ContextSection>,
+}
+
 /// DSL generator using LLM API
 pub struct DslGenerator {
     client: Arc<dyn LlmClient>,
+    estimator: TokenEstimator,
+    budget_tokens: usize,
+    context: Option<AgentContext>,
 }
 
 impl DslGenerator {
     /// Create a new DSL generator with explicit API key
     pub fn new(api_key: String) -> Self {
         let client = create_llm_client_with_key(api_key).expect("Failed to create LLM client");
-        Self { client }
+        Self::with_client(client).with_estimator(Self::env_estimator())
     }
 
     /// Create from environment variables
     pub fn from_env() -> Result<Self> {
         let client = create_llm_client()?;
-        Ok(Self { client })
+        Ok(Self::with_client(client).with_estimator(Self::env_estimator()))
     }
 
     /// Create with a specific LLM client
     pub fn with_client(client: Arc<dyn LlmClient>) -> Self {
-        Self { client }
+        Self {
+            client,
+            estimator: TokenEstimator::default(),
+            budget_tokens: PROMPT_BUDGET_TOKENS,
+            context: None,
+        }
+    }
+
+    /// Estimate prompt tokens with `estimator` (see [`TokenEstimator::for_backend`])
+    pub fn with_estimator(mut self, estimator: TokenEstimator) -> Self {
+        self.estimator = estimator;
+        self
+    }
+
+    /// Cap each request at `budget_tokens` (system and user prompt together)
+    pub fn with_budget(mut self, budget_tokens: usize) -> Self {
+        self.budget_tokens = budget_tokens;
+        self
+    }
+
+    /// Include session context (bindings, recent messages, suggestions) in
+    /// the prompt alongside the verb schemas and example
+    pub fn with_context(mut self, context: AgentContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    fn env_estimator() -> TokenEstimator {
+        AgentBackend::from_env()
+            .map(TokenEstimator::for_backend)
+            .unwrap_or_default()
     }
 
     /// Generate DSL from an onboarding plan
     pub async fn generate(&self, plan: &OnboardingPlan) -> Result<String> {
-        let system_prompt = self.build_system_prompt(plan.pattern);
-        let user_prompt = self.build_user_prompt(plan);
-
-        let response = self.client.chat(&system_prompt, &user_prompt).await?;
+        let prompts = self.prompts(plan);
+        let response = self.client.chat(&prompts.system, &prompts.user).await?;
         Ok(Self::strip_code_blocks(&response))
     }
 
+    /// Prompts [`Self::generate`] sends for `plan`, fitted to the budget
+    pub fn prompts(&self, plan: &OnboardingPlan) -> GenerationPrompts {
+        self.fit_prompts(plan.pattern, self.build_user_prompt(plan))
+    }
+
     /// Generate DSL with error correction
     pub async fn generate_with_fix(
         &self,
@@ -71,16 +123,53 @@ Return ONLY the corrected DSL code, no explanations."#,
             self.build_user_prompt(plan)
         );
 
-        let system_prompt = self.build_system_prompt(plan.pattern);
-        let response = self.client.chat(&system_prompt, &prompt).await?;
+        let prompts = self.fit_prompts(plan.pattern, prompt);
+        let response = self.client.chat(&prompts.system, &prompts.user).await?;
         Ok(Self::strip_code_blocks(&response))
     }
 
-    fn build_system_prompt(&self, pattern: OnboardingPattern) -> String {
-        let verb_schemas = include_str!("schemas/trading_profile_verbs.md");
-        let reference_data = include_str!("schemas/reference_data.md");
-        let example = pattern.example_dsl();
+    /// Fit the schemas, example and session context into whatever budget the
+    /// fixed instructions and `user_prompt` leave, dropping the lowest
+    /// priority sections first
+    fn fit_prompts(&self, pattern: OnboardingPattern, user_prompt: String) -> GenerationPrompts {
+        let mut context = self.context.clone().unwrap_or_default();
+        context.schema_hints.push(format!(
+            "## Available Verbs\n\n{}",
+            include_str!("schemas/trading_profile_verbs.md")
+        ));
+        context.schema_hints.push(format!(
+            "## Reference Data\n\n{}",
+            include_str!("schemas/reference_data.md")
+        ));
+        context.examples.push(format!(
+            "## Example ({} pattern)\n\n{}",
+            pattern,
+            pattern.example_dsl()
+        ));
+
+        let fixed_tokens = self.estimator.estimate(&Self::build_system_prompt(""))
+            + self.estimator.estimate(&user_prompt);
+        let budgeted = context.to_prompt_context_within(
+            self.budget_tokens.saturating_sub(fixed_tokens),
+            self.estimator,
+        );
+        if !budgeted.trimmed.is_empty() {
+            tracing::warn!(
+                "Trimmed prompt sections {:?} to fit {} tokens",
+                budgeted.trimmed,
+                self.budget_tokens
+            );
+        }
 
+        GenerationPrompts {
+            system: Self::build_system_prompt(&budgeted.prompt),
+            user: user_prompt,
+            estimated_tokens: fixed_tokens + budgeted.estimated_tokens,
+            trimmed: budgeted.trimmed,
+        }
+    }
+
+    fn build_system_prompt(context: &str) -> String {
         format!(
             r#"# DSL Generation System
 
@@ -99,17 +188,7 @@ S-expression format:
 - Lists use brackets: `["a" "b" "c"]`
 - Comments start with `;`
 
-## Available Verbs
-
-{verb_schemas}
-
-## Reference Data
-
-{reference_data}
-
-## Example ({pattern} pattern)
-
-{example}
+{context}
 
 ## Rules
 
@@ -121,11 +200,7 @@ S-expression format:
 6. Use placeholder values for account numbers (e.g., "SAFE-001", "CASH-001")
 7. Use today's date for effective-date: "2024-12-01"
 8. Output ONLY the DSL code, no explanations
-"#,
-            verb_schemas = verb_schemas,
-            reference_data = reference_data,
-            example = example,
-            pattern = pattern
+"#
         )
     }
 
@@ -221,6 +296,15 @@ S-expression format:
             prompt.push('\n');
         }
 
+        // Step order (bindings are produced before they are used)
+        if !plan.steps.is_empty() {
+            prompt.push_str("## Statement Order\n");
+            for (i, step) in plan.steps.iter().enumerate() {
+                prompt.push_str(&format!("{}. {}\n", i + 1, step.description));
+            }
+            prompt.push('\n');
+        }
+
         prompt.push_str("Generate the complete DSL now.\n");
 
         prompt
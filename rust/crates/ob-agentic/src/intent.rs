@@ -47,6 +47,9 @@ pub struct Interpretation {
     pub jurisdiction: Option<String>,
     /// Human-readable description
     pub description: String,
+    /// Model's confidence in this reading (0.0 - 1.0), if reported
+    #[serde(default)]
+    pub confidence: Option<f64>,
 }
 
 impl IntentResult {
@@ -70,6 +73,62 @@ impl IntentResult {
             _ => None,
         }
     }
+
+    /// Clarification to ask before acting on this result, given a minimum
+    /// confidence
+    ///
+    /// A clear intent needs clarification when its reported confidence is
+    /// below `threshold`, or when an alternative reading scores at least as
+    /// high (a tie). The request lists the intent's own reading as option 1
+    /// followed by the alternatives. Intents without a reported confidence
+    /// are taken as confident.
+    pub fn clarification_below(&self, threshold: f64) -> Option<ClarificationRequest> {
+        let intent = match self {
+            IntentResult::NeedsClarification(c) => return Some(c.clone()),
+            IntentResult::Clear(i) => i,
+        };
+        let confidence = intent.confidence?;
+        let tied = intent
+            .alternatives
+            .iter()
+            .any(|alt| alt.confidence.is_some_and(|c| c >= confidence));
+        if confidence >= threshold && !tied {
+            return None;
+        }
+
+        let primary = Interpretation {
+            option: 1,
+            name: intent.client.name.clone(),
+            jurisdiction: intent.client.jurisdiction.clone(),
+            description: format!("{} ({:?})", intent.client.name, intent.classify()),
+            confidence: Some(confidence),
+        };
+        let interpretations: Vec<Interpretation> = std::iter::once(primary)
+            .chain(intent.alternatives.iter().cloned())
+            .zip(1u8..)
+            .map(|(interpretation, option)| Interpretation {
+                option,
+                ..interpretation
+            })
+            .collect();
+        let question = if interpretations.len() > 1 {
+            "I'm not sure which of these you meant. Which one should I use?".to_string()
+        } else {
+            format!(
+                "I'm not confident I understood this request. Did you mean {}?",
+                interpretations[0].description
+            )
+        };
+
+        Some(ClarificationRequest {
+            needs_clarification: true,
+            ambiguity: AmbiguityDetails {
+                original_text: intent.original_request.clone(),
+                interpretations,
+                question,
+            },
+        })
+    }
 }
 
 /// Structured representation of user's onboarding request
@@ -92,6 +151,14 @@ pub struct OnboardingIntent {
 
     /// Original natural language request
     pub original_request: String,
+
+    /// Model's confidence in this reading (0.0 - 1.0), if reported
+    #[serde(default)]
+    pub confidence: Option<f64>,
+
+    /// Other readings the model considered
+    #[serde(default)]
+    pub alternatives: Vec<Interpretation>,
 }
 
 /// Client information extracted from the request
@@ -222,6 +289,8 @@ mod tests {
             otc_counterparties: vec![],
             explicit_requirements: vec![],
             original_request: "Set up Test Fund for US equities".to_string(),
+            confidence: None,
+            alternatives: vec![],
         }
     }
 
@@ -273,6 +342,60 @@ mod tests {
         assert_eq!(currencies, vec!["GBP", "USD"]);
     }
 
+    #[test]
+    fn test_confident_intent_needs_no_clarification() {
+        let mut intent = simple_equity_intent();
+        intent.confidence = Some(0.92);
+        intent.alternatives.push(Interpretation {
+            option: 2,
+            name: "Test".to_string(),
+            jurisdiction: Some("US".to_string()),
+            description: "'Fund' is not part of the name".to_string(),
+            confidence: Some(0.3),
+        });
+        let result = IntentResult::Clear(intent);
+        assert!(result.clarification_below(0.7).is_none());
+
+        // No reported confidence counts as confident
+        let result = IntentResult::Clear(simple_equity_intent());
+        assert!(result.clarification_below(0.7).is_none());
+    }
+
+    #[test]
+    fn test_ambiguous_intent_lists_candidates() {
+        let mut intent = simple_equity_intent();
+        intent.confidence = Some(0.55);
+        intent.alternatives.push(Interpretation {
+            option: 1,
+            name: "Test Fund US".to_string(),
+            jurisdiction: None,
+            description: "'US' is part of the name".to_string(),
+            confidence: Some(0.55),
+        });
+
+        let clarification = IntentResult::Clear(intent.clone())
+            .clarification_below(0.5)
+            .expect("tie should ask for clarification");
+        let options: Vec<(u8, &str)> = clarification
+            .ambiguity
+            .interpretations
+            .iter()
+            .map(|i| (i.option, i.name.as_str()))
+            .collect();
+        assert_eq!(options, vec![(1, "Test Fund"), (2, "Test Fund US")]);
+        assert_eq!(
+            clarification.ambiguity.original_text,
+            "Set up Test Fund for US equities"
+        );
+
+        // Below threshold without alternatives still asks
+        intent.alternatives.clear();
+        let clarification = IntentResult::Clear(intent)
+            .clarification_below(0.7)
+            .expect("low confidence should ask for clarification");
+        assert_eq!(clarification.ambiguity.interpretations.len(), 1);
+    }
+
     #[test]
     fn test_has_otc_instruments() {
         let mut intent = simple_equity_intent();
@@ -80,6 +80,7 @@ impl EntityResolver for DatabaseEntityResolver {
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let mut client = self.client.lock().await;
@@ -137,6 +138,7 @@ impl EntityResolver for DatabaseEntityResolver {
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let mut client = self.client.lock().await;
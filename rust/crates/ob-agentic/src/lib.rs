@@ -15,6 +15,10 @@
 //! - `anthropic` (default): Anthropic Claude API, `claude-sonnet-4-6`
 //! - `openai`: OpenAI API
 //! - `claude-code-cli`: local Claude Code CLI, usually authenticated through Zed/Claude Code
+//!
+//! Set `AGENT_BACKEND_FALLBACK` (e.g. `openai,claude-code-cli`) to have
+//! `create_llm_client` retry rate-limited or 5xx requests on the listed
+//! backends in order.
 #![deny(unreachable_pub)]
 
 // LLM client abstraction
@@ -22,6 +26,7 @@ pub mod anthropic_client;
 pub mod backend;
 pub mod claude_code_cli_client;
 pub mod client_factory;
+pub mod fallback_client;
 pub mod llm_client;
 pub mod openai_client;
 
@@ -42,7 +47,7 @@ pub mod lexicon_agent;
 
 // Re-exports for convenience
 pub use backend::AgentBackend;
-pub use client_factory::create_llm_client;
+pub use client_factory::{create_llm_client, create_llm_client_with_fallback};
 pub use intent::{ClarificationRequest, IntentResult, OnboardingIntent};
 pub use lexicon::IntentAst;
-pub use llm_client::LlmClient;
+pub use llm_client::{ApiError, LlmClient, OnToken, TokenUsage};
@@ -32,12 +32,85 @@ pub struct ToolCallResult {
     pub arguments: serde_json::Value,
 }
 
+/// Token counts reported by a provider
+///
+/// Per request from [`LlmClient::chat_with_usage`], or summed across calls
+/// by [`LlmClient::token_usage`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Input/prompt tokens
+    pub prompt: u64,
+    /// Output/completion tokens
+    pub completion: u64,
+    /// Prompt + completion
+    pub total: u64,
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.prompt += other.prompt;
+        self.completion += other.completion;
+        self.total += other.total;
+    }
+}
+
+/// Non-success HTTP response from a provider API
+#[derive(Debug, thiserror::Error)]
+#[error("{provider} API error {status}: {body}")]
+pub struct ApiError {
+    pub provider: &'static str,
+    pub status: reqwest::StatusCode,
+    pub body: String,
+}
+
+impl ApiError {
+    /// Rate limits and server-side failures, which another backend may not share
+    pub fn is_transient(&self) -> bool {
+        self.status == reqwest::StatusCode::TOO_MANY_REQUESTS || self.status.is_server_error()
+    }
+}
+
+/// Callback receiving each text delta from [`LlmClient::chat_streaming`]
+pub type OnToken<'a> = dyn FnMut(&str) + Send + 'a;
+
 /// Unified LLM client interface for both Anthropic and OpenAI
 #[async_trait]
 pub trait LlmClient: Send + Sync {
     /// Call the LLM with system + user prompts, return raw text response
     async fn chat(&self, system_prompt: &str, user_prompt: &str) -> Result<String>;
 
+    /// [`chat`](Self::chat), also returning the tokens this request consumed
+    ///
+    /// Unlike [`token_usage`](Self::token_usage), the usage belongs to this
+    /// request alone, even when other requests share the client. Defaults to
+    /// zero usage for clients whose backend doesn't report it.
+    async fn chat_with_usage(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+    ) -> Result<(String, TokenUsage)> {
+        Ok((
+            self.chat(system_prompt, user_prompt).await?,
+            TokenUsage::default(),
+        ))
+    }
+
+    /// Call the LLM with system + user prompts, streaming the text response
+    ///
+    /// `on_token` is called with each text delta as it arrives; the returned
+    /// string is their concatenation. The default delivers the whole
+    /// [`chat`](Self::chat) response as a single delta.
+    async fn chat_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &mut OnToken<'_>,
+    ) -> Result<String> {
+        let text = self.chat(system_prompt, user_prompt).await?;
+        on_token(&text);
+        Ok(text)
+    }
+
     /// Call the LLM expecting JSON response
     /// - For OpenAI: uses response_format json_object mode
     /// - For Anthropic: adds JSON instruction to system prompt
@@ -60,4 +133,124 @@ pub trait LlmClient: Send + Sync {
 
     /// Get the provider name for logging
     fn provider_name(&self) -> &str;
+
+    /// Tokens consumed by this client so far, summed over all calls
+    ///
+    /// Cumulative: the total never resets, includes every request made
+    /// through this client (and, for the HTTP clients, its clones), and so
+    /// can't attribute usage to one request when calls overlap. Use
+    /// [`chat_with_usage`](Self::chat_with_usage) for per-request counts.
+    /// Defaults to zero for clients whose backend doesn't report usage.
+    fn token_usage(&self) -> TokenUsage {
+        TokenUsage::default()
+    }
+}
+
+/// Incremental decoder for `text/event-stream` response bodies
+///
+/// Body chunks can split lines (and UTF-8 sequences) anywhere, so bytes are
+/// buffered until a full line arrives. Only `data:` payloads are returned;
+/// event names, comments and blank separators are dropped.
+#[derive(Debug, Default)]
+pub(crate) struct SseDecoder {
+    buffer: Vec<u8>,
+}
+
+impl SseDecoder {
+    /// Feed a body chunk, returning the `data:` payloads it completed
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(chunk);
+        let mut payloads = Vec::new();
+        while let Some(end) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            if let Some(data) = line.trim_end_matches(['\r', '\n']).strip_prefix("data:") {
+                payloads.push(data.trim_start().to_string());
+            }
+        }
+        payloads
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve one `text/event-stream` response, writing each event as a
+    /// separate chunk. Returns the URL to post to.
+    pub(crate) async fn serve_sse_once(events: Vec<String>) -> String {
+        serve_once("text/event-stream", events).await
+    }
+
+    /// Serve one `application/json` response. Returns the URL to post to.
+    pub(crate) async fn serve_json_once(body: serde_json::Value) -> String {
+        serve_once("application/json", vec![body.to_string()]).await
+    }
+
+    async fn serve_once(content_type: &'static str, chunks: Vec<String>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            read_request(&mut socket).await;
+            let head = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: {content_type}\r\nconnection: close\r\n\r\n"
+            );
+            socket.write_all(head.as_bytes()).await.unwrap();
+            for chunk in chunks {
+                socket.write_all(chunk.as_bytes()).await.unwrap();
+                socket.flush().await.unwrap();
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+        });
+        format!("http://{addr}/v1/stream")
+    }
+
+    /// Drain the request headers and body so closing the socket is clean
+    async fn read_request(socket: &mut tokio::net::TcpStream) {
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+        loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&request);
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let content_length = text[..header_end]
+                    .lines()
+                    .find_map(|l| {
+                        let (name, value) = l.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length")
+                            .then(|| value.trim().parse::<usize>().ok())?
+                    })
+                    .unwrap_or(0);
+                if request.len() >= header_end + 4 + content_length {
+                    return;
+                }
+            }
+            if n == 0 {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sse_decoder_handles_split_chunks() {
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.push(b"event: ping\nda").is_empty());
+        assert_eq!(
+            decoder.push(b"ta: {\"a\":1}\r\n\ndata:[DONE]\n"),
+            vec!["{\"a\":1}".to_string(), "[DONE]".to_string()]
+        );
+        // A multi-byte character split across chunks survives intact
+        let bytes = "data: caf\u{e9}\n".as_bytes();
+        let (head, tail) = bytes.split_at(bytes.len() - 2);
+        assert!(decoder.push(head).is_empty());
+        assert_eq!(decoder.push(tail), vec!["caf\u{e9}".to_string()]);
+    }
 }
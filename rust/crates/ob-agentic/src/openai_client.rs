@@ -5,18 +5,68 @@
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use serde::Deserialize;
+use std::sync::{Arc, Mutex};
 
-use super::llm_client::{LlmClient, ToolCallResult, ToolDefinition};
+use super::llm_client::{
+    ApiError, LlmClient, OnToken, SseDecoder, TokenUsage, ToolCallResult, ToolDefinition,
+};
+
+/// Usage block on a Chat Completions response
+#[derive(Debug, Deserialize)]
+struct ApiUsage {
+    prompt_tokens: u64,
+    completion_tokens: u64,
+    total_tokens: u64,
+}
+
+impl From<ApiUsage> for TokenUsage {
+    fn from(usage: ApiUsage) -> Self {
+        TokenUsage {
+            prompt: usage.prompt_tokens,
+            completion: usage.completion_tokens,
+            total: usage.total_tokens,
+        }
+    }
+}
+
+/// Chunk on a streaming Chat Completions response
+#[derive(Deserialize)]
+struct StreamChunk {
+    #[serde(default)]
+    choices: Vec<StreamChoice>,
+    /// Only on the final chunk, when `stream_options.include_usage` is set
+    #[serde(default)]
+    usage: Option<ApiUsage>,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct StreamChoice {
+    delta: StreamDelta,
+}
+
+#[derive(Deserialize)]
+struct StreamDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
 
 /// Default OpenAI model
 const DEFAULT_MODEL: &str = "gpt-4o";
 
+/// Default Chat Completions endpoint
+const API_URL: &str = "https://api.openai.com/v1/chat/completions";
+
 /// OpenAI API client
 #[derive(Clone)]
 pub struct OpenAiClient {
     api_key: String,
     client: reqwest::Client,
     model: String,
+    endpoint: String,
+    /// Running token total; shared between clones of this client
+    usage: Arc<Mutex<TokenUsage>>,
 }
 
 impl OpenAiClient {
@@ -27,6 +77,8 @@ impl OpenAiClient {
             api_key,
             client: reqwest::Client::new(),
             model,
+            endpoint: API_URL.to_string(),
+            usage: Arc::default(),
         }
     }
 
@@ -36,9 +88,17 @@ impl OpenAiClient {
             api_key,
             client: reqwest::Client::new(),
             model: model.to_string(),
+            endpoint: API_URL.to_string(),
+            usage: Arc::default(),
         }
     }
 
+    /// Send requests to a different endpoint (proxy, gateway or test server)
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
     /// Create from environment variables
     pub fn from_env() -> Result<Self> {
         let api_key = std::env::var("OPENAI_API_KEY")
@@ -46,13 +106,22 @@ impl OpenAiClient {
         Ok(Self::new(api_key))
     }
 
+    /// Add a response's usage block to the running total, returning it
+    fn record_usage(&self, usage: Option<ApiUsage>) -> TokenUsage {
+        let usage: TokenUsage = usage.map(Into::into).unwrap_or_default();
+        if let Ok(mut total) = self.usage.lock() {
+            *total += usage;
+        }
+        usage
+    }
+
     /// Internal API call implementation
     async fn call_api(
         &self,
         system_prompt: &str,
         user_prompt: &str,
         json_mode: bool,
-    ) -> Result<String> {
+    ) -> Result<(String, TokenUsage)> {
         let mut body = serde_json::json!({
             "model": &self.model,
             "temperature": 0,
@@ -68,7 +137,7 @@ impl OpenAiClient {
 
         let response = self
             .client
-            .post("https://api.openai.com/v1/chat/completions")
+            .post(&self.endpoint)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .header("Content-Type", "application/json")
             .json(&body)
@@ -78,7 +147,12 @@ impl OpenAiClient {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(anyhow!("OpenAI API error {}: {}", status, body));
+            return Err(ApiError {
+                provider: "OpenAI",
+                status,
+                body,
+            }
+            .into());
         }
 
         #[derive(Deserialize)]
@@ -92,14 +166,81 @@ impl OpenAiClient {
         #[derive(Deserialize)]
         struct ApiResponse {
             choices: Vec<Choice>,
+            #[serde(default)]
+            usage: Option<ApiUsage>,
         }
 
         let api_response: ApiResponse = response.json().await?;
+        let usage = self.record_usage(api_response.usage);
         api_response
             .choices
             .first()
             .map(|c| c.message.content.clone())
             .ok_or_else(|| anyhow!("OpenAI returned no choices"))
+            .map(|text| (text, usage))
+    }
+
+    /// Internal API call over server-sent events
+    async fn call_api_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &mut OnToken<'_>,
+    ) -> Result<String> {
+        let mut response = self
+            .client
+            .post(&self.endpoint)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .header("Content-Type", "application/json")
+            .json(&serde_json::json!({
+                "model": &self.model,
+                "temperature": 0,
+                "stream": true,
+                "stream_options": {"include_usage": true},
+                "messages": [
+                    {"role": "system", "content": system_prompt},
+                    {"role": "user", "content": user_prompt}
+                ]
+            }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(ApiError {
+                provider: "OpenAI",
+                status,
+                body,
+            }
+            .into());
+        }
+
+        let mut decoder = SseDecoder::default();
+        let mut text = String::new();
+        while let Some(chunk) = response.chunk().await? {
+            for data in decoder.push(&chunk) {
+                if data == "[DONE]" {
+                    continue;
+                }
+                let chunk: StreamChunk = serde_json::from_str(&data)?;
+                if let Some(error) = chunk.error {
+                    return Err(anyhow!("OpenAI stream error: {}", error));
+                }
+                for delta in chunk
+                    .choices
+                    .into_iter()
+                    .filter_map(|c| c.delta.content)
+                    .filter(|d| !d.is_empty())
+                {
+                    on_token(&delta);
+                    text.push_str(&delta);
+                }
+                self.record_usage(chunk.usage);
+            }
+        }
+
+        Ok(text)
     }
 
     /// Internal API call with function_calling for structured output
@@ -111,7 +252,7 @@ impl OpenAiClient {
     ) -> Result<ToolCallResult> {
         let response = self
             .client
-            .post("https://api.openai.com/v1/chat/completions")
+            .post(&self.endpoint)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .header("Content-Type", "application/json")
             .json(&serde_json::json!({
@@ -134,7 +275,12 @@ impl OpenAiClient {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(anyhow!("OpenAI API error {}: {}", status, body));
+            return Err(ApiError {
+                provider: "OpenAI",
+                status,
+                body,
+            }
+            .into());
         }
 
         // Parse function_call response
@@ -155,6 +301,8 @@ impl OpenAiClient {
         #[derive(Deserialize)]
         struct ApiResponse {
             choices: Vec<Choice>,
+            #[serde(default)]
+            usage: Option<ApiUsage>,
         }
 
         let response_text = response.text().await?;
@@ -165,6 +313,7 @@ impl OpenAiClient {
 
         let api_response: ApiResponse = serde_json::from_str(&response_text)
             .map_err(|e| anyhow!("Failed to parse OpenAI response: {}", e))?;
+        self.record_usage(api_response.usage);
 
         let function_call = api_response
             .choices
@@ -193,11 +342,31 @@ impl OpenAiClient {
 #[async_trait]
 impl LlmClient for OpenAiClient {
     async fn chat(&self, system_prompt: &str, user_prompt: &str) -> Result<String> {
+        let (text, _) = self.call_api(system_prompt, user_prompt, false).await?;
+        Ok(text)
+    }
+
+    async fn chat_with_usage(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+    ) -> Result<(String, TokenUsage)> {
         self.call_api(system_prompt, user_prompt, false).await
     }
 
+    async fn chat_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &mut OnToken<'_>,
+    ) -> Result<String> {
+        self.call_api_streaming(system_prompt, user_prompt, on_token)
+            .await
+    }
+
     async fn chat_json(&self, system_prompt: &str, user_prompt: &str) -> Result<String> {
-        self.call_api(system_prompt, user_prompt, true).await
+        let (text, _) = self.call_api(system_prompt, user_prompt, true).await?;
+        Ok(text)
     }
 
     async fn chat_with_tool(
@@ -217,6 +386,10 @@ impl LlmClient for OpenAiClient {
     fn provider_name(&self) -> &str {
         "OpenAI"
     }
+
+    fn token_usage(&self) -> TokenUsage {
+        self.usage.lock().map(|usage| *usage).unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -235,4 +408,58 @@ mod tests {
         let client = OpenAiClient::with_model("test-key".to_string(), "gpt-4o");
         assert_eq!(client.model_name(), "gpt-4o");
     }
+
+    #[test]
+    fn test_usage_block_accumulates() {
+        let usage: ApiUsage = serde_json::from_str(
+            r#"{"prompt_tokens": 812, "completion_tokens": 96, "total_tokens": 908}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            TokenUsage::from(usage),
+            TokenUsage {
+                prompt: 812,
+                completion: 96,
+                total: 908,
+            }
+        );
+
+        let client = OpenAiClient::new("test-key".to_string());
+        let usage = r#"{"prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15}"#;
+        client.record_usage(serde_json::from_str(usage).ok());
+        client
+            .clone()
+            .record_usage(serde_json::from_str(usage).ok());
+        client.record_usage(None);
+        assert_eq!(client.token_usage().total, 30);
+    }
+
+    #[tokio::test]
+    async fn test_chat_streaming_emits_each_delta() {
+        let events = [
+            r#"{"choices":[{"index":0,"delta":{"role":"assistant","content":""}}]}"#,
+            r#"{"choices":[{"index":0,"delta":{"content":"Hel"}}]}"#,
+            r#"{"choices":[{"index":0,"delta":{"content":"lo"}}]}"#,
+            r#"{"choices":[{"index":0,"delta":{"content":" world"}}]}"#,
+            r#"{"choices":[{"index":0,"delta":{},"finish_reason":"stop"}]}"#,
+            r#"{"choices":[],"usage":{"prompt_tokens":12,"completion_tokens":3,"total_tokens":15}}"#,
+            "[DONE]",
+        ]
+        .iter()
+        .map(|data| format!("data: {data}\n\n"))
+        .collect();
+        let url = crate::llm_client::test_support::serve_sse_once(events).await;
+        let client =
+            OpenAiClient::with_model("test-key".to_string(), "gpt-test").with_endpoint(url);
+
+        let mut tokens = Vec::new();
+        let text = client
+            .chat_streaming("system", "hi", &mut |t: &str| tokens.push(t.to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(tokens, vec!["Hel", "lo", " world"]);
+        assert_eq!(text, "Hello world");
+        assert_eq!(client.token_usage().total, 15);
+    }
 }
@@ -6,6 +6,7 @@
 use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use crate::intent::OnboardingIntent;
 use crate::patterns::OnboardingPattern;
@@ -20,6 +21,28 @@ pub struct OnboardingPlan {
     pub ssis: Vec<SsiPlan>,
     pub booking_rules: Vec<BookingRulePlan>,
     pub isdas: Vec<IsdaPlan>,
+    /// Every step above, in an order that satisfies binding dependencies
+    #[serde(default)]
+    pub steps: Vec<PlanStep>,
+}
+
+/// One DSL statement to emit, with the bindings it produces and consumes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanStep {
+    pub description: String,
+    /// Binding this step creates (without the `@`)
+    pub produces: Option<String>,
+    /// Bindings that must exist before this step runs
+    pub requires: Vec<String>,
+}
+
+/// A plan whose steps cannot be put in a valid order
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PlanningError {
+    #[error("step '{step}' requires @{binding}, which no step produces")]
+    MissingBinding { step: String, binding: String },
+    #[error("circular binding dependency between steps: {}", steps.join(", "))]
+    Cycle { steps: Vec<String> },
 }
 
 /// CBU creation plan
@@ -104,7 +127,7 @@ pub struct RequirementPlanner;
 
 impl RequirementPlanner {
     /// Plan complete onboarding from intent
-    pub fn plan(intent: &OnboardingIntent) -> OnboardingPlan {
+    pub fn plan(intent: &OnboardingIntent) -> Result<OnboardingPlan, PlanningError> {
         let pattern = intent.classify();
 
         let cbu = Self::plan_cbu(intent);
@@ -114,7 +137,7 @@ impl RequirementPlanner {
         let booking_rules = Self::derive_booking_rules(&universe, &ssis);
         let isdas = Self::plan_isdas(intent);
 
-        OnboardingPlan {
+        let mut plan = OnboardingPlan {
             pattern,
             cbu,
             entities,
@@ -122,7 +145,115 @@ impl RequirementPlanner {
             ssis,
             booking_rules,
             isdas,
+            steps: Vec::new(),
+        };
+        plan.steps = Self::order_steps(Self::derive_steps(&plan))?;
+        Ok(plan)
+    }
+
+    /// Order steps so each runs after the producers of its bindings
+    ///
+    /// Stable: among steps whose dependencies are met, the earliest in the
+    /// input goes first, so an already-valid order is left unchanged.
+    pub fn order_steps(steps: Vec<PlanStep>) -> Result<Vec<PlanStep>, PlanningError> {
+        let produced: HashSet<&str> = steps.iter().filter_map(|s| s.produces.as_deref()).collect();
+        for step in &steps {
+            if let Some(binding) = step
+                .requires
+                .iter()
+                .find(|b| !produced.contains(b.as_str()))
+            {
+                return Err(PlanningError::MissingBinding {
+                    step: step.description.clone(),
+                    binding: binding.clone(),
+                });
+            }
+        }
+
+        let mut pending = steps;
+        let mut ordered = Vec::with_capacity(pending.len());
+        let mut bound: HashSet<String> = HashSet::new();
+        while !pending.is_empty() {
+            let Some(next) = pending
+                .iter()
+                .position(|s| s.requires.iter().all(|b| bound.contains(b)))
+            else {
+                return Err(PlanningError::Cycle {
+                    steps: pending.into_iter().map(|s| s.description).collect(),
+                });
+            };
+            let step = pending.remove(next);
+            bound.extend(step.produces.clone());
+            ordered.push(step);
         }
+        Ok(ordered)
+    }
+
+    fn derive_steps(plan: &OnboardingPlan) -> Vec<PlanStep> {
+        let cbu = &plan.cbu.variable;
+        let mut steps = vec![PlanStep {
+            description: format!("CBU {}", plan.cbu.name),
+            produces: Some(cbu.clone()),
+            requires: vec![],
+        }];
+
+        steps.extend(plan.entities.iter().map(|e| PlanStep {
+            description: format!("Entity {}", e.name),
+            produces: Some(e.variable.clone()),
+            requires: vec![],
+        }));
+
+        steps.extend(plan.universe.iter().map(|u| {
+            PlanStep {
+                description: format!(
+                    "Universe {} {}",
+                    u.instrument_class,
+                    u.market.as_deref().unwrap_or("OTC")
+                ),
+                produces: None,
+                requires: std::iter::once(cbu.clone())
+                    .chain(u.counterparty_var.clone())
+                    .collect(),
+            }
+        }));
+
+        steps.extend(plan.ssis.iter().map(|s| PlanStep {
+            description: format!("SSI {}", s.name),
+            produces: Some(s.variable.clone()),
+            requires: vec![cbu.clone()],
+        }));
+
+        steps.extend(plan.booking_rules.iter().map(|r| {
+            PlanStep {
+                description: format!("Booking rule {}", r.name),
+                produces: None,
+                requires: [
+                    Some(cbu.clone()),
+                    Some(r.ssi_variable.clone()),
+                    r.counterparty_var.clone(),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
+            }
+        }));
+
+        for isda in &plan.isdas {
+            steps.push(PlanStep {
+                description: format!("ISDA with {}", isda.counterparty_name),
+                produces: Some(isda.variable.clone()),
+                requires: vec![cbu.clone(), isda.counterparty_var.clone()],
+            });
+            if let Some(csa) = &isda.csa {
+                steps.push(PlanStep {
+                    description: format!("{} CSA with {}", csa.csa_type, isda.counterparty_name),
+                    produces: Some(csa.variable.clone()),
+                    requires: vec![isda.variable.clone()],
+                });
+            }
+        }
+
+        steps
     }
 
     fn plan_cbu(intent: &OnboardingIntent) -> CbuPlan {
@@ -408,13 +539,15 @@ mod tests {
             otc_counterparties: vec![],
             explicit_requirements: vec![],
             original_request: "Set up Apex Fund for US equities".to_string(),
+            confidence: None,
+            alternatives: vec![],
         }
     }
 
     #[test]
     fn test_simple_equity_plan() {
         let intent = simple_intent();
-        let plan = RequirementPlanner::plan(&intent);
+        let plan = RequirementPlanner::plan(&intent).unwrap();
 
         assert_eq!(plan.pattern, OnboardingPattern::SimpleEquity);
         assert_eq!(plan.cbu.name, "Apex Fund");
@@ -445,7 +578,7 @@ mod tests {
             settlement_types: vec!["DVP".to_string()],
         });
 
-        let plan = RequirementPlanner::plan(&intent);
+        let plan = RequirementPlanner::plan(&intent).unwrap();
 
         assert_eq!(plan.pattern, OnboardingPattern::MultiMarket);
 
@@ -470,7 +603,7 @@ mod tests {
             csa_required: true,
         });
 
-        let plan = RequirementPlanner::plan(&intent);
+        let plan = RequirementPlanner::plan(&intent).unwrap();
 
         assert_eq!(plan.pattern, OnboardingPattern::WithOtc);
 
@@ -494,6 +627,85 @@ mod tests {
             .any(|u| u.instrument_class == "OTC_IRS"));
     }
 
+    fn step(description: &str, produces: Option<&str>, requires: &[&str]) -> PlanStep {
+        PlanStep {
+            description: description.to_string(),
+            produces: produces.map(str::to_string),
+            requires: requires.iter().map(|b| b.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_steps_are_topologically_ordered() {
+        let ordered = RequirementPlanner::order_steps(vec![
+            step("rule", None, &["cbu", "ssi-usd"]),
+            step("ssi", Some("ssi-usd"), &["cbu"]),
+            step("cbu", Some("cbu"), &[]),
+            step("entity", Some("morgan"), &[]),
+        ])
+        .unwrap();
+        let order: Vec<_> = ordered.iter().map(|s| s.description.as_str()).collect();
+        assert_eq!(order, vec!["cbu", "ssi", "rule", "entity"]);
+
+        // Every planned step runs after the producers of its bindings
+        let mut intent = simple_intent();
+        intent.otc_counterparties.push(CounterpartyIntent {
+            name: "Morgan Stanley".to_string(),
+            instruments: vec!["OTC_IRS".to_string()],
+            governing_law: None,
+            csa_required: true,
+        });
+        let plan = RequirementPlanner::plan(&intent).unwrap();
+        let mut bound = HashSet::new();
+        for s in &plan.steps {
+            for binding in &s.requires {
+                assert!(
+                    bound.contains(binding),
+                    "{} runs before @{}",
+                    s.description,
+                    binding
+                );
+            }
+            bound.extend(s.produces.clone());
+        }
+        assert!(plan
+            .steps
+            .iter()
+            .any(|s| s.produces.as_deref() == Some("csa-morgan")));
+    }
+
+    #[test]
+    fn test_unsatisfiable_dependency_names_binding() {
+        let err = RequirementPlanner::order_steps(vec![
+            step("cbu", Some("cbu"), &[]),
+            step("isda", Some("isda-ubs"), &["cbu", "ubs"]),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err,
+            PlanningError::MissingBinding {
+                step: "isda".to_string(),
+                binding: "ubs".to_string(),
+            }
+        );
+        assert!(err.to_string().contains("@ubs"));
+
+        // No markets or counterparties: the fallback rule has no SSI to book to
+        let mut intent = simple_intent();
+        intent.markets.clear();
+        assert!(matches!(
+            RequirementPlanner::plan(&intent),
+            Err(PlanningError::MissingBinding { binding, .. }) if binding == "ssi-default"
+        ));
+
+        let err = RequirementPlanner::order_steps(vec![
+            step("a", Some("a"), &["b"]),
+            step("b", Some("b"), &["a"]),
+        ])
+        .unwrap_err();
+        assert!(matches!(err, PlanningError::Cycle { steps } if steps.len() == 2));
+    }
+
     #[test]
     fn test_entity_variable() {
         assert_eq!(
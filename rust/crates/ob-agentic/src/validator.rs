@@ -2,7 +2,10 @@
 //!
 //! Validates generated DSL using the existing parser and CSG linter.
 
+use std::collections::BTreeMap;
+
 use anyhow::Result;
+use dsl_core::{VerbCall, VerbsConfig};
 use serde::{Deserialize, Serialize};
 
 /// Validation result
@@ -14,13 +17,23 @@ pub struct ValidationResult {
 }
 
 /// Validation error with location info
+///
+/// Same shape as `ob_poc_types::ValidationError`: 1-based `line`/`column`,
+/// and a `suggestion` holding replacement text for a one-click fix.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationError {
     pub line: Option<usize>,
+    pub column: Option<usize>,
     pub message: String,
     pub suggestion: Option<String>,
 }
 
+/// Verb FQN ("domain.verb") -> required argument names
+type VerbCatalogue = BTreeMap<String, Vec<String>>;
+
+/// Maximum edit distance for suggesting a known verb
+const MAX_VERB_SUGGESTION_DISTANCE: usize = 3;
+
 /// DSL validator using existing parser and linter
 pub struct AgentValidator;
 
@@ -42,6 +55,7 @@ impl AgentValidator {
                     is_valid: false,
                     errors: vec![ValidationError {
                         line: Self::extract_line_number(&e),
+                        column: None,
                         message: e,
                         suggestion: None,
                     }],
@@ -59,6 +73,7 @@ impl AgentValidator {
                 is_valid: false,
                 errors: vec![ValidationError {
                     line: None,
+                    column: None,
                     message: "Compilation produced no steps".to_string(),
                     suggestion: None,
                 }],
@@ -66,33 +81,19 @@ impl AgentValidator {
             };
         }
 
-        // Phase 3: Verb existence check against the loaded verb catalogue.
-        // The Op-free compiler emits all VerbCalls without checking existence;
-        // this phase catches unknown verbs before execution.
+        // Phase 3: Verb existence and required-arg check against the loaded
+        // verb catalogue. The Op-free compiler emits all VerbCalls without
+        // checking either; this phase catches them before execution.
         use dsl_core::ConfigLoader;
         let verbs_config = ConfigLoader::from_env().load_verbs().ok();
-        let mut errors: Vec<ValidationError> = Vec::new();
-
-        if let Some(ref cfg) = verbs_config {
-            for step in &compiled.steps {
-                let vc = &step.verb_call;
-                let known = cfg
-                    .domains
-                    .get(&vc.domain)
-                    .and_then(|d| d.verbs.get(&vc.verb))
-                    .is_some();
-                if !known {
-                    errors.push(ValidationError {
-                        line: None,
-                        message: format!(
-                            "Unknown verb '{}.{}': not declared in the verb catalogue",
-                            vc.domain, vc.verb
-                        ),
-                        suggestion: None,
-                    });
-                }
-            }
-        }
+        let errors = match verbs_config {
+            Some(ref cfg) => Self::check_verb_calls(
+                dsl_source,
+                compiled.steps.iter().map(|step| &step.verb_call),
+                &Self::catalogue(cfg),
+            ),
+            None => Vec::new(),
+        };
 
         if !errors.is_empty() {
             return ValidationResult {
@@ -109,6 +110,77 @@ impl AgentValidator {
         }
     }
 
+    fn catalogue(cfg: &VerbsConfig) -> VerbCatalogue {
+        cfg.domains
+            .iter()
+            .flat_map(|(domain, d)| {
+                d.verbs.iter().map(move |(verb, v)| {
+                    let required = v
+                        .args
+                        .iter()
+                        .filter(|a| a.required)
+                        .map(|a| a.name.clone())
+                        .collect();
+                    (format!("{}.{}", domain, verb), required)
+                })
+            })
+            .collect()
+    }
+
+    /// Report unknown verbs (suggesting the nearest known verb) and missing
+    /// required arguments (suggesting the argument keyword)
+    fn check_verb_calls<'a>(
+        source: &str,
+        calls: impl IntoIterator<Item = &'a VerbCall>,
+        catalogue: &VerbCatalogue,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        for vc in calls {
+            let fqn = format!("{}.{}", vc.domain, vc.verb);
+            let (line, column) = Self::line_column(source, vc.span.start);
+
+            let Some(required) = catalogue.get(&fqn) else {
+                errors.push(ValidationError {
+                    line: Some(line),
+                    column: Some(column),
+                    message: format!("Unknown verb '{}': not declared in the verb catalogue", fqn),
+                    suggestion: Self::nearest_verb(&fqn, catalogue),
+                });
+                continue;
+            };
+
+            for arg in required {
+                if !vc.arguments.iter().any(|a| &a.key == arg) {
+                    errors.push(ValidationError {
+                        line: Some(line),
+                        column: Some(column),
+                        message: format!("Missing required argument ':{}' for '{}'", arg, fqn),
+                        suggestion: Some(format!(":{}", arg)),
+                    });
+                }
+            }
+        }
+        errors
+    }
+
+    /// Closest known verb FQN by edit distance, if close enough to be a typo
+    fn nearest_verb(fqn: &str, catalogue: &VerbCatalogue) -> Option<String> {
+        catalogue
+            .keys()
+            .map(|known| (strsim::levenshtein(fqn, known), known))
+            .filter(|(distance, _)| *distance <= MAX_VERB_SUGGESTION_DISTANCE)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, known)| known.clone())
+    }
+
+    /// 1-based line and column for a byte offset into `source`
+    fn line_column(source: &str, offset: usize) -> (usize, usize) {
+        let before = &source[..offset.min(source.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+
     /// Extract line number from error message if present
     fn extract_line_number(error: &str) -> Option<usize> {
         // Try to extract "line X" from error message
@@ -158,6 +230,52 @@ mod tests {
         assert!(!result.errors.is_empty());
     }
 
+    fn catalogue() -> VerbCatalogue {
+        VerbCatalogue::from([
+            (
+                "cbu.ensure".to_string(),
+                vec!["name".to_string(), "jurisdiction".to_string()],
+            ),
+            ("cbu.assign-role".to_string(), vec!["role".to_string()]),
+        ])
+    }
+
+    fn check(dsl: &str) -> Vec<ValidationError> {
+        let program = dsl_core::parse_program(dsl).expect("test DSL parses");
+        let calls: Vec<&VerbCall> = program
+            .statements
+            .iter()
+            .filter_map(|s| match s {
+                dsl_core::Statement::VerbCall(vc) => Some(vc),
+                _ => None,
+            })
+            .collect();
+        AgentValidator::check_verb_calls(dsl, calls, &catalogue())
+    }
+
+    #[test]
+    fn test_unknown_verb_suggests_nearest() {
+        let dsl =
+            "(cbu.ensure :name \"A\" :jurisdiction \"US\" :as @cbu)\n  (cbu.ensur :name \"B\")";
+        let errors = check(dsl);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(errors[0].suggestion.as_deref(), Some("cbu.ensure"));
+        assert_eq!(errors[0].line, Some(2));
+        assert!(errors[0].column.is_some());
+
+        let errors = check(r#"(kyc.open-everything :x "y")"#);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].suggestion, None);
+    }
+
+    #[test]
+    fn test_missing_required_arg_suggests_keyword() {
+        let errors = check(r#"(cbu.ensure :name "Test Fund")"#);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(errors[0].suggestion.as_deref(), Some(":jurisdiction"));
+        assert!(errors[0].message.contains("cbu.ensure"));
+    }
+
     #[test]
     fn test_invalid_verb() {
         let validator = AgentValidator::new().unwrap();
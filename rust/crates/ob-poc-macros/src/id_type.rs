@@ -1,7 +1,7 @@
 //! Implementation of #[derive(IdType)] macro for UUID newtypes
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse_macro_input, Data, DeriveInput, Fields};
 
 pub(crate) fn derive_id_type_impl(input: TokenStream) -> TokenStream {
@@ -67,15 +67,61 @@ pub(crate) fn derive_id_type_impl(input: TokenStream) -> TokenStream {
         }
     };
 
-    // FromStr handles prefix stripping
+    // FromStr requires the prefix (when set) and a valid UUID after it
     let from_str_impl = if let Some(ref pfx) = prefix {
         let pfx_underscore = format!("{}_", pfx);
+        let vis = &input.vis;
+        let error_name = format_ident!("{}ParseError", name);
+        let error_doc = format!("Error parsing a `{}` from a `{}_<uuid>` string.", name, pfx);
         quote! {
+            #[doc = #error_doc]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #vis enum #error_name {
+                /// The input doesn't start with the expected prefix.
+                WrongPrefix {
+                    expected: &'static str,
+                    found: ::std::string::String,
+                },
+                /// The text after the prefix isn't a valid UUID.
+                InvalidUuid(::uuid::Error),
+            }
+
+            impl ::std::fmt::Display for #error_name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    match self {
+                        Self::WrongPrefix { expected, found } => write!(
+                            f,
+                            "invalid {}: expected prefix '{}', got '{}'",
+                            stringify!(#name), expected, found
+                        ),
+                        Self::InvalidUuid(e) => {
+                            write!(f, "invalid {}: {}", stringify!(#name), e)
+                        }
+                    }
+                }
+            }
+
+            impl ::std::error::Error for #error_name {
+                fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                    match self {
+                        Self::WrongPrefix { .. } => None,
+                        Self::InvalidUuid(e) => Some(e),
+                    }
+                }
+            }
+
             impl ::std::str::FromStr for #name {
-                type Err = ::uuid::Error;
+                type Err = #error_name;
                 fn from_str(s: &str) -> Result<Self, Self::Err> {
-                    let uuid_str = s.strip_prefix(#pfx_underscore).unwrap_or(s);
-                    Ok(Self(::uuid::Uuid::parse_str(uuid_str)?))
+                    let uuid_str = s.strip_prefix(#pfx_underscore).ok_or_else(|| {
+                        #error_name::WrongPrefix {
+                            expected: #pfx_underscore,
+                            found: s.to_string(),
+                        }
+                    })?;
+                    ::uuid::Uuid::parse_str(uuid_str)
+                        .map(Self)
+                        .map_err(#error_name::InvalidUuid)
                 }
             }
         }
@@ -95,12 +141,27 @@ pub(crate) fn derive_id_type_impl(input: TokenStream) -> TokenStream {
             pub fn from_uuid(id: #inner_type) -> Self { Self(id) }
             // Return by value (Uuid is Copy) for API compatibility
             pub fn as_uuid(&self) -> #inner_type { self.0 }
+            /// Wrap every UUID, e.g. when loading a column of ids.
+            pub fn from_uuids(ids: Vec<#inner_type>) -> Vec<Self> {
+                ids.into_iter().map(Self).collect()
+            }
+            /// Unwrap every id, e.g. for binding as a UUID array.
+            pub fn into_uuids(ids: Vec<Self>) -> Vec<#inner_type> {
+                ids.into_iter().map(|id| id.0).collect()
+            }
         }
 
         #new_impl
         #display_impl
         #from_str_impl
 
+        impl<'a> ::std::convert::TryFrom<&'a str> for #name {
+            type Error = <Self as ::std::str::FromStr>::Err;
+            fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
         impl From<#inner_type> for #name {
             fn from(id: #inner_type) -> Self { Self(id) }
         }
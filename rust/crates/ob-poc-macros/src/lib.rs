@@ -18,15 +18,16 @@ mod id_type;
 /// Derive macro for UUID-backed ID newtypes.
 ///
 /// Generates implementations for: Clone, Copy, Debug, Display, FromStr,
-/// PartialEq, Eq, Hash, Serialize, Deserialize, and (with `database` feature)
-/// SQLx traits.
+/// `TryFrom<&str>`, PartialEq, Eq, Hash, Serialize, Deserialize, and (with
+/// `database` feature) SQLx traits.
 ///
 /// **Important:** Do NOT also derive Clone, Copy, Debug, PartialEq, Eq, Hash,
 /// Serialize, or Deserialize — IdType generates all of these.
 ///
 /// # Attributes
 ///
-/// - `#[id(prefix = "...")]` - Optional prefix for Display/FromStr (e.g., "req" → "req_<uuid>")
+/// - `#[id(prefix = "...")]` - Optional prefix for Display/FromStr (e.g., "req" → "req_<uuid>").
+///   `FromStr` then requires the prefix and fails with a generated `<Name>ParseError`.
 /// - `#[id(new_v4)]` - Generate `::new()` and `Default` implementations
 ///
 /// # Example
@@ -0,0 +1,26 @@
+//! Runtime tests for the bulk conversions generated by `#[derive(IdType)]`.
+
+// The generated SQLx impls are gated on the consumer's `database` feature,
+// which this test crate doesn't declare.
+#![allow(unexpected_cfgs)]
+
+use ob_poc_macros::IdType;
+use uuid::Uuid;
+
+#[derive(IdType)]
+pub struct EntityId(Uuid);
+
+#[test]
+fn uuids_round_trip_through_batch_helpers() {
+    let uuids = vec![
+        Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap(),
+        Uuid::parse_str("9a1c8e2b-7d4f-4c3a-8b5e-1f2d3c4b5a69").unwrap(),
+        Uuid::nil(),
+    ];
+
+    let ids = EntityId::from_uuids(uuids.clone());
+    assert_eq!(ids.len(), 3);
+    assert_eq!(ids[0].as_uuid(), uuids[0]);
+
+    assert_eq!(EntityId::into_uuids(ids), uuids);
+}
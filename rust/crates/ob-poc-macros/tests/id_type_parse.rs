@@ -0,0 +1,55 @@
+//! Runtime tests for the parsing impls generated by `#[derive(IdType)]`.
+
+// The generated SQLx impls are gated on the consumer's `database` feature,
+// which this test crate doesn't declare.
+#![allow(unexpected_cfgs)]
+
+use ob_poc_macros::IdType;
+use uuid::Uuid;
+
+#[derive(IdType)]
+#[id(prefix = "req")]
+pub struct RequirementId(Uuid);
+
+#[derive(IdType)]
+pub struct PlainId(Uuid);
+
+const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+#[test]
+fn prefixed_id_round_trips() {
+    let id: RequirementId = format!("req_{}", UUID).parse().unwrap();
+    assert_eq!(id.as_uuid(), Uuid::parse_str(UUID).unwrap());
+    assert_eq!(id.to_string(), format!("req_{}", UUID));
+    assert_eq!(RequirementId::try_from(id.to_string().as_str()), Ok(id));
+}
+
+#[test]
+fn prefixed_id_rejects_wrong_prefix() {
+    let err = format!("doc_{}", UUID)
+        .parse::<RequirementId>()
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        RequirementIdParseError::WrongPrefix {
+            expected: "req_",
+            ..
+        }
+    ));
+
+    // A bare UUID is missing the prefix too
+    assert!(RequirementId::try_from(UUID).is_err());
+}
+
+#[test]
+fn prefixed_id_rejects_malformed_uuid() {
+    let err = "req_not-a-uuid".parse::<RequirementId>().unwrap_err();
+    assert!(matches!(err, RequirementIdParseError::InvalidUuid(_)));
+    assert!(err.to_string().starts_with("invalid RequirementId:"));
+}
+
+#[test]
+fn plain_id_parses_bare_uuid() {
+    assert!(PlainId::try_from(UUID).is_ok());
+    assert!(PlainId::try_from("nope").is_err());
+}
@@ -7,6 +7,18 @@ use crate::types::{EntityDef, EntityTaxonomyConfig, FkRelationship};
 use std::collections::HashMap;
 use std::path::Path;
 
+/// An entity type found by [`EntityTaxonomy::search`].
+#[derive(Debug, Clone)]
+pub struct TaxonomyMatch<'a> {
+    /// The matching entity type name
+    pub entity_type: &'a str,
+    /// Definition of the matching type
+    pub def: &'a EntityDef,
+    /// Ancestor type names following `parent_type`, root first; empty for a
+    /// root type
+    pub path: Vec<&'a str>,
+}
+
 /// Entity taxonomy loaded from configuration.
 #[derive(Debug, Clone)]
 pub struct EntityTaxonomy {
@@ -262,6 +274,59 @@ impl EntityTaxonomy {
             .map(|s| s.as_str())
     }
 
+    /// Case-insensitive substring search on entity type names.
+    ///
+    /// Matches are sorted by type name, each with its ancestor path for
+    /// breadcrumbing. An empty query matches nothing.
+    pub fn search(&self, query: &str) -> Vec<TaxonomyMatch<'_>> {
+        let needle = query.trim().to_lowercase();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<TaxonomyMatch<'_>> = self
+            .entities
+            .iter()
+            .filter(|(entity_type, _)| entity_type.to_lowercase().contains(&needle))
+            .map(|(entity_type, def)| TaxonomyMatch {
+                entity_type,
+                def,
+                path: self.ancestors(entity_type),
+            })
+            .collect();
+        matches.sort_by(|a, b| a.entity_type.cmp(b.entity_type));
+        matches
+    }
+
+    /// Entity type names in `category` (case-insensitive), sorted.
+    pub fn filter_by_category(&self, category: &str) -> Vec<&str> {
+        let mut types: Vec<&str> = self
+            .entities
+            .iter()
+            .filter(|(_, def)| def.category.eq_ignore_ascii_case(category))
+            .map(|(entity_type, _)| entity_type.as_str())
+            .collect();
+        types.sort_unstable();
+        types
+    }
+
+    /// Ancestors of `entity_type` via `parent_type`, root first.
+    ///
+    /// Stops at the first repeated type so a misconfigured cycle terminates.
+    fn ancestors(&self, entity_type: &str) -> Vec<&str> {
+        let mut path: Vec<&str> = Vec::new();
+        let mut current = entity_type;
+        while let Some(parent) = self.parent_type(current) {
+            if parent == entity_type || path.contains(&parent) {
+                break;
+            }
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+
     /// Check if an entity type is a subtype of another.
     pub fn is_subtype_of(&self, entity_type: &str, parent: &str) -> bool {
         let mut current = entity_type;
@@ -311,6 +376,22 @@ entities:
       allowed: true
       canonical_verb: "entity.create"
       required_args: [entity-type, name]
+  proper_person:
+    description: "Natural person"
+    category: entity
+    parent_type: entity
+    db:
+      schema: ob-poc
+      table: entity_proper_persons
+      pk: entity_id
+  uk_proper_person:
+    description: "UK-resident natural person"
+    category: entity
+    parent_type: proper_person
+    db:
+      schema: ob-poc
+      table: entity_proper_persons
+      pk: entity_id
 relationships:
   - parent: cbu
     child: document
@@ -365,4 +446,35 @@ reference_tables: {}
             Some("cbu")
         );
     }
+
+    #[test]
+    fn test_search_returns_ancestor_path() {
+        let taxonomy = EntityTaxonomy::from_yaml(TEST_YAML).unwrap();
+
+        let matches = taxonomy.search("UK_Proper");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].entity_type, "uk_proper_person");
+        assert_eq!(matches[0].path, vec!["entity", "proper_person"]);
+
+        let names: Vec<_> = taxonomy
+            .search("person")
+            .iter()
+            .map(|m| m.entity_type)
+            .collect();
+        assert_eq!(names, vec!["proper_person", "uk_proper_person"]);
+
+        assert!(taxonomy.search("cbu")[0].path.is_empty());
+        assert!(taxonomy.search("  ").is_empty());
+    }
+
+    #[test]
+    fn test_filter_by_category() {
+        let taxonomy = EntityTaxonomy::from_yaml(TEST_YAML).unwrap();
+        assert_eq!(
+            taxonomy.filter_by_category("Entity"),
+            vec!["entity", "proper_person", "uk_proper_person"]
+        );
+        assert_eq!(taxonomy.filter_by_category("subject"), vec!["cbu"]);
+        assert!(taxonomy.filter_by_category("document").is_empty());
+    }
 }
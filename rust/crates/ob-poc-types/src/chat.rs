@@ -57,6 +57,18 @@ pub enum ChatMessageRole {
     System,
 }
 
+/// Indices of messages whose content contains `query`, case-insensitively.
+/// A blank query matches every message, so a cleared search box shows all.
+pub fn filter_messages(messages: &[ChatMessage], query: &str) -> Vec<usize> {
+    let needle = query.trim().to_lowercase();
+    messages
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| needle.is_empty() || m.content.to_lowercase().contains(&needle))
+        .map(|(i, _)| i)
+        .collect()
+}
+
 // ============================================================================
 // CHAT API
 // ============================================================================
@@ -722,3 +734,110 @@ pub enum ChatPayload {
         commands: Option<Vec<AgentCommand>>,
     },
 }
+
+/// Flattens a V2 response for clients that only understand [`ChatResponse`]
+///
+/// `Ready` DSL becomes `dsl`; a disambiguation request is set on
+/// `disambiguation_request` and its prompt appended to `message`, so UIs
+/// that ignore the request still tell the user what is needed. An unknown
+/// `session_state` string maps to the default state.
+impl From<ChatResponseV2> for ChatResponse {
+    fn from(v2: ChatResponseV2) -> Self {
+        let session_state =
+            serde_json::from_value(serde_json::Value::String(v2.session_state)).unwrap_or_default();
+        let mut message = v2.message;
+        let (dsl, commands, disambiguation_request) = match v2.payload {
+            ChatPayload::Ready {
+                dsl_source,
+                ast,
+                can_execute,
+                commands,
+            } => (
+                Some(crate::DslState {
+                    source: Some(dsl_source),
+                    ast,
+                    can_execute,
+                    bindings: Default::default(),
+                }),
+                commands,
+                None,
+            ),
+            ChatPayload::NeedsDisambiguation { disambiguation } => {
+                if !disambiguation.prompt.is_empty() && !message.contains(&disambiguation.prompt) {
+                    if !message.is_empty() {
+                        message.push_str("\n\n");
+                    }
+                    message.push_str(&disambiguation.prompt);
+                }
+                (None, None, Some(disambiguation))
+            }
+            ChatPayload::Message { commands } => (None, commands, None),
+        };
+
+        ChatResponse {
+            message,
+            dsl,
+            session_state,
+            commands,
+            disambiguation_request,
+            verb_disambiguation: None,
+            intent_tier: None,
+            unresolved_refs: None,
+            current_ref_index: None,
+            dsl_hash: None,
+            decision: None,
+            available_verbs: None,
+            surface_fingerprint: None,
+            sage_explain: None,
+            drafter_proposal: None,
+            discovery_bootstrap: None,
+            parked_entries: None,
+            onboarding_state: None,
+            runbook_plan: None,
+            session_feedback: None,
+            narration: None,
+            acp_trace: None,
+            trace_id: None,
+            bpmn_form: None,
+        }
+    }
+}
+
+impl ChatResponse {
+    /// Best-effort conversion to [`ChatResponseV2`]
+    ///
+    /// A disambiguation request wins over DSL, and DSL with a source is
+    /// `Ready`; anything else is a plain `Message`. Fields V2 has no place
+    /// for (verb disambiguation, decisions, narration, ...) are dropped.
+    pub fn into_v2(self) -> ChatResponseV2 {
+        let session_state = serde_json::to_value(&self.session_state)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let payload = match (self.disambiguation_request, self.dsl) {
+            (Some(disambiguation), _) => ChatPayload::NeedsDisambiguation { disambiguation },
+            (
+                None,
+                Some(crate::DslState {
+                    source: Some(dsl_source),
+                    ast,
+                    can_execute,
+                    ..
+                }),
+            ) => ChatPayload::Ready {
+                dsl_source,
+                ast,
+                can_execute,
+                commands: self.commands,
+            },
+            (None, _) => ChatPayload::Message {
+                commands: self.commands,
+            },
+        };
+        ChatResponseV2 {
+            message: self.message,
+            payload,
+            session_state,
+        }
+    }
+}
@@ -133,6 +133,104 @@ pub enum DisambiguationSelection {
     },
 }
 
+impl DisambiguationRequest {
+    /// Check that `resp` answers this request completely
+    ///
+    /// Every `EntityMatch` and `InterpretationChoice` item needs a selection,
+    /// and each selection must name one of its item's options. Client group
+    /// matches are resolved through scope selection, not here. Returns one
+    /// message per problem.
+    pub fn validate_response(&self, resp: &DisambiguationResponse) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        if resp.request_id != self.request_id {
+            problems.push(format!(
+                "response is for request '{}', expected '{}'",
+                resp.request_id, self.request_id
+            ));
+        }
+
+        for item in &self.items {
+            match item {
+                DisambiguationItem::EntityMatch {
+                    param,
+                    search_text,
+                    matches,
+                    ..
+                } => {
+                    let selected = resp.selections.iter().find_map(|s| match s {
+                        DisambiguationSelection::Entity {
+                            param: p,
+                            entity_id,
+                        } if p == param => Some(entity_id),
+                        _ => None,
+                    });
+                    match selected {
+                        None => problems.push(format!(
+                            "no entity selected for '{}' ({})",
+                            search_text, param
+                        )),
+                        Some(id) if !matches.iter().any(|m| &m.entity_id == id) => {
+                            problems.push(format!(
+                                "entity '{}' is not a match offered for '{}' ({})",
+                                id, search_text, param
+                            ))
+                        }
+                        Some(_) => {}
+                    }
+                }
+                DisambiguationItem::InterpretationChoice { text, options } => {
+                    let selected = resp.selections.iter().find_map(|s| match s {
+                        DisambiguationSelection::Interpretation {
+                            text: t,
+                            interpretation_id,
+                        } if t == text => Some(interpretation_id),
+                        _ => None,
+                    });
+                    match selected {
+                        None => problems.push(format!("no interpretation selected for '{}'", text)),
+                        Some(id) if !options.iter().any(|o| &o.id == id) => problems.push(format!(
+                            "interpretation '{}' is not an option for '{}'",
+                            id, text
+                        )),
+                        Some(_) => {}
+                    }
+                }
+                DisambiguationItem::ClientGroupMatch { .. } => {}
+            }
+        }
+
+        for selection in &resp.selections {
+            let answers_an_item = self.items.iter().any(|item| match (item, selection) {
+                (
+                    DisambiguationItem::EntityMatch { param, .. },
+                    DisambiguationSelection::Entity { param: p, .. },
+                ) => p == param,
+                (
+                    DisambiguationItem::InterpretationChoice { text, .. },
+                    DisambiguationSelection::Interpretation { text: t, .. },
+                ) => t == text,
+                _ => false,
+            });
+            if !answers_an_item {
+                problems.push(match selection {
+                    DisambiguationSelection::Entity { param, .. } => {
+                        format!("selection for '{}' matches no item in the request", param)
+                    }
+                    DisambiguationSelection::Interpretation { text, .. } => {
+                        format!("selection for '{}' matches no item in the request", text)
+                    }
+                });
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+}
+
 // ============================================================================
 // VERB DISAMBIGUATION API (for ambiguous verb matches)
 // ============================================================================
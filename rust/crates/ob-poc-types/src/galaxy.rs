@@ -542,6 +542,9 @@ pub struct ViewTransition {
     pub camera_arrived: bool,
     /// Whether content is loaded and ready
     pub content_ready: bool,
+    /// Duration override and easing curve
+    #[serde(default)]
+    pub easing: EasingConfig,
 }
 
 impl Default for ViewTransition {
@@ -560,6 +563,7 @@ impl Default for ViewTransition {
             },
             camera_arrived: true,
             content_ready: true,
+            easing: EasingConfig::default(),
         }
     }
 }
@@ -592,9 +596,32 @@ impl ViewTransition {
             },
             camera_arrived: false,
             content_ready: false,
+            easing: EasingConfig::default(),
         }
     }
 
+    /// Apply an easing config; a duration override replaces the
+    /// level-based default (e.g. to slow transitions for presentations)
+    pub fn with_easing(mut self, easing: EasingConfig) -> Self {
+        if let Some(duration) = easing.duration {
+            self.duration = duration.max(f32::EPSILON);
+        }
+        self.easing = easing;
+        self
+    }
+
+    /// Advance by `dt` seconds, updating progress; returns true when complete
+    pub fn advance(&mut self, dt: f32) -> bool {
+        self.elapsed += dt;
+        self.progress = (self.elapsed / self.duration).clamp(0.0, 1.0);
+        self.is_complete()
+    }
+
+    /// Progress after applying the easing curve
+    pub fn eased_progress(&self) -> f32 {
+        self.easing.function.apply(self.progress)
+    }
+
     /// Check if transition is complete
     pub fn is_complete(&self) -> bool {
         self.progress >= 1.0
@@ -620,7 +647,7 @@ impl ViewTransition {
     pub fn depth_factor(&self) -> f32 {
         let from_depth = Self::level_to_depth(&self.from_level);
         let to_depth = Self::level_to_depth(&self.to_level);
-        let t = Self::ease_out_cubic(self.progress);
+        let t = self.eased_progress();
         from_depth + (to_depth - from_depth) * t
     }
 
@@ -653,13 +680,49 @@ impl ViewTransition {
             ViewLevel::Core => 5,
         }
     }
+}
 
-    /// Ease-out cubic for smooth deceleration
-    fn ease_out_cubic(t: f32) -> f32 {
-        1.0 - (1.0 - t).powi(3)
+/// Easing curve for view transitions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum EasingFunction {
+    /// Constant speed
+    Linear,
+    /// Cubic ease-in-out: slow start and finish
+    EaseInOut,
+    /// Cubic ease-out: fast start, smooth deceleration
+    #[default]
+    EaseOutCubic,
+}
+
+impl EasingFunction {
+    /// Map linear progress `t` (clamped to 0.0 - 1.0) onto the curve
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            EasingFunction::Linear => t,
+            EasingFunction::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            EasingFunction::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+        }
     }
 }
 
+/// Timing for view transitions
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct EasingConfig {
+    /// Duration in seconds; None keeps the level-based default
+    #[serde(default)]
+    pub duration: Option<f32>,
+    #[serde(default)]
+    pub function: EasingFunction,
+}
+
 /// Camera path for transitions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -2408,6 +2471,45 @@ impl NavSuggestion {
 mod tests {
     use super::*;
 
+    #[test]
+    fn easing_endpoints_and_midpoints() {
+        let cases = [
+            (EasingFunction::Linear, 0.5),
+            (EasingFunction::EaseInOut, 0.5),
+            (EasingFunction::EaseOutCubic, 0.875),
+        ];
+        for (function, mid) in cases {
+            assert_eq!(function.apply(0.0), 0.0, "{function:?} at 0");
+            assert!(
+                (function.apply(0.5) - mid).abs() < 1e-6,
+                "{function:?} at 0.5"
+            );
+            assert_eq!(function.apply(1.0), 1.0, "{function:?} at 1");
+        }
+    }
+
+    #[test]
+    fn easing_config_overrides_duration() {
+        let slow = EasingConfig {
+            duration: Some(2.0),
+            function: EasingFunction::Linear,
+        };
+        let mut transition = ViewTransition::new(
+            ViewLevel::Universe,
+            NavigationScope::Universe,
+            ViewLevel::System,
+            NavigationScope::Universe,
+            (0.0, 0.0),
+            (10.0, 0.0),
+        )
+        .with_easing(slow);
+
+        assert!(!transition.advance(1.0));
+        assert!((transition.eased_progress() - 0.5).abs() < 1e-6);
+        assert!(transition.advance(1.0));
+        assert_eq!(transition.eased_progress(), 1.0);
+    }
+
     #[test]
     fn navigation_scope_tagged() {
         let scope = NavigationScope::Cbu {
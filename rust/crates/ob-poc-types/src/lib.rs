@@ -123,8 +123,8 @@ use uuid::Uuid;
 // Replaces 8 wildcard re-exports — surface unchanged, but every type is now
 // reviewable at a glance and follow-on dead-surface audits can prune the list.
 pub use chat::{
-    BindingSummary, ChatDebugInfo, ChatMessage, ChatMessageRole, ChatPayload, ChatRequest,
-    ChatResponse, ChatResponseV2, ChatStreamEvent, DiscoveryBootstrapPayload,
+    filter_messages, BindingSummary, ChatDebugInfo, ChatMessage, ChatMessageRole, ChatPayload,
+    ChatRequest, ChatResponse, ChatResponseV2, ChatStreamEvent, DiscoveryBootstrapPayload,
     DiscoveryConstellationOption, DiscoveryDomainOption, DiscoveryFamilyOption,
     DiscoveryInputPrompt, DiscoveryQuestionPrompt, DiscoveryUniverseOption, DraftProposalPayload,
     DslDisplaySegment, EnrichedDsl, EntityCandidateDebug, EntityMentionDebug,
@@ -154,7 +154,7 @@ pub use onboarding_state::{
 pub use resolution::{
     CancelResolutionResponse, CommitResolutionResponse, ConfirmAllRequest,
     ConfirmResolutionRequest, DiscriminatorField, DiscriminatorFieldType, EntityMatchResponse,
-    EntityStatus, EnumValue, RefContext, ResolutionContextInfo, ResolutionMethod,
+    EntityStatus, EnumValue, RefContext, ResolutionCache, ResolutionContextInfo, ResolutionMethod,
     ResolutionModeHint, ResolutionRequiredPayload, ResolutionSearchRequest,
     ResolutionSearchResponse, ResolutionSessionResponse, ResolutionStateResponse,
     ResolutionSummary, ResolutionWarning, ResolvedRefResponse, ReviewRequirement, SearchKeyField,
@@ -353,7 +353,7 @@ pub use viewport::{
     CameraState, CbuRef, CbuViewMemory, CbuViewType, ConcreteEntityRef, ConcreteEntityType,
     ConfidenceZone, ConfigNodeRef, EnhanceArg, EnhanceLevelInfo, EnhanceOp, Enhanceable,
     FocusManager, FocusMode, InstrumentMatrixRef, InstrumentType, ProductServiceRef,
-    ViewportFilters, ViewportFocusState, ViewportState,
+    ViewportFilters, ViewportFocusState, ViewportHistory, ViewportState,
 };
 
 // ============================================================================
@@ -440,6 +440,10 @@ pub struct SessionStateResponse {
     #[serde(default)]
     pub message_count: usize,
 
+    /// How many of those messages `messages` holds (None from older servers)
+    #[serde(default)]
+    pub returned_message_count: Option<usize>,
+
     /// Pending intents awaiting validation (empty vec if none, skipped in JSON if empty)
     #[serde(default)]
     pub pending_intents: Vec<serde_json::Value>,
@@ -476,6 +480,12 @@ pub struct SessionStateResponse {
 }
 
 impl SessionStateResponse {
+    /// Whether older messages were compacted into a summary marker
+    pub fn history_truncated(&self) -> bool {
+        self.returned_message_count
+            .is_some_and(|returned| returned < self.message_count)
+    }
+
     /// Get combined DSL source (for UI compatibility)
     pub fn dsl_source(&self) -> Option<&str> {
         self.combined_dsl.as_deref()
@@ -514,6 +524,61 @@ impl SessionStateResponse {
             .and_then(|b| serde_json::from_value(b.clone()).ok())
             .unwrap_or_default()
     }
+
+    /// Context bindings as summaries, keyed by symbol, with the active CBU
+    /// marked primary
+    ///
+    /// Unlike [`get_bindings`](Self::get_bindings), a malformed payload is an
+    /// error rather than an empty map. Missing keys are not errors.
+    pub fn bindings_typed(
+        &self,
+    ) -> Result<std::collections::HashMap<String, BindingSummary>, serde_json::Error> {
+        let field = |key: &str| self.context.get(key).cloned().unwrap_or_default();
+        let bindings: Option<std::collections::HashMap<String, ContextBinding>> =
+            serde_json::from_value(field("bindings"))?;
+        let active: Option<ContextBinding> = serde_json::from_value(field("active_cbu"))?;
+
+        Ok(bindings
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(symbol, b)| {
+                let summary = BindingSummary {
+                    is_primary: active.as_ref().is_some_and(|a| a.id == b.id),
+                    symbol: symbol.clone(),
+                    display_name: b.display_name,
+                    entity_type: b.entity_type,
+                    entity_id: b.id,
+                };
+                (symbol, summary)
+            })
+            .collect())
+    }
+
+    /// `context` as [`SessionContextInfo`] (defaults if the server sent none)
+    pub fn context_typed(&self) -> Result<SessionContextInfo, serde_json::Error> {
+        if self.context.is_null() {
+            return Ok(SessionContextInfo::default());
+        }
+        serde_json::from_value(self.context.clone())
+    }
+
+    /// `pending_intents` deserialized into the caller's intent type
+    pub fn pending_intents_typed<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<Vec<T>, serde_json::Error> {
+        self.pending_intents
+            .iter()
+            .map(|intent| serde_json::from_value(intent.clone()))
+            .collect()
+    }
+}
+
+/// A bound entity as the server serializes it inside the session context
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextBinding {
+    pub id: String,
+    pub entity_type: String,
+    pub display_name: String,
 }
 
 /// Session context information
@@ -770,6 +835,170 @@ pub struct CbuGraphResponse {
     pub edges: Vec<GraphEdge>,
 }
 
+impl CbuGraphResponse {
+    /// Number of nodes per role category, for rendering a legend.
+    /// A node with several categories counts once towards each.
+    pub fn role_category_counts(&self) -> std::collections::BTreeMap<String, usize> {
+        let mut counts = std::collections::BTreeMap::new();
+        for category in self.nodes.iter().flat_map(|n| &n.role_categories) {
+            *counts.entry(category.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Verification statuses aggregated over every edge in the graph.
+    /// `total_edges` includes pending and unverified edges.
+    pub fn verification_rollup(&self) -> VerificationSummary {
+        let mut summary = VerificationSummary {
+            total_edges: self.edges.len() as i32,
+            ..Default::default()
+        };
+        for status in self
+            .edges
+            .iter()
+            .filter_map(|e| EdgeVerification::classify(e.verification_status.as_deref()))
+        {
+            match status {
+                EdgeVerification::Proven => summary.proven_edges += 1,
+                EdgeVerification::Alleged => summary.alleged_edges += 1,
+                EdgeVerification::Disputed => summary.disputed_edges += 1,
+            }
+        }
+        summary
+    }
+
+    /// Export as Cytoscape.js JSON: `{ "elements": { "nodes": [...], "edges": [...] } }`
+    ///
+    /// Node and edge ids are ours unchanged. Visual hints travel in `data`,
+    /// with `importance` also mapped to a `size` in pixels; each edge's
+    /// `verification_status` becomes a line style class (solid when proven,
+    /// dashed alleged, dotted disputed, otherwise faded). Nodes inside a
+    /// container get it as their compound `parent` when the container is in
+    /// the graph.
+    pub fn to_cytoscape(&self) -> serde_json::Value {
+        use serde_json::{json, Map, Value};
+
+        let node_ids: std::collections::HashSet<&str> =
+            self.nodes.iter().map(|n| n.id.as_str()).collect();
+        let nodes: Vec<Value> = self
+            .nodes
+            .iter()
+            .map(|n| {
+                let mut data = Map::new();
+                data.insert("id".into(), json!(n.id));
+                data.insert("label".into(), json!(n.label));
+                data.insert("node_type".into(), json!(n.node_type));
+                data.insert("layer".into(), json!(n.layer));
+                data.insert("status".into(), json!(n.status));
+                data.insert("roles".into(), json!(n.roles));
+                data.insert("needs_attention".into(), json!(n.needs_attention));
+                let optional = [
+                    ("sublabel", json!(n.sublabel)),
+                    ("primary_role", json!(n.primary_role)),
+                    ("jurisdiction", json!(n.jurisdiction)),
+                    ("importance", json!(n.importance)),
+                    ("size", json!(n.importance.map(cytoscape_node_size))),
+                    ("hierarchy_depth", json!(n.hierarchy_depth)),
+                    ("kyc_completion", json!(n.kyc_completion)),
+                    ("entity_category", json!(n.entity_category)),
+                    ("person_state", json!(n.person_state)),
+                ];
+                for (key, value) in optional {
+                    if !value.is_null() {
+                        data.insert(key.into(), value);
+                    }
+                }
+                if let Some(parent) = n
+                    .container_parent_id
+                    .as_deref()
+                    .filter(|p| *p != n.id && node_ids.contains(p))
+                {
+                    data.insert("parent".into(), json!(parent));
+                }
+
+                let mut classes = vec![n.node_type.to_lowercase()];
+                if n.needs_attention {
+                    classes.push("needs-attention".into());
+                }
+                if let Some(state) = &n.person_state {
+                    classes.push(state.to_lowercase());
+                }
+                let mut element = json!({ "data": data, "classes": classes.join(" ") });
+                if let (Some(x), Some(y)) = (n.x, n.y) {
+                    element["position"] = json!({ "x": x, "y": y });
+                }
+                element
+            })
+            .collect();
+
+        let edges: Vec<Value> = self
+            .edges
+            .iter()
+            .map(|e| {
+                let mut data = Map::new();
+                data.insert("id".into(), json!(e.id));
+                data.insert("source".into(), json!(e.source));
+                data.insert("target".into(), json!(e.target));
+                data.insert("edge_type".into(), json!(e.edge_type));
+                for (key, value) in [
+                    ("label", json!(e.label)),
+                    ("weight", json!(e.weight)),
+                    ("verification_status", json!(e.verification_status)),
+                ] {
+                    if !value.is_null() {
+                        data.insert(key.into(), value);
+                    }
+                }
+                let line = verification_line_class(e.verification_status.as_deref());
+                json!({ "data": data, "classes": format!("{} {}", e.edge_type.to_lowercase(), line) })
+            })
+            .collect();
+
+        json!({ "elements": { "nodes": nodes, "edges": edges } })
+    }
+}
+
+/// Smallest and largest Cytoscape node size (px) for importance 0.0 and 1.0
+const CYTOSCAPE_NODE_SIZE: (f32, f32) = (20.0, 60.0);
+
+fn cytoscape_node_size(importance: f32) -> f32 {
+    let (min, max) = CYTOSCAPE_NODE_SIZE;
+    min + importance.clamp(0.0, 1.0) * (max - min)
+}
+
+/// Edge verification statuses the graph views tell apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EdgeVerification {
+    Proven,
+    Alleged,
+    Disputed,
+}
+
+impl EdgeVerification {
+    /// Matched case-insensitively, with "verified" treated as proven, the
+    /// same way the server parses `VerificationStatus`. Pending, unverified,
+    /// waived and unknown statuses classify as `None`.
+    fn classify(status: Option<&str>) -> Option<Self> {
+        match status?.to_lowercase().as_str() {
+            "proven" | "verified" => Some(Self::Proven),
+            "alleged" => Some(Self::Alleged),
+            "disputed" => Some(Self::Disputed),
+            _ => None,
+        }
+    }
+}
+
+/// Cytoscape line style class for an edge verification status: proven edges
+/// are solid, alleged dashed, disputed dotted, and pending or unknown faded
+fn verification_line_class(status: Option<&str>) -> &'static str {
+    match EdgeVerification::classify(status) {
+        Some(EdgeVerification::Proven) => "line-solid",
+        Some(EdgeVerification::Alleged) => "line-dashed",
+        Some(EdgeVerification::Disputed) => "line-dotted",
+        None => "line-faded",
+    }
+}
+
 /// Multi-CBU scope graph response
 /// Contains combined graph for all CBUs in session scope
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -916,6 +1145,115 @@ pub struct GraphEdge {
     pub verification_status: Option<String>,
 }
 
+/// Importance falloff per hop from the root in [`recompute_importance`].
+const IMPORTANCE_FALLOFF: f32 = 0.8;
+/// Importance given to nodes unreachable from the root.
+///
+/// Zero, so it stays below `IMPORTANCE_FALLOFF^depth` however deep the chain.
+const UNREACHABLE_IMPORTANCE: f32 = 0.0;
+
+/// Recompute `importance` and `hierarchy_depth` relative to a new root.
+///
+/// Depth is the BFS hop count from `root`, following edges in either
+/// direction; importance is `1.0` at the root and falls by a factor of
+/// 0.8 per hop. Nodes not connected to `root` get no depth and the minimum
+/// importance. Pure and DB-free so the UI can call it when rerooting.
+pub fn recompute_importance(nodes: &mut [GraphNode], edges: &[GraphEdge], root: &str) {
+    use std::collections::{HashMap, VecDeque};
+
+    let mut adjacency: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in edges {
+        adjacency
+            .entry(&edge.source)
+            .or_default()
+            .push(&edge.target);
+        adjacency
+            .entry(&edge.target)
+            .or_default()
+            .push(&edge.source);
+    }
+
+    let mut depths: HashMap<&str, i32> = HashMap::from([(root, 0)]);
+    let mut queue = VecDeque::from([root]);
+    while let Some(id) = queue.pop_front() {
+        let next = depths[id] + 1;
+        for &neighbour in adjacency.get(id).into_iter().flatten() {
+            depths.entry(neighbour).or_insert_with(|| {
+                queue.push_back(neighbour);
+                next
+            });
+        }
+    }
+
+    for node in nodes.iter_mut() {
+        match depths.get(node.id.as_str()) {
+            Some(&depth) => {
+                node.hierarchy_depth = Some(depth);
+                node.importance = Some(IMPORTANCE_FALLOFF.powi(depth));
+            }
+            None => {
+                node.hierarchy_depth = None;
+                node.importance = Some(UNREACHABLE_IMPORTANCE);
+            }
+        }
+    }
+}
+
+/// How the renderer should draw edges.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeRouting {
+    /// Every edge is a straight line; no offsets are computed.
+    #[default]
+    Straight,
+    /// Edges sharing both endpoints fan out as curves.
+    Bundled,
+}
+
+/// Spacing between neighbouring curves in a bundle, in layout units.
+const EDGE_BUNDLE_SPACING: f32 = 24.0;
+
+/// Control-point offsets for drawing bundled edges, keyed by edge id.
+///
+/// Edges joining the same pair of nodes (in either direction) are spread
+/// symmetrically around the straight line between them. The offset is
+/// perpendicular to the edge's own source→target direction, so it is
+/// negated for edges running against the pair's canonical order. Edges
+/// with no parallel sibling (and the middle edge of an odd bundle) get no
+/// entry and are drawn straight, as is everything under
+/// [`EdgeRouting::Straight`]. Deterministic for a given edge order; O(E).
+pub fn edge_bundle_offsets(
+    edges: &[GraphEdge],
+    routing: EdgeRouting,
+) -> std::collections::HashMap<String, f32> {
+    use std::collections::HashMap;
+
+    let mut offsets = HashMap::new();
+    if routing == EdgeRouting::Straight {
+        return offsets;
+    }
+
+    let mut bundles: HashMap<(&str, &str), Vec<&GraphEdge>> = HashMap::new();
+    for edge in edges {
+        let (a, b) = (edge.source.as_str(), edge.target.as_str());
+        let key = if a <= b { (a, b) } else { (b, a) };
+        bundles.entry(key).or_default().push(edge);
+    }
+
+    for ((low, _), bundle) in bundles {
+        let centre = (bundle.len() as f32 - 1.0) / 2.0;
+        for (i, edge) in bundle.iter().enumerate() {
+            let offset = (i as f32 - centre) * EDGE_BUNDLE_SPACING;
+            if offset == 0.0 {
+                continue;
+            }
+            let sign = if edge.source == low { 1.0 } else { -1.0 };
+            offsets.insert(edge.id.clone(), offset * sign);
+        }
+    }
+    offsets
+}
+
 // ============================================================================
 // DSL API
 // ============================================================================
@@ -1012,6 +1350,34 @@ pub struct AstSpan {
     pub end_line: Option<u32>,
 }
 
+/// Count `EntityRef` values as `(resolved, unresolved)` across all
+/// statements, including refs nested in lists and maps.
+/// A ref is resolved when it carries a `resolved_key`.
+pub fn ast_resolution_summary(statements: &[AstStatement]) -> (usize, usize) {
+    fn visit(value: &AstValue, counts: &mut (usize, usize)) {
+        match value {
+            AstValue::EntityRef { resolved_key, .. } => {
+                if resolved_key.is_some() {
+                    counts.0 += 1;
+                } else {
+                    counts.1 += 1;
+                }
+            }
+            AstValue::List { items } => items.iter().for_each(|v| visit(v, counts)),
+            AstValue::Map { entries } => entries.iter().for_each(|e| visit(&e.value, counts)),
+            _ => {}
+        }
+    }
+
+    let mut counts = (0, 0);
+    for statement in statements {
+        if let AstStatement::VerbCall { arguments, .. } = statement {
+            arguments.iter().for_each(|a| visit(&a.value, &mut counts));
+        }
+    }
+    counts
+}
+
 // ============================================================================
 // EVENT PAYLOADS
 // Keep these dead simple - just IDs
@@ -1130,6 +1496,55 @@ pub struct ValidateDslResponse {
     pub warnings: Vec<String>,
 }
 
+/// Editor marker for a validation error, positioned within the source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationMarker {
+    /// 0-based line index
+    pub line: usize,
+    /// 0-based column (in chars) within the line
+    pub col: usize,
+    /// Underline length in chars (the token at `col`, at least 1 on non-empty lines)
+    pub len: usize,
+    /// The validation error's message, shown as the marker's hover text
+    pub message: String,
+}
+
+/// Map validation errors (1-based `line`/`column`) to editor markers.
+///
+/// Positions are clamped to the source: a missing or out-of-range line lands
+/// on the last line, a missing column on the first character, and a column
+/// past the end on the last character.
+pub fn errors_to_markers(errors: &[ValidationError], source: &str) -> Vec<ValidationMarker> {
+    let lines: Vec<&str> = source.lines().collect();
+    let last_line = lines.len().saturating_sub(1);
+    errors
+        .iter()
+        .map(|error| {
+            let line = error
+                .line
+                .map_or(last_line, |line| line.saturating_sub(1).min(last_line));
+            let chars: Vec<char> = lines.get(line).copied().unwrap_or("").chars().collect();
+            let col = error
+                .column
+                .unwrap_or(1)
+                .saturating_sub(1)
+                .min(chars.len().saturating_sub(1));
+            let len = chars
+                .iter()
+                .skip(col)
+                .take_while(|c| !c.is_whitespace())
+                .count()
+                .max(usize::from(!chars.is_empty()));
+            ValidationMarker {
+                line,
+                col,
+                len,
+                message: error.message.clone(),
+            }
+        })
+        .collect()
+}
+
 // ============================================================================
 // SESSION CONTEXT API (for agent prompt and UI context panel)
 // ============================================================================
@@ -1302,6 +1717,57 @@ pub struct GetContextResponse {
 // CONVERSION HELPERS
 // ============================================================================
 
+/// Source of new session ids
+///
+/// Production uses [`SessionIdGenerator::Random`]. A seeded generator yields
+/// the same id sequence on every run, so tests and demos can reproduce a
+/// session exactly.
+#[derive(Debug, Default)]
+pub enum SessionIdGenerator {
+    /// Random v4 UUIDs
+    #[default]
+    Random,
+    /// V4-formatted UUIDs derived from a seed and a per-generator counter
+    Seeded {
+        seed: u64,
+        next: std::sync::atomic::AtomicU64,
+    },
+}
+
+impl SessionIdGenerator {
+    /// Deterministic generator: the same seed yields the same id sequence
+    pub fn seeded(seed: u64) -> Self {
+        Self::Seeded {
+            seed,
+            next: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    /// Mint the next session id
+    pub fn next_id(&self) -> Uuid {
+        match self {
+            Self::Random => Uuid::new_v4(),
+            Self::Seeded { seed, next } => {
+                let n = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                let hi = splitmix64(seed ^ splitmix64(n));
+                let lo = splitmix64(hi);
+                let mut bytes = [0u8; 16];
+                bytes[..8].copy_from_slice(&hi.to_be_bytes());
+                bytes[8..].copy_from_slice(&lo.to_be_bytes());
+                uuid::Builder::from_random_bytes(bytes).into_uuid()
+            }
+        }
+    }
+}
+
+/// SplitMix64 finaliser: cheap, well-mixed and stable across platforms
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 impl CreateSessionResponse {
     pub fn new(session_id: Uuid, state: &str, created_at: DateTime<Utc>) -> Self {
         Self {
@@ -1565,6 +2031,601 @@ pub enum AgentStreamEvent {
 mod tests {
     use super::*;
 
+    fn server_session_state() -> SessionStateResponse {
+        serde_json::from_value(serde_json::json!({
+            "session_id": "6f0c1d9e-8a51-4b1e-9d2f-0d7c3a0e4b11",
+            "state": "scoped",
+            "message_count": 2,
+            "pending_intents": [{ "verb": "cbu.ensure", "confidence": 0.9 }],
+            "context": {
+                "stage_focus": "kyc",
+                "domain_hint": "cbu",
+                "named_refs": {},
+                "active_cbu": {
+                    "id": "0b6e2f4a-1c3d-4e5f-8a9b-7c6d5e4f3a21",
+                    "entity_type": "cbu",
+                    "display_name": "Aviva Lux 9"
+                },
+                "bindings": {
+                    "fund": {
+                        "id": "0b6e2f4a-1c3d-4e5f-8a9b-7c6d5e4f3a21",
+                        "entity_type": "cbu",
+                        "display_name": "Aviva Lux 9"
+                    },
+                    "manager": {
+                        "id": "9d8c7b6a-5f4e-4d3c-8b2a-1f0e9d8c7b6a",
+                        "entity_type": "entity",
+                        "display_name": "Aviva Investors"
+                    }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn disambiguation_request() -> DisambiguationRequest {
+        let entity = |id: &str, name: &str| EntityMatch {
+            entity_id: id.to_string(),
+            name: name.to_string(),
+            entity_type: "proper_person".to_string(),
+            jurisdiction: None,
+            context: None,
+            score: None,
+        };
+        DisambiguationRequest {
+            request_id: "req-1".to_string(),
+            items: vec![
+                DisambiguationItem::EntityMatch {
+                    param: "entity-id".to_string(),
+                    search_text: "John Smith".to_string(),
+                    matches: vec![entity("e-1", "John Smith"), entity("e-2", "John A. Smith")],
+                    entity_type: None,
+                    search_column: None,
+                    ref_id: None,
+                },
+                DisambiguationItem::InterpretationChoice {
+                    text: "UK".to_string(),
+                    options: vec![Interpretation {
+                        id: "jurisdiction".to_string(),
+                        label: "Jurisdiction".to_string(),
+                        description: "United Kingdom".to_string(),
+                        effect: None,
+                    }],
+                },
+            ],
+            prompt: "Which did you mean?".to_string(),
+        }
+    }
+
+    fn entity_selection(entity_id: &str) -> DisambiguationSelection {
+        DisambiguationSelection::Entity {
+            param: "entity-id".to_string(),
+            entity_id: entity_id.to_string(),
+        }
+    }
+
+    fn uk_selection() -> DisambiguationSelection {
+        DisambiguationSelection::Interpretation {
+            text: "UK".to_string(),
+            interpretation_id: "jurisdiction".to_string(),
+        }
+    }
+
+    fn assert_v2_round_trips(v2: ChatResponseV2) {
+        let expected = serde_json::to_value(&v2).unwrap();
+        let legacy = ChatResponse::from(v2);
+        assert_eq!(serde_json::to_value(legacy.into_v2()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_chat_v2_ready_round_trips_through_legacy() {
+        let v2 = ChatResponseV2 {
+            message: "Here is the DSL".to_string(),
+            payload: ChatPayload::Ready {
+                dsl_source: "(cbu.ensure :name \"Apex\")".to_string(),
+                ast: Some(vec![AstStatement::Comment {
+                    text: "generated".to_string(),
+                    span: None,
+                }]),
+                can_execute: true,
+                commands: Some(vec![AgentCommand::Undo]),
+            },
+            session_state: "ready_to_execute".to_string(),
+        };
+        let legacy = ChatResponse::from(v2.clone());
+        let dsl = legacy.dsl.as_ref().unwrap();
+        assert_eq!(dsl.source.as_deref(), Some("(cbu.ensure :name \"Apex\")"));
+        assert!(dsl.can_execute);
+        assert!(matches!(
+            legacy.session_state,
+            SessionStateEnum::ReadyToExecute
+        ));
+        assert_v2_round_trips(v2);
+    }
+
+    #[test]
+    fn test_chat_v2_message_round_trips_through_legacy() {
+        let v2 = ChatResponseV2 {
+            message: "Nothing to generate".to_string(),
+            payload: ChatPayload::Message { commands: None },
+            session_state: "scoped".to_string(),
+        };
+        let legacy = ChatResponse::from(v2.clone());
+        assert!(legacy.dsl.is_none());
+        assert!(legacy.disambiguation_request.is_none());
+        assert_v2_round_trips(v2);
+    }
+
+    #[test]
+    fn test_chat_v2_disambiguation_is_surfaced_in_message() {
+        let v2 = ChatResponseV2 {
+            message: "I found several matches.".to_string(),
+            payload: ChatPayload::NeedsDisambiguation {
+                disambiguation: disambiguation_request(),
+            },
+            session_state: "scoped".to_string(),
+        };
+        let legacy = ChatResponse::from(v2);
+        assert_eq!(
+            legacy.message,
+            "I found several matches.\n\nWhich did you mean?"
+        );
+        assert!(legacy.disambiguation_request.is_some());
+        assert!(matches!(
+            legacy.into_v2().payload,
+            ChatPayload::NeedsDisambiguation { .. }
+        ));
+    }
+
+    #[test]
+    fn test_disambiguation_complete_response_is_valid() {
+        let response = DisambiguationResponse {
+            request_id: "req-1".to_string(),
+            selections: vec![entity_selection("e-2"), uk_selection()],
+        };
+        assert_eq!(
+            disambiguation_request().validate_response(&response),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_disambiguation_missing_item_is_reported() {
+        let response = DisambiguationResponse {
+            request_id: "req-1".to_string(),
+            selections: vec![entity_selection("e-1")],
+        };
+        let problems = disambiguation_request()
+            .validate_response(&response)
+            .unwrap_err();
+        assert_eq!(
+            problems,
+            vec!["no interpretation selected for 'UK'".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_disambiguation_unknown_option_is_reported() {
+        let response = DisambiguationResponse {
+            request_id: "req-1".to_string(),
+            selections: vec![entity_selection("e-9"), uk_selection()],
+        };
+        let problems = disambiguation_request()
+            .validate_response(&response)
+            .unwrap_err();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("'e-9'"), "{problems:?}");
+    }
+
+    #[test]
+    fn test_session_state_typed_accessors() {
+        let state = server_session_state();
+
+        let bindings = state.bindings_typed().unwrap();
+        assert_eq!(bindings.len(), 2);
+        assert!(bindings["fund"].is_primary);
+        assert_eq!(bindings["fund"].display_name, "Aviva Lux 9");
+        assert!(!bindings["manager"].is_primary);
+        assert_eq!(bindings["manager"].entity_type, "entity");
+
+        let context = state.context_typed().unwrap();
+        assert_eq!(context.stage_focus.as_deref(), Some("kyc"));
+        assert_eq!(context.domain_hint.as_deref(), Some("cbu"));
+
+        #[derive(Deserialize)]
+        struct Intent {
+            verb: String,
+        }
+        let intents: Vec<Intent> = state.pending_intents_typed().unwrap();
+        assert_eq!(intents[0].verb, "cbu.ensure");
+        assert!(state.pending_intents_typed::<u32>().is_err());
+    }
+
+    #[test]
+    fn test_session_state_typed_accessors_handle_sparse_and_bad_payloads() {
+        let mut state = server_session_state();
+        state.context = serde_json::Value::Null;
+        assert!(state.bindings_typed().unwrap().is_empty());
+        assert!(state.context_typed().unwrap().stage_focus.is_none());
+
+        state.context = serde_json::json!({ "bindings": { "fund": { "id": 7 } } });
+        assert!(state.bindings_typed().is_err());
+        assert!(state.get_bindings().is_empty());
+    }
+
+    #[test]
+    fn test_session_state_history_truncated() {
+        let state = |returned: Option<usize>| {
+            let mut json = serde_json::json!({ "session_id": "s-1", "message_count": 40 });
+            if let Some(n) = returned {
+                json["returned_message_count"] = n.into();
+            }
+            serde_json::from_value::<SessionStateResponse>(json).unwrap()
+        };
+        assert!(state(Some(10)).history_truncated());
+        assert!(!state(Some(40)).history_truncated());
+        assert!(!state(None).history_truncated());
+    }
+
+    #[test]
+    fn test_seeded_session_ids_are_deterministic() {
+        let ids = SessionIdGenerator::seeded(42);
+        let first = ids.next_id();
+        assert_eq!(first.to_string(), "4d9b3f1e-c9cf-4b1b-b46e-c5e8f3ece91f");
+        assert_eq!(
+            ids.next_id().to_string(),
+            "7eb3b394-ac9e-4c29-a791-839b6c9caf25"
+        );
+
+        // Same seed replays the same sequence; ids are valid v4 UUIDs
+        let replay = SessionIdGenerator::seeded(42);
+        assert_eq!(replay.next_id(), first);
+        assert_eq!(replay.next_id().get_version_num(), 4);
+        assert_ne!(SessionIdGenerator::seeded(7).next_id(), first);
+
+        let response = CreateSessionResponse::new(first, "new", DateTime::<Utc>::UNIX_EPOCH);
+        assert_eq!(response.session_id, first.to_string());
+    }
+
+    #[test]
+    fn test_cytoscape_export() {
+        let mut graph = small_graph();
+        graph.nodes[1].importance = Some(0.5);
+        graph.nodes[1].container_parent_id = Some("cbu".into());
+        graph.nodes[2].container_parent_id = Some("elsewhere".into());
+
+        let cy = graph.to_cytoscape();
+        let nodes = cy["elements"]["nodes"].as_array().unwrap();
+        let edges = cy["elements"]["edges"].as_array().unwrap();
+        assert_eq!(nodes.len(), graph.nodes.len());
+        assert_eq!(edges.len(), graph.edges.len());
+
+        let manco = &nodes[1]["data"];
+        assert_eq!(manco["id"], "manco");
+        assert_eq!(manco["importance"], 0.5);
+        assert_eq!(manco["size"], 40.0);
+        assert_eq!(manco["parent"], "cbu");
+        assert!(nodes[2]["data"].get("parent").is_none());
+        assert!(nodes[0]["data"].get("size").is_none());
+
+        assert_eq!(edges[0]["data"]["id"], "e1");
+        assert_eq!(edges[0]["data"]["verification_status"], "proven");
+        assert_eq!(edges[0]["classes"], "ownership line-solid");
+        assert_eq!(edges[2]["classes"], "ownership line-dashed");
+        assert_eq!(edges[5]["classes"], "ownership line-faded");
+
+        assert_eq!(verification_line_class(Some("Proven")), "line-solid");
+        assert_eq!(verification_line_class(Some("VERIFIED")), "line-solid");
+        assert_eq!(verification_line_class(Some("Disputed")), "line-dotted");
+        assert_eq!(verification_line_class(None), "line-faded");
+    }
+
+    fn small_graph() -> CbuGraphResponse {
+        let node = |id: &str, categories: &[&str]| -> GraphNode {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "node_type": "entity",
+                "layer": "core",
+                "label": id,
+                "status": "active",
+                "role_categories": categories,
+            }))
+            .unwrap()
+        };
+        let edge = |id: &str, status: Option<&str>| GraphEdge {
+            id: id.into(),
+            source: "cbu".into(),
+            target: id.into(),
+            edge_type: "ownership".into(),
+            label: None,
+            weight: None,
+            verification_status: status.map(Into::into),
+        };
+        CbuGraphResponse {
+            cbu_id: "cbu".into(),
+            label: "Fund".into(),
+            cbu_category: None,
+            jurisdiction: None,
+            nodes: vec![
+                node("cbu", &[]),
+                node("manco", &["OWNERSHIP_CONTROL", "FUND_MANAGEMENT"]),
+                node("ubo", &["OWNERSHIP_CONTROL"]),
+            ],
+            edges: vec![
+                edge("e1", Some("proven")),
+                edge("e2", Some("proven")),
+                edge("e3", Some("alleged")),
+                edge("e4", Some("disputed")),
+                edge("e5", Some("pending")),
+                edge("e6", None),
+            ],
+        }
+    }
+
+    #[test]
+    fn role_category_counts_tally_each_category() {
+        let counts = small_graph().role_category_counts();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["OWNERSHIP_CONTROL"], 2);
+        assert_eq!(counts["FUND_MANAGEMENT"], 1);
+    }
+
+    #[test]
+    fn recompute_importance_reroots_chain() {
+        // cbu → manco → ubo, plus an isolated node; reroot on the UBO.
+        let mut graph = small_graph();
+        graph.nodes.push(graph.nodes[0].clone());
+        graph.nodes[3].id = "orphan".into();
+        let chain = [("cbu", "manco"), ("manco", "ubo")];
+        let edges: Vec<GraphEdge> = chain
+            .iter()
+            .map(|(s, t)| GraphEdge {
+                source: (*s).into(),
+                target: (*t).into(),
+                ..graph.edges[0].clone()
+            })
+            .collect();
+
+        recompute_importance(&mut graph.nodes, &edges, "ubo");
+
+        let by_id = |id: &str| graph.nodes.iter().find(|n| n.id == id).unwrap();
+        let (ubo, manco, cbu) = (by_id("ubo"), by_id("manco"), by_id("cbu"));
+        assert_eq!(ubo.hierarchy_depth, Some(0));
+        assert_eq!(manco.hierarchy_depth, Some(1));
+        assert_eq!(cbu.hierarchy_depth, Some(2));
+        assert_eq!(ubo.importance, Some(1.0));
+        assert!(manco.importance > cbu.importance);
+        assert!(ubo.importance > manco.importance);
+
+        let orphan = by_id("orphan");
+        assert_eq!(orphan.hierarchy_depth, None);
+        assert!(orphan.importance < cbu.importance);
+    }
+
+    #[test]
+    fn recompute_importance_keeps_deep_nodes_above_unreachable() {
+        // n0 → n1 → … → n12, plus an isolated node; root at n0.
+        let mut graph = small_graph();
+        let template = graph.nodes[0].clone();
+        graph.nodes = (0..=12)
+            .map(|i| format!("n{i}"))
+            .chain(["orphan".to_string()])
+            .map(|id| GraphNode {
+                id,
+                ..template.clone()
+            })
+            .collect();
+        let edges: Vec<GraphEdge> = (0..12)
+            .map(|i| GraphEdge {
+                source: format!("n{i}"),
+                target: format!("n{}", i + 1),
+                ..graph.edges[0].clone()
+            })
+            .collect();
+
+        recompute_importance(&mut graph.nodes, &edges, "n0");
+
+        let by_id = |id: &str| graph.nodes.iter().find(|n| n.id == id).unwrap();
+        let deepest = by_id("n12");
+        assert_eq!(deepest.hierarchy_depth, Some(12));
+        assert!(by_id("orphan").importance < deepest.importance);
+    }
+
+    fn edge_between(id: &str, source: &str, target: &str) -> GraphEdge {
+        GraphEdge {
+            id: id.into(),
+            source: source.into(),
+            target: target.into(),
+            edge_type: "ownership".into(),
+            label: None,
+            weight: None,
+            verification_status: None,
+        }
+    }
+
+    #[test]
+    fn parallel_edges_get_distinct_symmetric_offsets() {
+        let edges = vec![
+            edge_between("e1", "a", "b"),
+            edge_between("e2", "a", "b"),
+            edge_between("solo", "b", "c"),
+        ];
+        let offsets = edge_bundle_offsets(&edges, EdgeRouting::Bundled);
+
+        assert_ne!(offsets["e1"], offsets["e2"]);
+        assert_eq!(offsets["e1"], -offsets["e2"]);
+        assert!(!offsets.contains_key("solo"), "lone edge stays straight");
+        assert_eq!(offsets, edge_bundle_offsets(&edges, EdgeRouting::Bundled));
+    }
+
+    #[test]
+    fn reversed_parallel_edges_bend_to_opposite_sides() {
+        // Drawn in opposite directions, equal offsets would overlap; the sign
+        // flip keeps the curves apart on screen.
+        let edges = vec![edge_between("ab", "a", "b"), edge_between("ba", "b", "a")];
+        let offsets = edge_bundle_offsets(&edges, EdgeRouting::Bundled);
+        assert_eq!(offsets["ab"], offsets["ba"]);
+    }
+
+    #[test]
+    fn straight_routing_computes_no_offsets() {
+        let edges = vec![edge_between("e1", "a", "b"), edge_between("e2", "a", "b")];
+        assert!(edge_bundle_offsets(&edges, EdgeRouting::Straight).is_empty());
+    }
+
+    #[test]
+    fn verification_rollup_counts_statuses() {
+        let rollup = small_graph().verification_rollup();
+        assert_eq!(rollup.total_edges, 6);
+        assert_eq!(rollup.proven_edges, 2);
+        assert_eq!(rollup.alleged_edges, 1);
+        assert_eq!(rollup.disputed_edges, 1);
+    }
+
+    #[test]
+    fn verification_rollup_matches_line_classes() {
+        let mut graph = small_graph();
+        for (edge, status) in graph
+            .edges
+            .iter_mut()
+            .zip(["Verified", "PROVEN", "Alleged"])
+        {
+            edge.verification_status = Some(status.into());
+        }
+
+        let rollup = graph.verification_rollup();
+        assert_eq!(rollup.proven_edges, 2);
+        assert_eq!(rollup.alleged_edges, 1);
+        assert_eq!(rollup.disputed_edges, 1);
+    }
+
+    #[test]
+    fn filter_messages_matches_content_case_insensitively() {
+        let messages: Vec<ChatMessage> = ["Create a CBU for Allianz", "Done.", "show allianz ubo"]
+            .iter()
+            .map(|content| ChatMessage {
+                id: None,
+                role: ChatMessageRole::User,
+                content: content.to_string(),
+                timestamp: None,
+                intents: None,
+                dsl: None,
+                sage_explain: None,
+                drafter_proposal: None,
+                parked_entries: None,
+            })
+            .collect();
+
+        assert_eq!(filter_messages(&messages, ""), vec![0, 1, 2]);
+        assert!(filter_messages(&messages, "lux").is_empty());
+        assert_eq!(filter_messages(&messages, "ALLIANZ"), vec![0, 2]);
+    }
+
+    #[test]
+    fn ast_resolution_summary_counts_nested_refs() {
+        let entity = |key: &str, resolved: Option<&str>| AstValue::EntityRef {
+            entity_type: "entity".into(),
+            search_key: key.into(),
+            resolved_key: resolved.map(String::from),
+        };
+        let arg = |key: &str, value: AstValue| AstArgument {
+            key: key.into(),
+            value,
+            span: None,
+        };
+        let statements = vec![
+            AstStatement::Comment {
+                text: "onboarding".into(),
+                span: None,
+            },
+            AstStatement::VerbCall {
+                domain: "cbu".into(),
+                verb: "assign-role".into(),
+                arguments: vec![
+                    arg("entity-id", entity("Allianz SE", Some("uuid-1"))),
+                    arg(
+                        "role",
+                        AstValue::String {
+                            value: "UBO".into(),
+                        },
+                    ),
+                    arg(
+                        "directors",
+                        AstValue::List {
+                            items: vec![
+                                entity("John Smith", None),
+                                entity("Jane Doe", Some("uuid-2")),
+                            ],
+                        },
+                    ),
+                ],
+                binding: None,
+                span: None,
+            },
+            AstStatement::VerbCall {
+                domain: "entity".into(),
+                verb: "link".into(),
+                arguments: vec![arg(
+                    "targets",
+                    AstValue::Map {
+                        entries: vec![AstMapEntry {
+                            key: "parent".into(),
+                            value: entity("Allianz Holdings", None),
+                        }],
+                    },
+                )],
+                binding: None,
+                span: None,
+            },
+        ];
+
+        assert_eq!(ast_resolution_summary(&statements), (2, 2));
+        assert_eq!(ast_resolution_summary(&[]), (0, 0));
+    }
+
+    #[test]
+    fn errors_to_markers_clamps_positions() {
+        let source = "(cbu.create :name \"Acme\")\n(entity.link :from @cbu :to @ubo)";
+        let error = |line: usize, column: usize| ValidationError {
+            line: Some(line),
+            column: Some(column),
+            message: "bad".into(),
+            suggestion: None,
+        };
+
+        let markers = errors_to_markers(&[error(1, 2), error(2, 29), error(9, 1)], source);
+
+        // Valid position: underlines `cbu.create`
+        assert_eq!(
+            (markers[0].line, markers[0].col, markers[0].len),
+            (0, 1, 10)
+        );
+        // Last line: underlines `@ubo)`
+        assert_eq!(
+            (markers[1].line, markers[1].col, markers[1].len),
+            (1, 28, 5)
+        );
+        // Out-of-range line clamps to the last line
+        assert_eq!((markers[2].line, markers[2].col), (1, 0));
+        assert_eq!(markers[2].message, "bad");
+
+        // Missing line lands on the last line, missing column at its start
+        let unplaced = ValidationError {
+            line: None,
+            column: None,
+            message: "bad".into(),
+            suggestion: None,
+        };
+        let markers = errors_to_markers(&[unplaced], source);
+        assert_eq!(
+            (markers[0].line, markers[0].col, markers[0].len),
+            (1, 0, 12)
+        );
+
+        let empty = errors_to_markers(&[error(3, 3)], "");
+        assert_eq!((empty[0].line, empty[0].col, empty[0].len), (0, 0, 0));
+    }
+
     #[test]
     fn chat_stream_event_tagged_correctly() {
         let event = ChatStreamEvent::Chunk {
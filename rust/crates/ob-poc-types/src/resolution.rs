@@ -9,8 +9,10 @@
 //! 3. User/agent selects resolutions via /resolution/select
 //! 4. POST /resolution/commit - Apply to AST and enable execution
 
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 // ============================================================================
 // RESOLUTION SESSION STATE
@@ -502,6 +504,128 @@ pub struct ResolutionRequiredPayload {
     pub suggestions_made: bool,
 }
 
+// ============================================================================
+// RESOLUTION CACHE
+// ============================================================================
+
+/// Per-session cache of entity-ref matches, keyed by (entity_type, search text)
+///
+/// Avoids repeat gateway calls when the same ref is searched again within a
+/// session. Search text is compared trimmed and case-insensitively. Entries
+/// expire after the TTL and are dropped explicitly via [`Self::invalidate`]
+/// when the user re-selects a resolution.
+#[derive(Debug)]
+pub struct ResolutionCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<(String, String), CachedMatches>>,
+}
+
+#[derive(Debug)]
+struct CachedMatches {
+    matches: Vec<EntityMatchResponse>,
+    inserted_at: DateTime<Utc>,
+}
+
+impl ResolutionCache {
+    /// Create an empty cache whose entries live for `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn key(entity_type: &str, search_text: &str) -> (String, String) {
+        (entity_type.to_string(), search_text.trim().to_lowercase())
+    }
+
+    /// Return unexpired cached matches for a ref, if any
+    ///
+    /// The lock is only held for the lookup, so callers resolve a miss
+    /// (typically an async gateway search) without blocking other refs and
+    /// then store the result with [`Self::insert`].
+    pub fn get(&self, entity_type: &str, search_text: &str) -> Option<Vec<EntityMatchResponse>> {
+        self.get_at(entity_type, search_text, Utc::now())
+    }
+
+    fn get_at(
+        &self,
+        entity_type: &str,
+        search_text: &str,
+        now: DateTime<Utc>,
+    ) -> Option<Vec<EntityMatchResponse>> {
+        let key = Self::key(entity_type, search_text);
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(&key)
+            .filter(|cached| now - cached.inserted_at < self.ttl)
+            .map(|cached| cached.matches.clone())
+    }
+
+    /// Store freshly resolved matches for a ref, replacing any previous entry
+    pub fn insert(&self, entity_type: &str, search_text: &str, matches: Vec<EntityMatchResponse>) {
+        self.insert_at(entity_type, search_text, matches, Utc::now());
+    }
+
+    fn insert_at(
+        &self,
+        entity_type: &str,
+        search_text: &str,
+        matches: Vec<EntityMatchResponse>,
+        now: DateTime<Utc>,
+    ) {
+        let key = Self::key(entity_type, search_text);
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(
+                key,
+                CachedMatches {
+                    matches,
+                    inserted_at: now,
+                },
+            );
+    }
+
+    /// Return cached matches for a ref, resolving and storing them on a miss
+    ///
+    /// The lock is released while `resolve` runs, so a slow resolver does not
+    /// block other refs; two concurrent misses on the same ref may both
+    /// resolve, and the later insert wins.
+    pub fn get_or_insert_with(
+        &self,
+        entity_type: &str,
+        search_text: &str,
+        resolve: impl FnOnce() -> Vec<EntityMatchResponse>,
+    ) -> Vec<EntityMatchResponse> {
+        if let Some(matches) = self.get(entity_type, search_text) {
+            return matches;
+        }
+        let matches = resolve();
+        self.insert(entity_type, search_text, matches.clone());
+        matches
+    }
+
+    /// Drop the entry for a ref, e.g. after the user re-selects its resolution
+    pub fn invalidate(&self, entity_type: &str, search_text: &str) {
+        let key = Self::key(entity_type, search_text);
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&key);
+    }
+
+    /// Number of entries held, including expired ones not yet replaced
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// True if the cache holds no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 // ============================================================================
 // IMPL HELPERS
 // ============================================================================
@@ -543,6 +667,84 @@ impl ResolutionSummary {
 mod tests {
     use super::*;
 
+    fn entity_match(id: &str) -> EntityMatchResponse {
+        EntityMatchResponse {
+            id: id.to_string(),
+            display: "Apex Capital".to_string(),
+            entity_type: "entity".to_string(),
+            score: 0.9,
+            discriminators: HashMap::new(),
+            status: EntityStatus::Active,
+            context: None,
+        }
+    }
+
+    #[test]
+    fn resolution_cache_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ResolutionCache>();
+    }
+
+    #[test]
+    fn resolution_cache_hit() {
+        let cache = ResolutionCache::new(Duration::minutes(5));
+        assert!(cache.get("entity", "Apex Capital").is_none());
+
+        cache.insert("entity", "Apex Capital", vec![entity_match("a")]);
+        let hit = cache.get("entity", "  apex capital ").expect("cache hit");
+
+        assert_eq!(hit[0].id, "a");
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn resolution_cache_get_or_insert_with_resolves_once() {
+        let cache = ResolutionCache::new(Duration::minutes(5));
+        let mut calls = 0;
+
+        let first = cache.get_or_insert_with("entity", "Apex", || {
+            calls += 1;
+            // The lock is not held while resolving
+            assert!(cache.get("entity", "Other").is_none());
+            vec![entity_match("a")]
+        });
+        let second = cache.get_or_insert_with("entity", " APEX ", || {
+            calls += 1;
+            vec![entity_match("b")]
+        });
+
+        assert_eq!(calls, 1);
+        assert_eq!(first[0].id, "a");
+        assert_eq!(second[0].id, "a");
+    }
+
+    #[test]
+    fn resolution_cache_ttl_expiry_misses() {
+        let cache = ResolutionCache::new(Duration::minutes(5));
+        let t0 = Utc::now();
+        cache.insert_at("entity", "Apex", vec![entity_match("old")], t0);
+        assert!(cache
+            .get_at("entity", "Apex", t0 + Duration::minutes(4))
+            .is_some());
+
+        let later = t0 + Duration::minutes(6);
+        assert!(cache.get_at("entity", "Apex", later).is_none());
+
+        cache.insert_at("entity", "Apex", vec![entity_match("new")], later);
+        let refreshed = cache.get_at("entity", "Apex", later).expect("refreshed");
+        assert_eq!(refreshed[0].id, "new");
+    }
+
+    #[test]
+    fn resolution_cache_invalidated_on_reselection() {
+        let cache = ResolutionCache::new(Duration::minutes(5));
+        cache.insert("entity", "Apex", vec![entity_match("a")]);
+
+        cache.invalidate("entity", "Apex");
+        assert!(cache.is_empty());
+        assert!(cache.get("entity", "Apex").is_none());
+    }
+
     #[test]
     fn resolution_state_serializes_snake_case() {
         let state = ResolutionStateResponse::Resolving;
@@ -11,7 +11,7 @@
 //! The entities ARE the truth - this view helps pick the right DSL.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 // ============================================================================
 // CONFIGURATION TYPES (loaded from YAML)
@@ -203,6 +203,31 @@ pub struct Progress {
     pub percentage: f32,
 }
 
+// ============================================================================
+// VERB FILTERING
+// ============================================================================
+
+impl SemanticStageMap {
+    /// Look up a stage definition by code
+    pub fn stage(&self, code: &str) -> Option<&StageDefinition> {
+        self.stages.iter().find(|s| s.code == code)
+    }
+
+    /// Verb FQNs relevant to the focused stage
+    ///
+    /// With a focus, returns that stage's `relevant_verbs` (empty if the stage
+    /// is unknown or declares none). Without a focus, returns every verb any
+    /// stage declares. The UI grays out verbs not in the set.
+    pub fn verbs_for_focus(&self, stage_focus: Option<&str>) -> BTreeSet<String> {
+        self.stages
+            .iter()
+            .filter(|s| stage_focus.is_none_or(|code| s.code == code))
+            .flat_map(|s| s.relevant_verbs.iter().flatten())
+            .cloned()
+            .collect()
+    }
+}
+
 // ============================================================================
 // DISPLAY HELPERS
 // ============================================================================
@@ -314,6 +339,60 @@ mod tests {
         assert!(!StageStatus::Blocked.is_done());
     }
 
+    fn stage_def(code: &str, verbs: &[&str]) -> StageDefinition {
+        StageDefinition {
+            code: code.to_string(),
+            name: code.to_string(),
+            description: String::new(),
+            required_entities: vec![],
+            depends_on: vec![],
+            blocking: false,
+            conditional: None,
+            relevant_verbs: Some(verbs.iter().map(|v| v.to_string()).collect()),
+        }
+    }
+
+    fn verb_filter_fixture() -> SemanticStageMap {
+        SemanticStageMap {
+            stages: vec![
+                stage_def("CLIENT_SETUP", &["cbu.create", "entity.create"]),
+                stage_def("KYC_REVIEW", &["kyc.open-case", "entity.create"]),
+                stage_def("TRADING", &["trading-profile.create"]),
+            ],
+            product_stages: HashMap::new(),
+            entity_stage_mapping: HashMap::new(),
+            condition_definitions: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn verbs_for_focused_stage() {
+        let map = verb_filter_fixture();
+
+        let verbs = map.verbs_for_focus(Some("KYC_REVIEW"));
+        assert_eq!(
+            verbs.into_iter().collect::<Vec<_>>(),
+            vec!["entity.create", "kyc.open-case"]
+        );
+        assert!(map.verbs_for_focus(Some("UNKNOWN")).is_empty());
+    }
+
+    #[test]
+    fn verbs_for_no_focus_returns_all_verbs() {
+        let map = verb_filter_fixture();
+
+        let verbs = map.verbs_for_focus(None);
+        assert_eq!(
+            verbs.into_iter().collect::<Vec<_>>(),
+            vec![
+                "cbu.create",
+                "entity.create",
+                "kyc.open-case",
+                "trading-profile.create"
+            ]
+        );
+    }
+
     #[test]
     fn semantic_state_to_prompt() {
         let state = SemanticState {
@@ -7,7 +7,7 @@
 //! - ASCEND/DESCEND - Hierarchical focus stack navigation
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use uuid::Uuid;
 
 // ============================================================================
@@ -707,6 +707,116 @@ impl Default for ViewportState {
     }
 }
 
+// ============================================================================
+// VIEWPORT HISTORY (Undo/Redo)
+// ============================================================================
+
+/// Bounded undo/redo history of viewport states
+///
+/// Unlike the focus stack (hierarchical ascend/descend), this records every
+/// navigation step in order so the user can step back through where they
+/// have been. Recording a new state after an undo discards the redo branch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewportHistory {
+    /// Recorded states, oldest first
+    states: VecDeque<ViewportState>,
+    /// Index of the current state in `states`
+    cursor: usize,
+    /// Maximum number of states retained
+    capacity: usize,
+}
+
+impl Default for ViewportHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl ViewportHistory {
+    /// Default number of states retained
+    pub const DEFAULT_CAPACITY: usize = 50;
+
+    /// Create an empty history retaining at most `capacity` states (minimum 1)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            states: VecDeque::new(),
+            cursor: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Record `state` as the new current state, truncating any redo branch
+    pub fn push_state(&mut self, state: ViewportState) {
+        if !self.states.is_empty() {
+            self.states.truncate(self.cursor + 1);
+        }
+        self.states.push_back(state);
+        while self.states.len() > self.capacity {
+            self.states.pop_front();
+        }
+        self.cursor = self.states.len() - 1;
+    }
+
+    /// Step back, returning the state to restore
+    pub fn undo(&mut self) -> Option<ViewportState> {
+        if !self.can_undo() {
+            return None;
+        }
+        self.cursor -= 1;
+        self.states.get(self.cursor).cloned()
+    }
+
+    /// Step forward after an undo, returning the state to restore
+    pub fn redo(&mut self) -> Option<ViewportState> {
+        if !self.can_redo() {
+            return None;
+        }
+        self.cursor += 1;
+        self.states.get(self.cursor).cloned()
+    }
+
+    /// Check if there is a state to step back to
+    pub fn can_undo(&self) -> bool {
+        self.cursor > 0
+    }
+
+    /// Check if there is a state to step forward to
+    pub fn can_redo(&self) -> bool {
+        self.cursor + 1 < self.states.len()
+    }
+
+    /// Number of states retained
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Check if nothing has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Apply a focus transition to `state`, recording history only if it succeeds
+    ///
+    /// The transition runs against a copy; on error `state` and the history
+    /// are left untouched. The pre-transition state is recorded first if the
+    /// history is empty, so the first transition can be undone.
+    pub fn record<T, E>(
+        &mut self,
+        state: &mut ViewportState,
+        transition: impl FnOnce(&mut ViewportState) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let mut next = state.clone();
+        let out = transition(&mut next)?;
+
+        if self.is_empty() {
+            self.push_state(state.clone());
+        }
+        self.push_state(next.clone());
+        *state = next;
+        Ok(out)
+    }
+}
+
 /// Active viewport filters
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ViewportFilters {
@@ -724,6 +834,77 @@ pub struct ViewportFilters {
     pub search_text: Option<String>,
 }
 
+impl ViewportFilters {
+    /// Combine filters so only what both allow is shown
+    ///
+    /// Type lists intersect (`None` means "all types"), the stricter
+    /// confidence zone wins, and `other`'s search text takes precedence.
+    /// Used to layer an agent `FilterByType` over the user's filters.
+    pub fn and(&self, other: &ViewportFilters) -> ViewportFilters {
+        ViewportFilters {
+            entity_types: intersect_allowed(&self.entity_types, &other.entity_types),
+            confidence_zone: match (self.confidence_zone, other.confidence_zone) {
+                (Some(a), Some(b)) => Some(if a.min_score() >= b.min_score() { a } else { b }),
+                (a, b) => a.or(b),
+            },
+            instrument_types: intersect_allowed(&self.instrument_types, &other.instrument_types),
+            search_text: other
+                .search_text
+                .clone()
+                .or_else(|| self.search_text.clone()),
+        }
+    }
+
+    /// Combine filters so anything either allows is shown
+    ///
+    /// Type lists union (`None` on either side means "all types"), the looser
+    /// confidence zone wins, and search text survives only if both agree.
+    pub fn or(&self, other: &ViewportFilters) -> ViewportFilters {
+        ViewportFilters {
+            entity_types: union_allowed(&self.entity_types, &other.entity_types),
+            confidence_zone: match (self.confidence_zone, other.confidence_zone) {
+                (Some(a), Some(b)) => Some(if a.min_score() <= b.min_score() { a } else { b }),
+                _ => None,
+            },
+            instrument_types: union_allowed(&self.instrument_types, &other.instrument_types),
+            search_text: if self.search_text == other.search_text {
+                self.search_text.clone()
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Remove all filters
+    pub fn clear(&mut self) {
+        *self = ViewportFilters::default();
+    }
+}
+
+/// Intersect two allow-lists where `None` allows everything
+fn intersect_allowed<T: Copy + PartialEq>(
+    a: &Option<Vec<T>>,
+    b: &Option<Vec<T>>,
+) -> Option<Vec<T>> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.iter().copied().filter(|t| b.contains(t)).collect()),
+        (Some(only), None) | (None, Some(only)) => Some(only.clone()),
+        (None, None) => None,
+    }
+}
+
+/// Union two allow-lists where `None` allows everything
+fn union_allowed<T: Copy + PartialEq>(a: &Option<Vec<T>>, b: &Option<Vec<T>>) -> Option<Vec<T>> {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let mut merged = a.clone();
+            merged.extend(b.iter().copied().filter(|t| !a.contains(t)));
+            Some(merged)
+        }
+        _ => None,
+    }
+}
+
 // ============================================================================
 // RESOLVED TYPES (For ViewportResolutionService)
 // ============================================================================
@@ -899,6 +1080,128 @@ mod tests {
         assert_eq!(ConfidenceZone::from_score(0.0), ConfidenceZone::Speculative);
     }
 
+    fn focused_on(cbu_id: Uuid, enhance_level: u8) -> ViewportState {
+        let mut state = ViewportState::default();
+        state.focus.set_focus(ViewportFocusState::CbuContainer {
+            cbu: CbuRef::new(cbu_id),
+            enhance_level,
+        });
+        state
+    }
+
+    fn enhance(state: &mut ViewportState) -> Result<(), &'static str> {
+        match state.focus.current_mut() {
+            ViewportFocusState::CbuContainer { enhance_level, .. } if *enhance_level < 2 => {
+                *enhance_level += 1;
+                Ok(())
+            }
+            _ => Err("cannot enhance"),
+        }
+    }
+
+    #[test]
+    fn viewport_history_linear_undo_redo() {
+        let cbu = Uuid::new_v4();
+        let mut state = focused_on(cbu, 0);
+        let mut history = ViewportHistory::default();
+
+        history.record(&mut state, enhance).unwrap();
+        history.record(&mut state, enhance).unwrap();
+        assert_eq!(state.focus.current().primary_enhance_level(), 2);
+        assert_eq!(history.len(), 3);
+
+        // Failed transition leaves state and history alone
+        assert!(history.record(&mut state, enhance).is_err());
+        assert_eq!(history.len(), 3);
+
+        let back = history.undo().unwrap();
+        assert_eq!(back.focus.current().primary_enhance_level(), 1);
+        let back = history.undo().unwrap();
+        assert_eq!(back.focus.current().primary_enhance_level(), 0);
+        assert!(history.undo().is_none());
+
+        let forward = history.redo().unwrap();
+        assert_eq!(forward.focus.current().primary_enhance_level(), 1);
+        let forward = history.redo().unwrap();
+        assert_eq!(forward.focus.current().primary_enhance_level(), 2);
+        assert!(history.redo().is_none());
+    }
+
+    #[test]
+    fn viewport_history_branch_truncates_redo() {
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let mut history = ViewportHistory::new(3);
+
+        history.push_state(focused_on(first, 0));
+        history.push_state(focused_on(first, 1));
+        history.push_state(focused_on(first, 2));
+        history.undo();
+        history.undo();
+
+        // New navigation from the oldest state drops both redo entries
+        history.push_state(focused_on(second, 0));
+        assert!(!history.can_redo());
+        assert_eq!(history.len(), 2);
+        let back = history.undo().unwrap();
+        assert_eq!(back.focus.current().cbu(), Some(&CbuRef::new(first)));
+
+        // Capacity evicts the oldest state
+        history.redo();
+        history.push_state(focused_on(second, 1));
+        history.push_state(focused_on(second, 2));
+        assert_eq!(history.len(), 3);
+        history.undo();
+        let oldest = history.undo().unwrap();
+        assert_eq!(oldest.focus.current().cbu(), Some(&CbuRef::new(second)));
+        assert!(!history.can_undo());
+    }
+
+    fn type_filter(types: &[ConcreteEntityType]) -> ViewportFilters {
+        ViewportFilters {
+            entity_types: Some(types.to_vec()),
+            ..ViewportFilters::default()
+        }
+    }
+
+    #[test]
+    fn viewport_filters_and_narrows() {
+        use ConcreteEntityType::*;
+        let user = ViewportFilters {
+            confidence_zone: Some(ConfidenceZone::Penumbra),
+            ..type_filter(&[Company, Trust, Person])
+        };
+        let agent = ViewportFilters {
+            confidence_zone: Some(ConfidenceZone::Shell),
+            ..type_filter(&[Person, Partnership])
+        };
+
+        let combined = user.and(&agent);
+        assert_eq!(combined.entity_types, Some(vec![Person]));
+        assert_eq!(combined.confidence_zone, Some(ConfidenceZone::Shell));
+
+        // An unset side doesn't widen the result
+        let combined = ViewportFilters::default().and(&agent);
+        assert_eq!(combined.entity_types, Some(vec![Person, Partnership]));
+    }
+
+    #[test]
+    fn viewport_filters_or_broadens() {
+        use ConcreteEntityType::*;
+        let a = type_filter(&[Company, Trust]);
+        let b = type_filter(&[Trust, Person]);
+
+        let combined = a.or(&b);
+        assert_eq!(combined.entity_types, Some(vec![Company, Trust, Person]));
+
+        // Unfiltered on either side means everything is allowed
+        assert_eq!(a.or(&ViewportFilters::default()).entity_types, None);
+
+        let mut cleared = combined;
+        cleared.clear();
+        assert_eq!(cleared, ViewportFilters::default());
+    }
+
     #[test]
     fn enhance_arg_apply() {
         assert_eq!(EnhanceArg::Increment.apply(0, 4), 1);
@@ -1504,7 +1504,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .with_session_repository(session_repository)
             .with_runbook_store(runbook_store)
             .with_orchestrated_verbs(orchestrated_verbs)
-            .with_acp_draft_mode(acp_draft_mode);
+            .with_acp_draft_mode(acp_draft_mode)
+            .with_session_ids(Arc::new(
+                ob_poc::api::AgentState::build_session_id_generator(),
+            ));
 
         // v1.2 Tranche 1 T1.F (DoD item 13): wire GatePipeline default-on
         // in production. The runtime gate (GateChecker for Mode A blocking,
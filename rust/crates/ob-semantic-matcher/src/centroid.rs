@@ -22,6 +22,16 @@ pub fn normalize(v: Vec<f32>) -> Vec<f32> {
     }
 }
 
+/// Cosine similarity between two vectors (0.0 if either is all zeros)
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let denom = l2_norm(a) * l2_norm(b);
+    if denom > 0.0 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>() / denom
+    } else {
+        0.0
+    }
+}
+
 /// Compute centroid from a list of embeddings.
 ///
 /// Algorithm:
@@ -107,6 +107,39 @@ pub struct ClientGroupAnchor {
     pub valid_to: Option<chrono::NaiveDate>,
 }
 
+/// Two anchor mappings that claim the same entity for different client groups
+///
+/// Reported by [`ClientGroupAnchorResolver::resolve_with_conflicts`] so an
+/// operator can reconcile the anchor table instead of one group silently winning.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnchorConflict {
+    /// The mapping that disagrees with `group_a`
+    pub anchor: ClientGroupAnchor,
+    pub group_a: Uuid,
+    pub group_b: Uuid,
+}
+
+/// Find every pair of anchor mappings that point the same entity at different groups
+///
+/// Pairs are reported in input order, once per (earlier, later) mapping.
+pub fn detect_anchor_conflicts(anchors: &[ClientGroupAnchor]) -> Vec<AnchorConflict> {
+    let mut conflicts = Vec::new();
+    for (i, earlier) in anchors.iter().enumerate() {
+        for later in &anchors[i + 1..] {
+            if earlier.anchor_entity_id == later.anchor_entity_id
+                && earlier.group_id != later.group_id
+            {
+                conflicts.push(AnchorConflict {
+                    anchor: later.clone(),
+                    group_a: earlier.group_id,
+                    group_b: later.group_id,
+                });
+            }
+        }
+    }
+    conflicts
+}
+
 /// Result of Stage 1 resolution (alias → group)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientGroupMatch {
@@ -216,6 +249,8 @@ pub struct ResolutionConfig {
     pub ambiguity_margin: f32,
     /// Maximum candidates to return on ambiguous match
     pub max_candidates: usize,
+    /// Drop ASCII punctuation during alias normalization ("A.G.I." → "agi")
+    pub strip_punctuation: bool,
 }
 
 impl Default for ResolutionConfig {
@@ -224,6 +259,7 @@ impl Default for ResolutionConfig {
             min_similarity: 0.75,
             ambiguity_margin: 0.10,
             max_candidates: 5,
+            strip_punctuation: true,
         }
     }
 }
@@ -235,6 +271,7 @@ impl ResolutionConfig {
             min_similarity: 0.85,
             ambiguity_margin: 0.15,
             max_candidates: 3,
+            strip_punctuation: true,
         }
     }
 
@@ -244,10 +281,40 @@ impl ResolutionConfig {
             min_similarity: 0.60,
             ambiguity_margin: 0.05,
             max_candidates: 10,
+            strip_punctuation: true,
         }
     }
 }
 
+/// Normalize an alias for exact matching: lowercase, collapse runs of
+/// ASCII whitespace to a single space, trim, and (if configured) drop ASCII
+/// punctuation.
+///
+/// Only used for comparison; the raw alias is kept for display. Stored
+/// aliases carry the same keys as generated columns (`alias_match_key`,
+/// `alias_match_key_bare`), so changes here need a matching migration.
+pub fn normalize_alias(alias: &str, config: &ResolutionConfig) -> String {
+    let lowered = alias.to_lowercase();
+    let kept: String = if config.strip_punctuation {
+        lowered
+            .chars()
+            .filter(|c| !c.is_ascii_punctuation())
+            .collect()
+    } else {
+        lowered
+    };
+    kept.split_ascii_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Stored column holding `normalize_alias` of each alias under `config`
+fn alias_match_column(config: &ResolutionConfig) -> &'static str {
+    if config.strip_punctuation {
+        "alias_match_key_bare"
+    } else {
+        "alias_match_key"
+    }
+}
+
 // ============================================================================
 // Traits
 // ============================================================================
@@ -271,9 +338,12 @@ pub trait ClientGroupAliasResolver: Send + Sync {
     ) -> Result<Vec<ClientGroupMatch>, ClientGroupResolveError>;
 
     /// Check if exact alias exists (fast path, no embedding)
+    ///
+    /// `alias` is compared after [`normalize_alias`] under `config`.
     async fn exact_match(
         &self,
-        alias_norm: &str,
+        alias: &str,
+        config: &ResolutionConfig,
     ) -> Result<Option<ClientGroupMatch>, ClientGroupResolveError>;
 }
 
@@ -294,6 +364,34 @@ pub trait ClientGroupAnchorResolver: Send + Sync {
         &self,
         group_id: Uuid,
     ) -> Result<Vec<AnchorResolution>, ClientGroupResolveError>;
+
+    /// Get every currently valid anchor mapping for an entity, with its group
+    async fn groups_for_anchor(
+        &self,
+        anchor_entity_id: Uuid,
+    ) -> Result<Vec<(ClientGroupAnchor, ClientGroup)>, ClientGroupResolveError>;
+
+    /// Reverse lookup: anchor entity → client group, surfacing conflicts.
+    ///
+    /// Returns the group only when every mapping for the entity agrees. If any
+    /// two mappings disagree, no group is chosen and all conflicting pairings
+    /// are returned instead.
+    async fn resolve_with_conflicts(
+        &self,
+        anchor_entity_id: Uuid,
+    ) -> Result<(Option<ClientGroup>, Vec<AnchorConflict>), ClientGroupResolveError> {
+        let mappings = self.groups_for_anchor(anchor_entity_id).await?;
+        let anchors: Vec<ClientGroupAnchor> = mappings.iter().map(|(a, _)| a.clone()).collect();
+        let conflicts = detect_anchor_conflicts(&anchors);
+
+        if !conflicts.is_empty() {
+            return Ok((None, conflicts));
+        }
+        Ok((
+            mappings.into_iter().next().map(|(_, group)| group),
+            conflicts,
+        ))
+    }
 }
 
 /// Combined two-stage resolver (convenience trait)
@@ -370,18 +468,23 @@ impl<E: Embedder> PgClientGroupResolver<E> {
 impl<E: Embedder + 'static> ClientGroupAliasResolver for PgClientGroupResolver<E> {
     async fn exact_match(
         &self,
-        alias_norm: &str,
+        alias: &str,
+        config: &ResolutionConfig,
     ) -> Result<Option<ClientGroupMatch>, ClientGroupResolveError> {
-        let row = sqlx::query_as::<_, (Uuid, String, String)>(
+        let alias_norm = normalize_alias(alias, config);
+        let row = sqlx::query_as::<_, (Uuid, String, String)>(&format!(
             r#"
             SELECT cg.id, cg.canonical_name, cga.alias
             FROM "ob-poc".client_group_alias cga
             JOIN "ob-poc".client_group cg ON cg.id = cga.group_id
             WHERE cga.alias_norm = $1
+               OR cga.{column} = $1
+            ORDER BY (cga.alias_norm = $1) DESC
             LIMIT 1
             "#,
-        )
-        .bind(alias_norm)
+            column = alias_match_column(config),
+        ))
+        .bind(&alias_norm)
         .fetch_optional(&self.pool)
         .await?;
 
@@ -447,10 +550,8 @@ impl<E: Embedder + 'static> ClientGroupAliasResolver for PgClientGroupResolver<E
         alias: &str,
         config: &ResolutionConfig,
     ) -> Result<ClientGroupMatch, ClientGroupResolveError> {
-        let alias_norm = alias.to_lowercase().trim().to_string();
-
         // Fast path: exact match (no embedding needed)
-        if let Some(m) = self.exact_match(&alias_norm).await? {
+        if let Some(m) = self.exact_match(alias, config).await? {
             return Ok(m);
         }
 
@@ -581,6 +682,50 @@ impl<E: Embedder + 'static> ClientGroupAnchorResolver for PgClientGroupResolver<
             })
             .collect())
     }
+
+    async fn groups_for_anchor(
+        &self,
+        anchor_entity_id: Uuid,
+    ) -> Result<Vec<(ClientGroupAnchor, ClientGroup)>, ClientGroupResolveError> {
+        let anchors = sqlx::query_as::<_, ClientGroupAnchor>(
+            r#"
+            SELECT id, group_id, anchor_entity_id, anchor_role,
+                   NULLIF(jurisdiction, '') AS jurisdiction,
+                   confidence::real AS confidence, priority, valid_from, valid_to
+            FROM "ob-poc".client_group_anchor
+            WHERE anchor_entity_id = $1
+              AND (valid_from IS NULL OR valid_from <= CURRENT_DATE)
+              AND (valid_to IS NULL OR valid_to >= CURRENT_DATE)
+            ORDER BY priority DESC, confidence DESC, group_id
+            "#,
+        )
+        .bind(anchor_entity_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let group_ids: Vec<Uuid> = anchors.iter().map(|a| a.group_id).collect();
+        let groups = sqlx::query_as::<_, ClientGroup>(
+            r#"
+            SELECT id, canonical_name, short_code, description
+            FROM "ob-poc".client_group
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(&group_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(anchors
+            .into_iter()
+            .filter_map(|anchor| {
+                groups
+                    .iter()
+                    .find(|g| g.id == anchor.group_id)
+                    .cloned()
+                    .map(|group| (anchor, group))
+            })
+            .collect())
+    }
 }
 
 // ============================================================================
@@ -798,7 +943,7 @@ pub async fn check_group_name_match(
     text: &str,
     pool: &PgPool,
 ) -> Result<Option<ClientGroup>, sqlx::Error> {
-    let text_norm = text.to_lowercase().trim().to_string();
+    let text_norm = normalize_alias(text, &ResolutionConfig::default());
 
     sqlx::query_as::<_, ClientGroup>(
         r#"
@@ -808,6 +953,7 @@ pub async fn check_group_name_match(
         WHERE LOWER(cg.canonical_name) = $1
            OR LOWER(cg.short_code) = $1
            OR cga.alias_norm = $1
+           OR cga.alias_match_key_bare = $1
         LIMIT 1
         "#,
     )
@@ -1137,6 +1283,97 @@ pub async fn get_all_entities_in_group(
 mod tests {
     use super::*;
 
+    /// In-memory anchor table for exercising the provided trait methods
+    struct StaticAnchors(Vec<(ClientGroupAnchor, ClientGroup)>);
+
+    #[async_trait]
+    impl ClientGroupAnchorResolver for StaticAnchors {
+        async fn resolve_anchor(
+            &self,
+            group_id: Uuid,
+            role: AnchorRole,
+            _jurisdiction: Option<&str>,
+        ) -> Result<AnchorResolution, ClientGroupResolveError> {
+            Err(ClientGroupResolveError::NoAnchor { group_id, role })
+        }
+
+        async fn list_anchors(
+            &self,
+            _group_id: Uuid,
+        ) -> Result<Vec<AnchorResolution>, ClientGroupResolveError> {
+            Ok(vec![])
+        }
+
+        async fn groups_for_anchor(
+            &self,
+            anchor_entity_id: Uuid,
+        ) -> Result<Vec<(ClientGroupAnchor, ClientGroup)>, ClientGroupResolveError> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|(a, _)| a.anchor_entity_id == anchor_entity_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn group(name: &str) -> ClientGroup {
+        ClientGroup {
+            id: Uuid::new_v4(),
+            canonical_name: name.to_string(),
+            short_code: None,
+            description: None,
+        }
+    }
+
+    fn anchor(group_id: Uuid, entity_id: Uuid) -> ClientGroupAnchor {
+        ClientGroupAnchor {
+            id: Uuid::new_v4(),
+            group_id,
+            anchor_entity_id: entity_id,
+            anchor_role: AnchorRole::GovernanceController.as_str().to_string(),
+            jurisdiction: None,
+            confidence: 1.0,
+            priority: 0,
+            valid_from: None,
+            valid_to: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_with_conflicts_reports_disagreeing_anchors() {
+        let entity = Uuid::new_v4();
+        let allianz = group("Allianz");
+        let aviva = group("Aviva");
+        let resolver = StaticAnchors(vec![
+            (anchor(allianz.id, entity), allianz.clone()),
+            (anchor(aviva.id, entity), aviva.clone()),
+        ]);
+
+        let (resolved, conflicts) = resolver.resolve_with_conflicts(entity).await.unwrap();
+
+        assert!(resolved.is_none(), "conflicting anchors must not resolve");
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].group_a, allianz.id);
+        assert_eq!(conflicts[0].group_b, aviva.id);
+        assert_eq!(conflicts[0].anchor.group_id, aviva.id);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_with_conflicts_agreeing_anchors() {
+        let entity = Uuid::new_v4();
+        let allianz = group("Allianz");
+        let resolver = StaticAnchors(vec![
+            (anchor(allianz.id, entity), allianz.clone()),
+            (anchor(allianz.id, entity), allianz.clone()),
+        ]);
+
+        let (resolved, conflicts) = resolver.resolve_with_conflicts(entity).await.unwrap();
+
+        assert_eq!(resolved.map(|g| g.id), Some(allianz.id));
+        assert!(conflicts.is_empty());
+    }
+
     #[test]
     fn test_anchor_role_roundtrip() {
         for role in [
@@ -1168,6 +1405,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_normalize_alias_variants_agree() {
+        let config = ResolutionConfig::default();
+        let canonical = normalize_alias("Apex Capital", &config);
+
+        assert_eq!(canonical, "apex capital");
+        assert_eq!(normalize_alias("APEX capital", &config), canonical);
+        assert_eq!(normalize_alias("  apex   capital ", &config), canonical);
+        assert_eq!(normalize_alias("apex\tcapital\n", &config), canonical);
+        assert_eq!(normalize_alias("Apex Capital.", &config), canonical);
+        assert_eq!(normalize_alias("Apex, Capital!", &config), canonical);
+        assert_ne!(normalize_alias("Apex Partners", &config), canonical);
+    }
+
+    #[test]
+    fn test_normalize_alias_keeps_punctuation_when_disabled() {
+        let config = ResolutionConfig {
+            strip_punctuation: false,
+            ..ResolutionConfig::default()
+        };
+        assert_eq!(normalize_alias(" A.G.I.  Group ", &config), "a.g.i. group");
+        assert_ne!(
+            normalize_alias("Apex Capital.", &config),
+            normalize_alias("Apex Capital", &config)
+        );
+    }
+
+    #[test]
+    fn test_alias_match_column_follows_punctuation_flag() {
+        let config = ResolutionConfig::default();
+        assert_eq!(alias_match_column(&config), "alias_match_key_bare");
+        let config = ResolutionConfig {
+            strip_punctuation: false,
+            ..config
+        };
+        assert_eq!(alias_match_column(&config), "alias_match_key");
+    }
+
     #[test]
     fn test_error_is_ambiguous() {
         let err = ClientGroupResolveError::NoMatch("test".into());
@@ -181,6 +181,19 @@ impl Embedder {
     }
 
     /// Internal: batch forward pass with CLS extraction
+    ///
+    /// All texts go through a single forward pass over a padded
+    /// `(batch_size, max_len)` tensor; the attention mask keeps padding
+    /// from affecting each row, so results match per-text embedding and
+    /// come back in input order.
+    ///
+    /// Batch-size tradeoff: activation memory grows with
+    /// `batch_size * max_len` (max_len is the longest text in the batch,
+    /// capped at 512 tokens), while per-call overhead shrinks. Callers
+    /// embedding thousands of phrases should chunk them (the
+    /// populate_embeddings binary uses batches of 128) rather
+    /// than pass everything at once, and avoid mixing very long and very
+    /// short texts in one batch since every row is padded to the longest.
     fn forward_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
         if texts.is_empty() {
             return Ok(vec![]);
@@ -381,6 +394,40 @@ mod tests {
         assert!(diff > 0.1, "Query and target embeddings should differ");
     }
 
+    #[test]
+    #[ignore] // Requires model download
+    fn test_batch_matches_single_embeddings() {
+        let embedder = Embedder::new().expect("Failed to load embedder");
+
+        // Deliberately different lengths so the batch needs padding
+        let texts = [
+            "load the cbu",
+            "show me who owns this fund and its ultimate beneficial owners",
+            "zoom",
+        ];
+
+        let batch = embedder.embed_batch_targets(&texts).unwrap();
+        assert_eq!(batch.len(), texts.len());
+
+        for (text, batched) in texts.iter().zip(&batch) {
+            let single = embedder.embed_target(text).unwrap();
+            assert_eq!(batched.len(), EMBEDDING_DIM);
+            let max_diff = single
+                .iter()
+                .zip(batched)
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0f32, f32::max);
+            assert!(
+                max_diff < 1e-4,
+                "batch embedding for '{}' diverged by {}",
+                text,
+                max_diff
+            );
+        }
+
+        assert!(embedder.embed_batch_targets(&[]).unwrap().is_empty());
+    }
+
     fn cosine_sim(a: &[f32], b: &[f32]) -> f32 {
         let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
         let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
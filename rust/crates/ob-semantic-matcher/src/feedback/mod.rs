@@ -3,6 +3,7 @@
 //! This module provides:
 //! - Capture of user interactions with intent matching
 //! - Outcome tracking (executed, corrected, abandoned)
+//! - Capture of accepted, edited and discarded DSL generations
 //! - Batch analysis for pattern discovery
 //! - Input sanitization for privacy
 //! - Automatic pattern learning from feedback
@@ -43,6 +44,6 @@ pub use repository::FeedbackRepository;
 pub use sanitize::sanitize_input;
 pub use service::FeedbackService;
 pub use types::{
-    Alternative, AnalysisResult, InputSource, IntentFeedback, MatchConfidence, Outcome,
-    OutcomeUpdate,
+    Alternative, AnalysisResult, CorrectedInstruction, GenerationFeedback, InputSource,
+    IntentFeedback, MatchConfidence, Outcome, OutcomeUpdate,
 };
@@ -6,6 +6,11 @@
 //! 3. Auto-promote qualified candidates
 //! 4. Queue borderline candidates for review
 //!
+//! [`PromotionService::dry_run`] previews a cycle without writing: it runs the
+//! same cycle as [`PromotionService::run_promotion_cycle`] inside a
+//! transaction and rolls it back, so its report is exactly what a real cycle
+//! would produce.
+//!
 //! ## Thresholds
 //!
 //! | Parameter | Value | Rationale |
@@ -16,7 +21,7 @@
 //! | `collision_threshold` | 0.92 | Prevent verb confusion |
 
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{Connection, PgConnection, PgPool};
 use tracing::{info, warn};
 
 use crate::Embedder;
@@ -70,12 +75,15 @@ pub struct PromotionReport {
     pub skipped: i32,
     pub collisions: i32,
     pub errors: i32,
+    /// True when produced by [`PromotionService::dry_run`]; nothing was written
+    pub dry_run: bool,
 }
 
 impl PromotionReport {
     pub fn summary(&self) -> String {
         format!(
-            "Promotion cycle: {} expired, {} promoted, {} skipped, {} collisions, {} errors",
+            "Promotion {}: {} expired, {} promoted, {} skipped, {} collisions, {} errors",
+            if self.dry_run { "dry run" } else { "cycle" },
             self.expired_outcomes,
             self.promoted.len(),
             self.skipped,
@@ -130,61 +138,89 @@ impl PromotionService {
 
     /// Run full promotion cycle
     pub async fn run_promotion_cycle(&self) -> Result<PromotionReport> {
+        let mut tx = self.pool.begin().await?;
+        let report = self.cycle(&mut tx).await?;
+        tx.commit().await?;
+        Ok(report)
+    }
+
+    /// Preview a promotion cycle without writing to the database
+    ///
+    /// Runs the cycle [`Self::run_promotion_cycle`] would run, then rolls it
+    /// back, so expired outcomes, collisions, promotions and skips are all
+    /// reported exactly as a real cycle would report them.
+    pub async fn dry_run(&self) -> Result<PromotionReport> {
+        let mut tx = self.pool.begin().await?;
+        let mut report = self.cycle(&mut tx).await?;
+        tx.rollback().await?;
+        report.dry_run = true;
+        info!("[dry run] {}", report.summary());
+        Ok(report)
+    }
+
+    /// One promotion cycle on `conn`; the caller commits or rolls back
+    ///
+    /// Each candidate runs in its own savepoint so a failed promotion is
+    /// counted as an error without aborting the rest of the cycle.
+    async fn cycle(&self, conn: &mut PgConnection) -> Result<PromotionReport> {
         // 1. Expire stale pending outcomes
-        let expired_outcomes = self.expire_pending_outcomes(30).await?;
+        let expired_outcomes = Self::expire_pending_outcomes(conn, 30).await?;
         if expired_outcomes > 0 {
             info!("Expired {} pending outcomes", expired_outcomes);
         }
 
         // 2. Get promotable candidates
-        let candidates = self.get_promotable_candidates().await?;
+        let candidates = self.promotable_candidates(conn).await?;
         if !candidates.is_empty() {
             info!("Found {} promotable candidates", candidates.len());
         }
 
         // 3. Run collision checks and promote
-        let mut promoted = Vec::new();
-        let mut skipped = 0;
-        let mut collisions = 0;
-        let mut errors = 0;
+        let mut report = PromotionReport {
+            expired_outcomes,
+            ..PromotionReport::default()
+        };
 
         for candidate in candidates {
-            match self.try_promote(&candidate).await {
+            let mut savepoint = conn.begin().await?;
+            let result = self.try_promote(&mut savepoint, &candidate).await;
+            match result {
                 Ok(PromoteResult::Promoted) => {
-                    promoted.push(candidate.phrase.clone());
+                    savepoint.commit().await?;
                     info!("Promoted: '{}' -> {}", candidate.phrase, candidate.verb);
+                    report.promoted.push(candidate.phrase);
                 }
                 Ok(PromoteResult::Collision(verb)) => {
-                    collisions += 1;
+                    savepoint.commit().await?;
+                    report.collisions += 1;
                     info!(
                         "Collision: '{}' conflicts with {} (target: {})",
                         candidate.phrase, verb, candidate.verb
                     );
                 }
                 Ok(PromoteResult::Skipped) => {
-                    skipped += 1;
+                    savepoint.commit().await?;
+                    report.skipped += 1;
                 }
                 Err(e) => {
+                    savepoint.rollback().await?;
                     warn!("Failed to promote '{}': {}", candidate.phrase, e);
-                    errors += 1;
+                    report.errors += 1;
                 }
             }
         }
 
-        Ok(PromotionReport {
-            expired_outcomes,
-            promoted,
-            skipped,
-            collisions,
-            errors,
-        })
+        Ok(report)
     }
 
     /// Expire pending outcomes older than N minutes
-    async fn expire_pending_outcomes(&self, older_than_minutes: i32) -> Result<i64> {
+    async fn expire_pending_outcomes(
+        conn: &mut PgConnection,
+        older_than_minutes: i32,
+    ) -> Result<i64> {
         let result: (i32,) = sqlx::query_as(r#"SELECT agent.expire_pending_outcomes($1)"#)
             .bind(older_than_minutes)
-            .fetch_one(&self.pool)
+            .fetch_one(conn)
             .await?;
 
         Ok(result.0 as i64)
@@ -192,6 +228,15 @@ impl PromotionService {
 
     /// Get candidates that meet promotion thresholds
     pub async fn get_promotable_candidates(&self) -> Result<Vec<PromotableCandidate>> {
+        let mut conn = self.pool.acquire().await?;
+        self.promotable_candidates(&mut conn).await
+    }
+
+    /// Candidates meeting the occurrence, success-rate and age thresholds
+    async fn promotable_candidates(
+        &self,
+        conn: &mut PgConnection,
+    ) -> Result<Vec<PromotableCandidate>> {
         let candidates: Vec<PromotableCandidate> = sqlx::query_as(
             r#"SELECT id, phrase, verb, occurrence_count, success_count, total_count,
                       success_rate, domain_hint
@@ -200,7 +245,7 @@ impl PromotionService {
         .bind(self.min_occurrences)
         .bind(self.min_success_rate)
         .bind(self.min_age_hours)
-        .fetch_all(&self.pool)
+        .fetch_all(conn)
         .await?;
 
         Ok(candidates)
@@ -228,10 +273,20 @@ impl PromotionService {
     }
 
     /// Try to promote a candidate
-    async fn try_promote(&self, candidate: &PromotableCandidate) -> Result<PromoteResult> {
-        // Collision check (semantic similarity to other verbs)
+    async fn try_promote(
+        &self,
+        conn: &mut PgConnection,
+        candidate: &PromotableCandidate,
+    ) -> Result<PromoteResult> {
+        // Collision check (semantic similarity to other verbs), in its own
+        // savepoint so a failed check doesn't abort the promotion
         if let Some(embedder) = &self.embedder {
-            match self.check_collision_safe(candidate, embedder).await {
+            let mut check = conn.begin().await?;
+            let collision = self
+                .check_collision_safe(&mut check, candidate, embedder)
+                .await;
+            check.rollback().await?;
+            match collision {
                 Ok(CollisionResult::Safe) => {}
                 Ok(CollisionResult::Collision(verb)) => {
                     // Mark as collision detected
@@ -244,7 +299,7 @@ impl PromotionService {
                     )
                     .bind(candidate.id)
                     .bind(&verb)
-                    .execute(&self.pool)
+                    .execute(&mut *conn)
                     .await?;
 
                     return Ok(PromoteResult::Collision(verb));
@@ -259,7 +314,7 @@ impl PromotionService {
         // Apply promotion
         let result: (bool,) = sqlx::query_as(r#"SELECT agent.apply_promotion($1, 'system_auto')"#)
             .bind(candidate.id)
-            .fetch_one(&self.pool)
+            .fetch_one(conn)
             .await?;
 
         if result.0 {
@@ -272,6 +327,7 @@ impl PromotionService {
     /// Check if a candidate is collision-safe (doesn't match another verb too closely)
     async fn check_collision_safe(
         &self,
+        conn: &mut PgConnection,
         candidate: &PromotableCandidate,
         embedder: &Embedder,
     ) -> Result<CollisionResult> {
@@ -294,7 +350,7 @@ impl PromotionService {
         .bind(embedding_vec)
         .bind(&candidate.verb)
         .bind(self.collision_threshold)
-        .fetch_optional(&self.pool)
+        .fetch_optional(conn)
         .await?;
 
         if let Some((colliding_verb, similarity)) = collision {
@@ -428,5 +484,25 @@ mod tests {
         assert!(summary.contains("1 promoted"));
         assert!(summary.contains("2 skipped"));
         assert!(summary.contains("1 collisions"));
+        assert!(summary.starts_with("Promotion cycle"));
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires database
+    async fn test_dry_run_matches_promotion_cycle() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let pool = PgPool::connect(&url).await.unwrap();
+        let service = PromotionService::new(pool);
+
+        let preview = service.dry_run().await.unwrap();
+        let actual = service.run_promotion_cycle().await.unwrap();
+
+        assert!(preview.dry_run);
+        assert!(!actual.dry_run);
+        assert_eq!(preview.expired_outcomes, actual.expired_outcomes);
+        assert_eq!(preview.promoted, actual.promoted);
+        assert_eq!(preview.skipped, actual.skipped);
+        assert_eq!(preview.collisions, actual.collisions);
+        assert_eq!(preview.errors, actual.errors);
     }
 }
@@ -81,6 +81,67 @@ impl FeedbackRepository {
         Ok(result.rows_affected() > 0)
     }
 
+    /// Record whether the user accepted, edited or discarded generated DSL
+    /// (append-only insert)
+    pub async fn record_generation_feedback(&self, feedback: &GenerationFeedback) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO "ob-poc".generation_feedback (
+                instruction, generated_dsl, accepted, edited_dsl, is_correction
+            ) VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(feedback.instruction.trim())
+        .bind(&feedback.generated_dsl)
+        .bind(feedback.accepted)
+        .bind(&feedback.edited_dsl)
+        .bind(feedback.is_correction())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Instructions with at least one corrected generation, most-corrected first
+    ///
+    /// Instructions are grouped case-insensitively and reported with their
+    /// first-seen spelling; ties are broken by instruction so the order is stable.
+    pub async fn most_corrected_generations(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<CorrectedInstruction>> {
+        let rows: Vec<(String, i64, i64, Option<String>)> = sqlx::query_as(
+            r#"
+            SELECT
+                (array_agg(instruction ORDER BY id))[1],
+                COUNT(*) FILTER (WHERE is_correction),
+                COUNT(*),
+                (array_agg(edited_dsl ORDER BY id DESC)
+                    FILTER (WHERE is_correction AND edited_dsl IS NOT NULL))[1]
+            FROM "ob-poc".generation_feedback
+            GROUP BY instruction_key
+            HAVING COUNT(*) FILTER (WHERE is_correction) > 0
+            ORDER BY 2 DESC, instruction_key
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(instruction, corrections, generations, latest_edit)| CorrectedInstruction {
+                    instruction,
+                    corrections,
+                    generations,
+                    latest_edit,
+                },
+            )
+            .collect())
+    }
+
     /// Mark stale pending interactions as abandoned
     /// Run periodically (e.g., every hour)
     pub async fn expire_pending(&self, older_than_minutes: i32) -> Result<u64> {
@@ -128,3 +189,51 @@ impl FeedbackRepository {
         Ok(result.rows_affected())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore] // Requires database
+    async fn test_most_corrected_surfaces_edited_generation() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let repository = FeedbackRepository::new(PgPool::connect(&url).await.unwrap());
+        let run = uuid::Uuid::new_v4();
+        let accepted = format!("Onboard Apex Fund {run}");
+        let corrected = format!("Add a USD SSI for Apex {run}");
+        let edit = r#"(ssi.create :cbu-id @cbu :currency "USD")"#;
+
+        for feedback in [
+            GenerationFeedback {
+                instruction: accepted.clone(),
+                generated_dsl: r#"(cbu.ensure :name "Apex" :jurisdiction "US")"#.into(),
+                accepted: true,
+                edited_dsl: None,
+            },
+            GenerationFeedback {
+                instruction: corrected.clone(),
+                generated_dsl: r#"(ssi.create :currency "USD")"#.into(),
+                accepted: true,
+                edited_dsl: Some(edit.into()),
+            },
+        ] {
+            repository
+                .record_generation_feedback(&feedback)
+                .await
+                .unwrap();
+        }
+
+        let ranked = repository
+            .most_corrected_generations(i64::MAX)
+            .await
+            .unwrap();
+        assert!(!ranked.iter().any(|c| c.instruction == accepted));
+        let entry = ranked
+            .iter()
+            .find(|c| c.instruction == corrected)
+            .expect("corrected instruction should be listed");
+        assert_eq!((entry.corrections, entry.generations), (1, 1));
+        assert_eq!(entry.latest_edit.as_deref(), Some(edit));
+    }
+}
@@ -221,6 +221,39 @@ impl FeedbackService {
         Ok(result.and_then(|r| r.0))
     }
 
+    /// Record whether the user accepted, edited or discarded generated DSL
+    ///
+    /// The instruction is sanitized like matcher input before it is stored.
+    pub async fn record_generation_feedback(
+        &self,
+        instruction: &str,
+        generated_dsl: &str,
+        accepted: bool,
+        edited_dsl: Option<String>,
+    ) -> Result<()> {
+        let known = self.known_entities.read().await;
+        let entity_refs: Vec<&str> = known.iter().map(|s| s.as_str()).collect();
+        let (sanitized_instruction, _) = sanitize_input(instruction, &entity_refs);
+        drop(known);
+
+        self.repository
+            .record_generation_feedback(&GenerationFeedback {
+                instruction: sanitized_instruction,
+                generated_dsl: generated_dsl.to_string(),
+                accepted,
+                edited_dsl,
+            })
+            .await
+    }
+
+    /// Instructions whose generated DSL users most often corrected
+    pub async fn most_corrected_generations(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<CorrectedInstruction>> {
+        self.repository.most_corrected_generations(limit).await
+    }
+
     /// Run analysis and get report
     pub async fn analyze(&self, days_back: i32) -> Result<AnalysisReport> {
         self.analyzer.run_full_analysis(days_back).await
@@ -124,6 +124,40 @@ pub struct OutcomeUpdate {
     pub user_edits: Option<serde_json::Value>,
 }
 
+/// What the user did with one generated DSL program
+#[derive(Debug, Clone)]
+pub struct GenerationFeedback {
+    /// The natural-language instruction the DSL was generated from
+    pub instruction: String,
+    pub generated_dsl: String,
+    /// Whether the user kept the generated DSL rather than discarding it
+    pub accepted: bool,
+    /// The user's corrected DSL, if they edited it
+    pub edited_dsl: Option<String>,
+}
+
+impl GenerationFeedback {
+    /// Discarded, or edited into something different
+    pub fn is_correction(&self) -> bool {
+        !self.accepted
+            || self
+                .edited_dsl
+                .as_deref()
+                .is_some_and(|e| e.trim() != self.generated_dsl.trim())
+    }
+}
+
+/// Correction count for one instruction, from
+/// [`FeedbackRepository::most_corrected_generations`](super::FeedbackRepository::most_corrected_generations)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorrectedInstruction {
+    pub instruction: String,
+    pub corrections: i64,
+    pub generations: i64,
+    /// Most recent user-edited DSL for this instruction
+    pub latest_edit: Option<String>,
+}
+
 /// Analysis result types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -157,3 +191,28 @@ pub enum AnalysisResult {
         occurrence_count: i64,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feedback(accepted: bool, edited: Option<&str>) -> GenerationFeedback {
+        GenerationFeedback {
+            instruction: "Add a USD SSI".into(),
+            generated_dsl: r#"(ssi.create :currency "USD")"#.into(),
+            accepted,
+            edited_dsl: edited.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_generation_correction() {
+        assert!(!feedback(true, None).is_correction());
+        // Accepting an unchanged "edit" is not a correction
+        assert!(!feedback(true, Some(" (ssi.create :currency \"USD\")\n")).is_correction());
+        assert!(
+            feedback(true, Some(r#"(ssi.create :cbu-id @cbu :currency "USD")"#)).is_correction()
+        );
+        assert!(feedback(false, None).is_correction());
+    }
+}
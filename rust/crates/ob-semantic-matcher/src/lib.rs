@@ -21,7 +21,7 @@
 //! │  → top-5 candidates with scores         │
 //! └─────────────────────────────────────────┘
 //!       │
-//!       ├─── High confidence (>0.85) ───► Return match
+//!       ├─── High confidence (>=0.85) ──► Return match (MatchTier::High)
 //!       │
 //!       ▼
 //! ┌─────────────────────────────────────────┐
@@ -41,16 +41,19 @@ pub mod centroid;
 pub mod client_group_resolver;
 pub mod embedder;
 pub mod feedback;
+pub mod matcher;
 pub mod phonetic;
 pub mod types;
 
 pub use client_group_resolver::{
-    AnchorRole, ClientGroup, ClientGroupAlias, ClientGroupAliasResolver, ClientGroupAnchor,
-    ClientGroupAnchorResolver, ClientGroupResolver, PgClientGroupResolver, ResolutionConfig,
+    AnchorConflict, AnchorRole, ClientGroup, ClientGroupAlias, ClientGroupAliasResolver,
+    ClientGroupAnchor, ClientGroupAnchorResolver, ClientGroupResolver, PgClientGroupResolver,
+    ResolutionConfig,
 };
 pub use embedder::Embedder;
+pub use matcher::SemanticMatcher;
 pub use phonetic::PhoneticMatcher;
-pub use types::{MatchMethod, MatchResult, MatcherConfig, MatcherError, VerbPattern};
+pub use types::{MatchMethod, MatchResult, MatchTier, MatcherConfig, MatcherError, VerbPattern};
 
 // Re-export key feedback types for convenience
 pub use feedback::{
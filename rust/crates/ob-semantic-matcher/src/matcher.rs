@@ -0,0 +1,204 @@
+//! In-memory semantic matching over pre-embedded verb patterns
+//!
+//! Scores a query embedding against each pattern's embedding by cosine
+//! similarity and tags every result with a [`MatchTier`](crate::MatchTier) derived from the
+//! matcher's [`MatcherConfig`] thresholds. Misheard queries can instead be
+//! matched on the patterns' Double Metaphone codes.
+
+use crate::centroid::cosine_similarity;
+use crate::phonetic::PhoneticMatcher;
+use crate::types::{MatchMethod, MatchResult, MatcherConfig, VerbPattern};
+
+/// Semantic matcher over a fixed set of verb patterns
+pub struct SemanticMatcher {
+    patterns: Vec<VerbPattern>,
+    config: MatcherConfig,
+    phonetic: PhoneticMatcher,
+}
+
+impl SemanticMatcher {
+    /// Create a matcher with the default thresholds
+    pub fn new(patterns: Vec<VerbPattern>) -> Self {
+        Self::new_with_config(patterns, MatcherConfig::default())
+    }
+
+    /// Create a matcher with explicit thresholds
+    pub fn new_with_config(patterns: Vec<VerbPattern>, config: MatcherConfig) -> Self {
+        Self {
+            patterns,
+            config,
+            phonetic: PhoneticMatcher::new(),
+        }
+    }
+
+    /// The thresholds this matcher tiers results with
+    pub fn config(&self) -> &MatcherConfig {
+        &self.config
+    }
+
+    /// Return up to `top_k` patterns at or above `min_similarity`, best first
+    ///
+    /// Every result carries its tier so the caller can auto-accept, prompt,
+    /// or fall back to phonetic matching.
+    pub fn match_embedding(&self, query: &[f32]) -> Vec<MatchResult> {
+        let mut results: Vec<MatchResult> = self
+            .patterns
+            .iter()
+            .map(|p| (p, cosine_similarity(query, &p.embedding)))
+            .filter(|(_, similarity)| *similarity >= self.config.min_similarity)
+            .map(|(p, similarity)| MatchResult {
+                verb_name: p.verb_name.clone(),
+                pattern_phrase: p.pattern_phrase.clone(),
+                similarity,
+                match_method: MatchMethod::Semantic,
+                category: p.category.clone(),
+                is_agent_bound: p.is_agent_bound,
+                tier: self.config.tier(similarity),
+                matched_code: None,
+            })
+            .collect();
+
+        self.rank(&mut results);
+        results
+    }
+
+    /// Return up to `top_k` patterns sharing phonetic codes with `query`, best first
+    ///
+    /// Similarity is the fraction of the query's codes found among the
+    /// pattern's `phonetic_codes`. Each result carries the first shared code
+    /// in `matched_code` so a false positive can be traced to its cause.
+    pub fn match_phonetic(&self, query: &str) -> Vec<MatchResult> {
+        let query_codes = self.phonetic.encode_phrase(query);
+        if query_codes.is_empty() {
+            return vec![];
+        }
+
+        let mut results: Vec<MatchResult> = self
+            .patterns
+            .iter()
+            .filter_map(|p| {
+                let mut shared = query_codes.iter().filter(|c| p.phonetic_codes.contains(c));
+                let matched_code = shared.next()?.clone();
+                let similarity = (shared.count() + 1) as f32 / query_codes.len() as f32;
+                Some((p, similarity, matched_code))
+            })
+            .filter(|(_, similarity, _)| *similarity >= self.config.min_similarity)
+            .map(|(p, similarity, matched_code)| MatchResult {
+                verb_name: p.verb_name.clone(),
+                pattern_phrase: p.pattern_phrase.clone(),
+                similarity,
+                match_method: MatchMethod::Phonetic,
+                category: p.category.clone(),
+                is_agent_bound: p.is_agent_bound,
+                tier: self.config.tier(similarity),
+                matched_code: Some(matched_code),
+            })
+            .collect();
+
+        self.rank(&mut results);
+        results
+    }
+
+    fn rank(&self, results: &mut Vec<MatchResult>) {
+        results.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        results.truncate(self.config.top_k);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MatchTier;
+    use uuid::Uuid;
+
+    /// Unit vector at `angle` radians from the x-axis, so its cosine
+    /// similarity with `[1, 0]` is exactly `angle.cos()`
+    fn at_angle(angle: f32) -> Vec<f32> {
+        vec![angle.cos(), angle.sin()]
+    }
+
+    fn pattern(verb: &str, similarity: f32) -> VerbPattern {
+        VerbPattern {
+            id: Uuid::new_v4(),
+            verb_name: verb.to_string(),
+            pattern_phrase: verb.to_string(),
+            pattern_normalized: verb.to_string(),
+            phonetic_codes: vec![],
+            // Nudge up by epsilon so acos/cos round-tripping can't drop us
+            // just under an inclusive boundary
+            embedding: at_angle((similarity + 1e-6_f32).min(1.0).acos()),
+            category: "test".to_string(),
+            is_agent_bound: false,
+            priority: 0,
+        }
+    }
+
+    #[test]
+    fn test_match_embedding_tiers_results() {
+        let patterns = vec![
+            pattern("high", 0.85),
+            pattern("medium", 0.70),
+            pattern("low", 0.50),
+            pattern("none", 0.30),
+        ];
+        let matcher = SemanticMatcher::new(patterns);
+
+        let results = matcher.match_embedding(&[1.0, 0.0]);
+        let tiers: Vec<_> = results
+            .iter()
+            .map(|r| (r.verb_name.as_str(), r.tier))
+            .collect();
+        assert_eq!(
+            tiers,
+            vec![
+                ("high", MatchTier::High),
+                ("medium", MatchTier::Medium),
+                ("low", MatchTier::Low),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_new_with_config_overrides_thresholds() {
+        let config = MatcherConfig {
+            min_similarity: 0.2,
+            low_confidence_threshold: 0.4,
+            high_confidence_threshold: 0.6,
+            top_k: 2,
+            ..MatcherConfig::default()
+        };
+        let matcher = SemanticMatcher::new_with_config(
+            vec![pattern("a", 0.65), pattern("b", 0.5), pattern("c", 0.3)],
+            config,
+        );
+
+        let results = matcher.match_embedding(&[1.0, 0.0]);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].tier, MatchTier::High);
+        assert_eq!(results[1].tier, MatchTier::Medium);
+    }
+
+    #[test]
+    fn test_match_phonetic_carries_matched_code() {
+        let phonetic = PhoneticMatcher::new();
+        let mut enhance = pattern("ui.enhance", 0.9);
+        enhance.phonetic_codes = phonetic.encode_phrase("enhance");
+        let mut zoom = pattern("ui.zoom-out", 0.0);
+        zoom.phonetic_codes = phonetic.encode_phrase("zoom out");
+        let matcher = SemanticMatcher::new(vec![enhance, zoom]);
+
+        let results = matcher.match_phonetic("enhawnce");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].verb_name, "ui.enhance");
+        assert_eq!(results[0].match_method, MatchMethod::Phonetic);
+        assert_eq!(
+            results[0].matched_code,
+            Some(phonetic.encode("enhance").0),
+            "primary code explains the match"
+        );
+
+        let semantic = matcher.match_embedding(&[1.0, 0.0]);
+        assert_eq!(semantic.len(), 1);
+        assert_eq!(semantic[0].matched_code, None);
+    }
+}
@@ -27,25 +27,36 @@ impl PhoneticMatcher {
         }
     }
 
-    /// Encode a word to its phonetic representation
+    /// Encode a word to its primary and secondary Double Metaphone codes
     ///
-    /// Returns both primary and alternate codes for better matching.
-    pub fn encode(&self, word: &str) -> Vec<String> {
+    /// The secondary code is `None` when the algorithm produces no distinct
+    /// alternate. An empty word encodes to an empty primary code.
+    pub fn encode(&self, word: &str) -> (String, Option<String>) {
         let word = word.trim().to_lowercase();
         if word.is_empty() {
-            return vec![];
+            return (String::new(), None);
         }
 
         let result = self.encoder.double_metaphone(&word);
         let primary = result.primary();
         let alternate = result.alternate();
 
-        let mut codes = vec![primary.to_string()];
-        if !alternate.is_empty() && alternate != primary {
-            codes.push(alternate.to_string());
-        }
+        let secondary = if !alternate.is_empty() && alternate != primary {
+            Some(alternate.to_string())
+        } else {
+            None
+        };
+
+        (primary.to_string(), secondary)
+    }
 
-        codes
+    /// All distinct codes for a word (primary first), empty for an empty word
+    fn codes(&self, word: &str) -> Vec<String> {
+        match self.encode(word) {
+            (primary, _) if primary.is_empty() => vec![],
+            (primary, Some(secondary)) => vec![primary, secondary],
+            (primary, None) => vec![primary],
+        }
     }
 
     /// Encode a phrase (multiple words) to phonetic codes
@@ -54,17 +65,38 @@ impl PhoneticMatcher {
     pub fn encode_phrase(&self, phrase: &str) -> Vec<String> {
         phrase
             .split_whitespace()
-            .flat_map(|word| self.encode(word))
+            .flat_map(|word| self.codes(word))
             .collect()
     }
 
     /// Check if two words match phonetically
     pub fn matches(&self, word1: &str, word2: &str) -> bool {
-        let codes1 = self.encode(word1);
-        let codes2 = self.encode(word2);
+        self.matched_code(word1, word2).is_some()
+    }
 
-        // Any overlap in codes means a phonetic match
-        codes1.iter().any(|c1| codes2.contains(c1))
+    /// The phonetic code two words share, if any
+    ///
+    /// This is the reason `matches` returned true, which is what you want
+    /// when chasing a false positive. Primary codes are preferred.
+    pub fn matched_code(&self, word1: &str, word2: &str) -> Option<String> {
+        let codes2 = self.codes(word2);
+        self.codes(word1).into_iter().find(|c| codes2.contains(c))
+    }
+
+    /// Edit distance between the closest phonetic codes of two words
+    ///
+    /// Compares every primary/secondary pairing and returns the smallest
+    /// Levenshtein distance, so 0 means the words match phonetically.
+    /// Returns `usize::MAX` if either word is empty.
+    pub fn distance(&self, word1: &str, word2: &str) -> usize {
+        let codes1 = self.codes(word1);
+        let codes2 = self.codes(word2);
+
+        codes1
+            .iter()
+            .flat_map(|c1| codes2.iter().map(move |c2| strsim::levenshtein(c1, c2)))
+            .min()
+            .unwrap_or(usize::MAX)
     }
 
     /// Calculate phonetic similarity between two phrases
@@ -94,15 +126,56 @@ mod tests {
         let matcher = PhoneticMatcher::new();
 
         // Basic encoding
-        let codes = matcher.encode("enhance");
-        assert!(!codes.is_empty());
+        let (primary, _) = matcher.encode("enhance");
+        assert!(!primary.is_empty());
 
         // Misspelling should match
-        let codes_misspelled = matcher.encode("enhawnce");
         assert!(
-            codes.iter().any(|c| codes_misspelled.contains(c)),
+            matcher.matched_code("enhance", "enhawnce").is_some(),
             "enhance and enhawnce should have overlapping codes"
         );
+
+        assert_eq!(matcher.encode("  "), (String::new(), None));
+    }
+
+    #[test]
+    fn test_known_code_pairs() {
+        let matcher = PhoneticMatcher::new();
+
+        // Classic Double Metaphone examples with distinct alternates
+        assert_eq!(
+            matcher.encode("Smith"),
+            ("SM0".to_string(), Some("XMT".to_string()))
+        );
+        assert_eq!(
+            matcher.encode("Schmidt"),
+            ("XMT".to_string(), Some("SMT".to_string()))
+        );
+
+        // They match through the shared code, which is reported
+        assert_eq!(
+            matcher.matched_code("smith", "schmidt"),
+            Some("XMT".to_string())
+        );
+    }
+
+    #[test]
+    fn test_homophones_share_primary_code() {
+        let matcher = PhoneticMatcher::new();
+
+        for (a, b) in [("knight", "night"), ("write", "right"), ("track", "trak")] {
+            assert_eq!(matcher.encode(a).0, matcher.encode(b).0, "{} vs {}", a, b);
+            assert_eq!(matcher.distance(a, b), 0);
+        }
+    }
+
+    #[test]
+    fn test_distance() {
+        let matcher = PhoneticMatcher::new();
+
+        assert_eq!(matcher.distance("smith", "schmidt"), 0);
+        assert!(matcher.distance("zoom", "enhance") > 0);
+        assert_eq!(matcher.distance("", "zoom"), usize::MAX);
     }
 
     #[test]
@@ -23,6 +23,32 @@ pub struct MatchResult {
 
     /// Whether this requires agent processing
     pub is_agent_bound: bool,
+
+    /// Confidence tier of `similarity` under the matcher's thresholds
+    #[serde(default)]
+    pub tier: MatchTier,
+
+    /// Phonetic code the query shared with the pattern (phonetic matches only)
+    ///
+    /// Shows why a phonetic match was chosen, e.g. "enhawnce" → "ANNS".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matched_code: Option<String>,
+}
+
+/// Confidence tier for a match, so callers can decide what to do with it
+///
+/// - `High`: auto-accept
+/// - `Medium`: ask the user to confirm
+/// - `Low`: weak signal; fall back to phonetic matching
+/// - `None`: below `min_similarity`, not a match
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchTier {
+    #[default]
+    None,
+    Low,
+    Medium,
+    High,
 }
 
 /// How the match was determined
@@ -75,6 +101,9 @@ pub struct MatcherConfig {
     /// Similarity threshold above which we skip phonetic fallback (default: 0.85)
     pub high_confidence_threshold: f32,
 
+    /// Similarity threshold separating `Medium` from `Low` tier (default: 0.7)
+    pub low_confidence_threshold: f32,
+
     /// Maximum number of candidates to retrieve from pgvector (default: 5)
     pub top_k: usize,
 
@@ -90,6 +119,7 @@ impl Default for MatcherConfig {
         Self {
             min_similarity: 0.5,
             high_confidence_threshold: 0.85,
+            low_confidence_threshold: 0.7,
             top_k: 5,
             use_cache: true,
             model_name: "sentence-transformers/all-MiniLM-L6-v2".to_string(),
@@ -97,6 +127,24 @@ impl Default for MatcherConfig {
     }
 }
 
+impl MatcherConfig {
+    /// Classify a similarity score into a confidence tier
+    ///
+    /// Each threshold is inclusive: a score equal to
+    /// `high_confidence_threshold` is `High`.
+    pub fn tier(&self, similarity: f32) -> MatchTier {
+        if similarity >= self.high_confidence_threshold {
+            MatchTier::High
+        } else if similarity >= self.low_confidence_threshold {
+            MatchTier::Medium
+        } else if similarity >= self.min_similarity {
+            MatchTier::Low
+        } else {
+            MatchTier::None
+        }
+    }
+}
+
 /// Error types for semantic matching
 #[derive(Debug, thiserror::Error)]
 pub enum MatcherError {
@@ -115,3 +163,55 @@ pub enum MatcherError {
     #[error("No match found for input")]
     NoMatch,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::centroid::cosine_similarity;
+
+    /// Unit vector at `angle` radians from the x-axis, so its cosine
+    /// similarity with `[1, 0]` is exactly `angle.cos()`
+    fn at_angle(angle: f32) -> Vec<f32> {
+        vec![angle.cos(), angle.sin()]
+    }
+
+    #[test]
+    fn test_tier_boundaries_from_embeddings() {
+        let config = MatcherConfig::default();
+        let query = vec![1.0, 0.0];
+
+        let cases = [
+            (0.95, MatchTier::High),
+            (0.85, MatchTier::High),
+            (0.80, MatchTier::Medium),
+            (0.70, MatchTier::Medium),
+            (0.60, MatchTier::Low),
+            (0.50, MatchTier::Low),
+            (0.30, MatchTier::None),
+        ];
+        for (similarity, expected) in cases {
+            // Nudge up by epsilon so acos/cos round-tripping can't drop us
+            // just under an inclusive boundary
+            let candidate = at_angle((similarity + 1e-6_f32).min(1.0).acos());
+            let actual = cosine_similarity(&query, &candidate);
+            assert!((actual - similarity).abs() < 1e-4);
+            assert_eq!(config.tier(actual), expected, "similarity {}", similarity);
+        }
+    }
+
+    #[test]
+    fn test_custom_thresholds() {
+        let config = MatcherConfig {
+            min_similarity: 0.2,
+            low_confidence_threshold: 0.4,
+            high_confidence_threshold: 0.6,
+            ..MatcherConfig::default()
+        };
+
+        assert_eq!(config.tier(0.65), MatchTier::High);
+        assert_eq!(config.tier(0.5), MatchTier::Medium);
+        assert_eq!(config.tier(0.3), MatchTier::Low);
+        assert_eq!(config.tier(0.1), MatchTier::None);
+        assert!(MatchTier::High > MatchTier::Medium);
+    }
+}
@@ -8,6 +8,9 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::error::TemplateError;
 
 /// Primary entity type that defines the root scope for a template
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -200,6 +203,10 @@ pub struct ParamDefinition {
     #[serde(default)]
     pub enum_values: Option<Vec<String>>,
 
+    /// Maximum length (in characters) for string params
+    #[serde(default)]
+    pub max_length: Option<usize>,
+
     // =========================================================================
     // Entity Reference Fields (for param_type = "entity_ref")
     // These define the schema for LookupRef triplet expansion
@@ -228,6 +235,59 @@ pub struct ParamDefinition {
     pub role_hint: Option<String>,
 }
 
+impl ParamDefinition {
+    /// Check that a provided value is acceptable for this parameter
+    ///
+    /// - required params must not be blank
+    /// - `cbu_ref` values must parse as UUIDs
+    /// - `string` values must respect `max_length`
+    ///
+    /// Errors name the parameter by its type; [`TemplateExpander::expand`]
+    /// reports the actual parameter name.
+    ///
+    /// [`TemplateExpander::expand`]: crate::TemplateExpander::expand
+    pub fn validate_value(&self, value: &str) -> Result<(), TemplateError> {
+        self.validate_named(&self.param_type, value)
+    }
+
+    /// [`Self::validate_value`] with errors attributed to `name`
+    pub(crate) fn validate_named(&self, name: &str, value: &str) -> Result<(), TemplateError> {
+        if value.trim().is_empty() {
+            return if self.required {
+                Err(TemplateError::MissingParam(name.to_string()))
+            } else {
+                Ok(())
+            };
+        }
+
+        let invalid = |message: String| TemplateError::InvalidParam {
+            param: name.to_string(),
+            message,
+        };
+
+        match self.param_type.as_str() {
+            "cbu_ref" => {
+                Uuid::parse_str(value.trim())
+                    .map_err(|_| invalid(format!("expected a CBU UUID, got '{}'", value)))?;
+            }
+            "string" => {
+                if let Some(max) = self.max_length {
+                    let len = value.chars().count();
+                    if len > max {
+                        return Err(invalid(format!(
+                            "{} characters exceeds max length {}",
+                            len, max
+                        )));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
 /// Output definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputDefinition {
@@ -244,7 +304,20 @@ pub struct OutputDefinition {
     pub binding: Option<String>,
 }
 
+/// Parse an `include: <template>` body line, returning the template ID
+pub(crate) fn include_target(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_prefix("include:")
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+}
+
 impl TemplateDefinition {
+    /// Template IDs pulled in by `include: <template>` lines in the body
+    pub fn includes(&self) -> Vec<&str> {
+        self.body.lines().filter_map(include_target).collect()
+    }
+
     /// Get list of required parameters
     pub fn required_params(&self) -> Vec<(&String, &ParamDefinition)> {
         self.params.iter().filter(|(_, p)| p.required).collect()
@@ -749,4 +822,32 @@ outputs:
         assert_eq!(shared.entity_type, "limited_company");
         assert_eq!(shared.role_hint.as_deref(), Some("MANAGEMENT_COMPANY"));
     }
+
+    fn param(yaml: &str) -> ParamDefinition {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_validate_value_cbu_ref() {
+        let cbu = param("type: cbu_ref\nrequired: true");
+        assert!(cbu
+            .validate_value(&uuid::Uuid::new_v4().to_string())
+            .is_ok());
+        assert!(matches!(
+            cbu.validate_value("cbu-123"),
+            Err(TemplateError::InvalidParam { .. })
+        ));
+        assert!(matches!(
+            cbu.validate_value(""),
+            Err(TemplateError::MissingParam(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_value_string_max_length() {
+        let name = param("type: string\nmax_length: 5");
+        assert!(name.validate_value("Alice").is_ok());
+        assert!(name.validate_value("").is_ok()); // optional
+        assert!(name.validate_value("Alicia").is_err());
+    }
 }
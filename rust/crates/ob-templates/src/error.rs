@@ -20,6 +20,23 @@ pub enum TemplateError {
     #[error("Invalid parameter value for '{param}': {message}")]
     InvalidParam { param: String, message: String },
 
+    #[error("Invalid parameters: {}", join_errors(.0))]
+    InvalidParams(Vec<TemplateError>),
+
+    #[error("Template include cycle: {}", .0.join(" -> "))]
+    IncludeCycle(Vec<String>),
+
+    #[error("Unbalanced template block: {0}")]
+    UnbalancedBlock(String),
+
     #[error("Template expansion error: {0}")]
     Expansion(String),
 }
+
+fn join_errors(errors: &[TemplateError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -1,11 +1,22 @@
 //! Template Expander
 //!
 //! Expands template definitions to DSL source text by substituting parameters.
+//!
+//! Bodies may contain conditional blocks, rendered before substitution:
+//!
+//! ```text
+//! {{#if fatca_required}}(kyc.add-fatca-check :case "$case_id"){{/if}}
+//! {{#unless has_lei}}(entity.request-lei :entity "$entity_id"){{/unless}}
+//! ```
+//!
+//! A param is truthy when it resolves to a non-empty value other than `false`;
+//! absent params are falsy. Blocks may nest.
 
 use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::definition::{ParamDefinition, TemplateDefinition};
+use super::error::TemplateError;
 
 /// Session context for template expansion
 ///
@@ -145,6 +156,28 @@ pub struct MissingParam {
     pub required: bool,
     /// Validation hint
     pub validation: Option<String>,
+    /// Declared `source: session`, so it fills itself once the session has a value
+    pub from_session: bool,
+}
+
+impl ExpansionResult {
+    /// User-facing questions for the missing params, in order
+    ///
+    /// Each is the param's `prompt` phrased as a question, e.g.
+    /// "Director's full legal name?".
+    pub fn prompts(&self) -> Vec<String> {
+        self.missing_params
+            .iter()
+            .map(|param| {
+                let prompt = param.prompt.trim().trim_end_matches(':');
+                if prompt.ends_with(['?', '.', '!']) {
+                    prompt.to_string()
+                } else {
+                    format!("{}?", prompt)
+                }
+            })
+            .collect()
+    }
 }
 
 /// Expands templates to DSL source text
@@ -158,12 +191,16 @@ impl TemplateExpander {
     /// 2. Session context (current_cbu, current_case, bindings)
     /// 3. Default values from param definition
     /// 4. Leave as placeholder if still unknown (and track as missing)
+    ///
+    /// Returns [`TemplateError::InvalidParams`] if any resolved value fails
+    /// [`ParamDefinition::validate_value`], or [`TemplateError::UnbalancedBlock`]
+    /// if the body's conditional blocks are malformed.
     pub fn expand(
         template: &TemplateDefinition,
         explicit_params: &HashMap<String, String>,
         context: &ExpansionContext,
-    ) -> ExpansionResult {
-        let mut dsl = template.body.clone();
+    ) -> Result<ExpansionResult, TemplateError> {
+        let mut values: HashMap<&str, String> = HashMap::new();
         let mut filled = Vec::new();
         let mut missing = Vec::new();
 
@@ -172,8 +209,7 @@ impl TemplateExpander {
 
             match value {
                 Some(v) => {
-                    // Substitute $param with value
-                    dsl = Self::substitute_param(&dsl, name, &v);
+                    values.insert(name, v);
                     filled.push(name.clone());
                 }
                 None if param_def.required => {
@@ -188,13 +224,13 @@ impl TemplateExpander {
                         example: param_def.example.clone(),
                         required: true,
                         validation: param_def.validation.clone(),
+                        from_session: param_def.source.as_deref() == Some("session"),
                     });
                 }
                 None => {
                     // Optional and missing - try default
                     if let Some(default) = &param_def.default {
-                        let resolved_default = Self::resolve_default(default, explicit_params);
-                        dsl = Self::substitute_param(&dsl, name, &resolved_default);
+                        values.insert(name, Self::resolve_default(default, explicit_params));
                         filled.push(name.clone());
                     }
                     // If no default, leave placeholder or empty
@@ -202,19 +238,134 @@ impl TemplateExpander {
             }
         }
 
+        // Validate every provided value, reporting all problems at once
+        let mut invalid: Vec<(&str, TemplateError)> = values
+            .iter()
+            .filter_map(|(name, v)| {
+                template.params[*name]
+                    .validate_named(name, v)
+                    .err()
+                    .map(|e| (*name, e))
+            })
+            .collect();
+        if !invalid.is_empty() {
+            invalid.sort_by_key(|(name, _)| *name);
+            return Err(TemplateError::InvalidParams(
+                invalid.into_iter().map(|(_, e)| e).collect(),
+            ));
+        }
+
+        // Conditional blocks first, so substitution only touches kept text
+        let mut dsl = Self::render_conditionals(&template.body, |name| {
+            values
+                .get(name)
+                .or_else(|| explicit_params.get(name))
+                .or_else(|| context.bindings.get(name))
+                .is_some_and(|v| Self::is_truthy(v))
+        })?;
+
+        for name in template.params.keys() {
+            if let Some(v) = values.get(name.as_str()) {
+                // Substitute $param with value
+                dsl = Self::substitute_param(&dsl, name, v);
+            }
+        }
+
         // Final pass: substitute any remaining dotted property access patterns
         // like $fund_entity.name that were passed as explicit params
         dsl = Self::substitute_all_params(&dsl, explicit_params);
 
         let outputs = template.outputs.keys().cloned().collect();
 
-        ExpansionResult {
+        Ok(ExpansionResult {
             dsl,
             filled_params: filled,
             missing_params: missing,
             outputs,
             template_id: template.template.clone(),
+        })
+    }
+
+    /// Truthiness for conditional blocks: non-empty and not a `false` bool
+    fn is_truthy(value: &str) -> bool {
+        let value = value.trim();
+        !value.is_empty() && !value.eq_ignore_ascii_case("false")
+    }
+
+    /// Render `{{#if name}}` / `{{#unless name}}` blocks
+    ///
+    /// Any other `{{...}}` text is passed through untouched.
+    fn render_conditionals(
+        body: &str,
+        is_truthy: impl Fn(&str) -> bool,
+    ) -> Result<String, TemplateError> {
+        // (block kind, param, whether this block's contents are emitted)
+        let mut stack: Vec<(&str, &str, bool)> = Vec::new();
+        let mut out = String::with_capacity(body.len());
+        let mut rest = body;
+
+        while let Some(start) = rest.find("{{") {
+            let emitting = stack.iter().all(|(_, _, keep)| *keep);
+            if emitting {
+                out.push_str(&rest[..start]);
+            }
+            let tag_src = &rest[start..];
+
+            let is_block_tag = tag_src[2..].starts_with('#') || tag_src[2..].starts_with('/');
+            let Some(end) = tag_src.find("}}").filter(|_| is_block_tag) else {
+                if emitting {
+                    out.push_str("{{");
+                }
+                rest = &tag_src[2..];
+                continue;
+            };
+            let tag = tag_src[2..end].trim();
+            rest = &tag_src[end + 2..];
+
+            if let Some(open) = tag.strip_prefix('#') {
+                let (kind, param) = open.split_once(char::is_whitespace).ok_or_else(|| {
+                    TemplateError::UnbalancedBlock(format!("'{{{{{}}}}}' has no param", tag))
+                })?;
+                let param = param.trim();
+                let keep = match kind {
+                    "if" => is_truthy(param),
+                    "unless" => !is_truthy(param),
+                    _ => {
+                        return Err(TemplateError::UnbalancedBlock(format!(
+                            "unknown block '{{{{#{}}}}}'",
+                            kind
+                        )))
+                    }
+                };
+                stack.push((kind, param, keep));
+            } else if let Some(close) = tag.strip_prefix('/') {
+                let close = close.trim();
+                match stack.pop() {
+                    Some((kind, _, _)) if kind == close => {}
+                    Some((kind, param, _)) => {
+                        return Err(TemplateError::UnbalancedBlock(format!(
+                            "'{{{{/{}}}}}' closes '{{{{#{} {}}}}}'",
+                            close, kind, param
+                        )))
+                    }
+                    None => {
+                        return Err(TemplateError::UnbalancedBlock(format!(
+                            "'{{{{/{}}}}}' without a matching open block",
+                            close
+                        )))
+                    }
+                }
+            }
         }
+
+        if let Some((kind, param, _)) = stack.pop() {
+            return Err(TemplateError::UnbalancedBlock(format!(
+                "'{{{{#{} {}}}}}' is never closed",
+                kind, param
+            )));
+        }
+        out.push_str(rest);
+        Ok(out)
     }
 
     /// Substitute a parameter in the DSL text
@@ -424,7 +575,7 @@ body: |
 
         let context = ExpansionContext::with_cbu(Uuid::new_v4());
 
-        let result = TemplateExpander::expand(&template, &params, &context);
+        let result = TemplateExpander::expand(&template, &params, &context).unwrap();
 
         assert!(result.missing_params.is_empty());
         assert!(result.dsl.contains("Alice"));
@@ -437,7 +588,7 @@ body: |
         let params = HashMap::new();
         let context = ExpansionContext::new(); // No CBU
 
-        let result = TemplateExpander::expand(&template, &params, &context);
+        let result = TemplateExpander::expand(&template, &params, &context).unwrap();
 
         // Should have 2 missing: cbu_id (no session) and name (required, no value)
         assert_eq!(result.missing_params.len(), 2);
@@ -454,12 +605,100 @@ body: |
         let cbu_id = Uuid::new_v4();
         let context = ExpansionContext::with_cbu(cbu_id);
 
-        let result = TemplateExpander::expand(&template, &params, &context);
+        let result = TemplateExpander::expand(&template, &params, &context).unwrap();
 
         assert!(result.missing_params.is_empty());
         assert!(result.dsl.contains(&cbu_id.to_string()));
     }
 
+    #[test]
+    fn test_expand_rejects_malformed_cbu_ref() {
+        let template = sample_template();
+        let mut params = HashMap::new();
+        params.insert("cbu_id".to_string(), "not-a-uuid".to_string());
+        params.insert("name".to_string(), "Alice".to_string());
+
+        let err =
+            TemplateExpander::expand(&template, &params, &ExpansionContext::new()).unwrap_err();
+
+        let TemplateError::InvalidParams(errors) = &err else {
+            panic!("expected InvalidParams, got {err:?}");
+        };
+        assert_eq!(errors.len(), 1);
+        assert!(
+            matches!(&errors[0], TemplateError::InvalidParam { param, .. } if param == "cbu_id")
+        );
+    }
+
+    #[test]
+    fn test_expand_aggregates_param_errors() {
+        let template = sample_template();
+        let mut params = HashMap::new();
+        params.insert("cbu_id".to_string(), "12345".to_string());
+        params.insert("name".to_string(), "   ".to_string());
+
+        let err =
+            TemplateExpander::expand(&template, &params, &ExpansionContext::new()).unwrap_err();
+
+        let TemplateError::InvalidParams(errors) = &err else {
+            panic!("expected InvalidParams, got {err:?}");
+        };
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(&errors[1], TemplateError::MissingParam(name) if name == "name"));
+        assert!(err.to_string().contains("Missing required parameter: name"));
+    }
+
+    #[test]
+    fn test_missing_param_prompts_flow_from_definition() {
+        let template: TemplateDefinition = serde_yaml::from_str(
+            r#"
+template: onboard-director
+version: 1
+metadata:
+  name: Onboard Director
+  summary: Add a director
+params:
+  cbu_id:
+    type: cbu_ref
+    required: true
+    source: session
+    prompt: "Which CBU"
+  name:
+    type: string
+    required: true
+    prompt: "Director's full legal name"
+body: |
+  (entity.create :cbu "$cbu_id" :name "$name")
+"#,
+        )
+        .unwrap();
+
+        let result =
+            TemplateExpander::expand(&template, &HashMap::new(), &ExpansionContext::new()).unwrap();
+
+        let name = result
+            .missing_params
+            .iter()
+            .find(|p| p.name == "name")
+            .unwrap();
+        assert_eq!(name.prompt, "Director's full legal name");
+        assert_eq!(name.param_type, "string");
+        assert!(!name.from_session);
+
+        let cbu = result
+            .missing_params
+            .iter()
+            .find(|p| p.name == "cbu_id")
+            .unwrap();
+        assert_eq!(cbu.param_type, "cbu_ref");
+        assert!(cbu.from_session);
+
+        let prompts = result.prompts();
+        assert_eq!(prompts.len(), 2);
+        assert!(prompts.contains(&"Director's full legal name?".to_string()));
+        assert!(prompts.contains(&"Which CBU?".to_string()));
+    }
+
     #[test]
     fn test_format_missing_params() {
         let missing = vec![
@@ -470,6 +709,7 @@ body: |
                 example: Some("John".to_string()),
                 required: true,
                 validation: None,
+                from_session: false,
             },
             MissingParam {
                 name: "date".to_string(),
@@ -478,6 +718,7 @@ body: |
                 example: None,
                 required: true,
                 validation: None,
+                from_session: false,
             },
         ];
 
@@ -502,4 +743,81 @@ body: |
         assert!(!ctx.binding_matches_type("company", "cbu"));
         assert!(!ctx.binding_matches_type("nonexistent", "entity"));
     }
+
+    fn conditional_template() -> TemplateDefinition {
+        serde_yaml::from_str(
+            r#"
+template: test-conditional
+version: 1
+metadata:
+  name: Test Conditional
+  summary: Test conditional blocks
+params:
+  jurisdiction:
+    type: string
+    required: true
+  us_person:
+    type: string
+    required: false
+body: |
+  (entity.create :jurisdiction "$jurisdiction")
+  {{#if us_person}}(kyc.add-fatca-check :jurisdiction "$jurisdiction"){{/if}}
+  {{#unless us_person}}(kyc.add-crs-check){{/unless}}
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_conditional_branch_taken() {
+        let template = conditional_template();
+        let mut params = HashMap::new();
+        params.insert("jurisdiction".to_string(), "US".to_string());
+        params.insert("us_person".to_string(), "true".to_string());
+
+        let result =
+            TemplateExpander::expand(&template, &params, &ExpansionContext::new()).unwrap();
+
+        assert!(result
+            .dsl
+            .contains("(kyc.add-fatca-check :jurisdiction \"US\")"));
+        assert!(!result.dsl.contains("add-crs-check"));
+        assert!(!result.dsl.contains("{{"));
+    }
+
+    #[test]
+    fn test_conditional_branch_skipped() {
+        let template = conditional_template();
+        let mut params = HashMap::new();
+        params.insert("jurisdiction".to_string(), "LU".to_string());
+        params.insert("us_person".to_string(), "false".to_string());
+
+        let result =
+            TemplateExpander::expand(&template, &params, &ExpansionContext::new()).unwrap();
+        assert!(!result.dsl.contains("add-fatca-check"));
+        assert!(result.dsl.contains("(kyc.add-crs-check)"));
+
+        // Absent param is falsy too
+        params.remove("us_person");
+        let result =
+            TemplateExpander::expand(&template, &params, &ExpansionContext::new()).unwrap();
+        assert!(!result.dsl.contains("add-fatca-check"));
+        assert!(result.dsl.contains("(kyc.add-crs-check)"));
+    }
+
+    #[test]
+    fn test_conditional_unclosed_block_is_error() {
+        let mut template = conditional_template();
+        template.body = "{{#if us_person}}(kyc.add-fatca-check)".to_string();
+
+        let err = TemplateExpander::expand(&template, &HashMap::new(), &ExpansionContext::new())
+            .unwrap_err();
+        assert!(matches!(err, TemplateError::UnbalancedBlock(_)));
+        assert!(err.to_string().contains("never closed"));
+
+        template.body = "(a){{/if}}".to_string();
+        let err = TemplateExpander::expand(&template, &HashMap::new(), &ExpansionContext::new())
+            .unwrap_err();
+        assert!(matches!(err, TemplateError::UnbalancedBlock(_)));
+    }
 }
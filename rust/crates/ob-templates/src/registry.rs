@@ -1,12 +1,16 @@
 //! Template Registry
 //!
 //! Loads, indexes, and provides access to template definitions.
+//!
+//! The registry also resolves template composition: a body line of the form
+//! `include: kyc-setup` is replaced by the expanded body of that template.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::Path;
 
-use super::definition::TemplateDefinition;
+use super::definition::{include_target, TemplateDefinition};
 use super::error::TemplateError;
+use super::expander::{ExpansionContext, ExpansionResult, TemplateExpander};
 
 /// Registry of all loaded templates with multiple indexes
 pub struct TemplateRegistry {
@@ -108,6 +112,173 @@ impl TemplateRegistry {
         self.templates.get(id)
     }
 
+    /// Expand a template, splicing in any `include:`d templates
+    ///
+    /// Included templates see the caller's explicit params and
+    /// [`ExpansionContext`]; their missing params, filled params and outputs
+    /// are merged into the returned result. Include cycles are rejected with
+    /// [`TemplateError::IncludeCycle`].
+    pub fn expand(
+        &self,
+        id: &str,
+        explicit_params: &HashMap<String, String>,
+        context: &ExpansionContext,
+    ) -> Result<ExpansionResult, TemplateError> {
+        let template = self
+            .get(id)
+            .ok_or_else(|| TemplateError::NotFound(id.to_string()))?;
+        self.expand_template(template, explicit_params, context)
+    }
+
+    /// Expand a template the caller already holds, resolving its includes
+    /// against this registry
+    ///
+    /// Use this instead of [`TemplateExpander::expand`] whenever the template
+    /// may contain `include:` lines.
+    pub fn expand_template(
+        &self,
+        template: &TemplateDefinition,
+        explicit_params: &HashMap<String, String>,
+        context: &ExpansionContext,
+    ) -> Result<ExpansionResult, TemplateError> {
+        self.expand_included(template, explicit_params, context, &mut Vec::new())
+    }
+
+    fn expand_included(
+        &self,
+        template: &TemplateDefinition,
+        explicit_params: &HashMap<String, String>,
+        context: &ExpansionContext,
+        stack: &mut Vec<String>,
+    ) -> Result<ExpansionResult, TemplateError> {
+        let id = template.template.as_str();
+        if let Some(pos) = stack.iter().position(|s| s == id) {
+            let mut cycle = stack[pos..].to_vec();
+            cycle.push(id.to_string());
+            return Err(TemplateError::IncludeCycle(cycle));
+        }
+
+        let mut result = TemplateExpander::expand(template, explicit_params, context)?;
+
+        stack.push(id.to_string());
+        let mut dsl = String::with_capacity(result.dsl.len());
+        for line in result.dsl.split_inclusive('\n') {
+            let Some(name) = include_target(line) else {
+                dsl.push_str(line);
+                continue;
+            };
+
+            let included_template = self
+                .get(name)
+                .ok_or_else(|| TemplateError::NotFound(name.to_string()))?;
+            let included =
+                self.expand_included(included_template, explicit_params, context, stack)?;
+            let indent = &line[..line.len() - line.trim_start().len()];
+            for included_line in included.dsl.trim_end().lines() {
+                dsl.push_str(indent);
+                dsl.push_str(included_line);
+                dsl.push('\n');
+            }
+
+            for missing in included.missing_params {
+                if !result.missing_params.iter().any(|m| m.name == missing.name) {
+                    result.missing_params.push(missing);
+                }
+            }
+            for filled in included.filled_params {
+                if !result.filled_params.contains(&filled) {
+                    result.filled_params.push(filled);
+                }
+            }
+            result.outputs.extend(included.outputs);
+        }
+        stack.pop();
+
+        result.dsl = dsl;
+        Ok(result)
+    }
+
+    /// Templates that include `id`, directly or transitively, sorted by ID
+    ///
+    /// Use this to find everything affected by an edit to a shared template.
+    pub fn dependents_of(&self, id: &str) -> Vec<&str> {
+        let mut found: BTreeSet<&str> = BTreeSet::new();
+        let mut frontier = vec![id];
+
+        while let Some(current) = frontier.pop() {
+            for (includer, template) in &self.templates {
+                if template.includes().contains(&current) && found.insert(includer.as_str()) {
+                    frontier.push(includer.as_str());
+                }
+            }
+        }
+
+        found.remove(id);
+        found.into_iter().collect()
+    }
+
+    /// All template IDs ordered so every template follows the ones it includes
+    ///
+    /// Ties are broken by ID so the order is stable. Includes of templates
+    /// that are not registered are ignored. Returns
+    /// [`TemplateError::IncludeCycle`] if the include graph is not a DAG.
+    pub fn topological_order(&self) -> Result<Vec<&str>, TemplateError> {
+        // template → registered templates it includes
+        let mut pending: BTreeMap<&str, BTreeSet<&str>> = self
+            .templates
+            .iter()
+            .map(|(id, template)| {
+                let deps = template
+                    .includes()
+                    .into_iter()
+                    .filter(|dep| self.templates.contains_key(*dep))
+                    .collect();
+                (id.as_str(), deps)
+            })
+            .collect();
+
+        let mut order = Vec::with_capacity(pending.len());
+        while !pending.is_empty() {
+            let ready: Vec<&str> = pending
+                .iter()
+                .filter(|(_, deps)| deps.is_empty())
+                .map(|(id, _)| *id)
+                .collect();
+
+            if ready.is_empty() {
+                return Err(TemplateError::IncludeCycle(Self::find_cycle(&pending)));
+            }
+
+            for id in ready {
+                pending.remove(id);
+                for deps in pending.values_mut() {
+                    deps.remove(id);
+                }
+                order.push(id);
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Walk include edges among unresolved templates until one repeats
+    fn find_cycle(pending: &BTreeMap<&str, BTreeSet<&str>>) -> Vec<String> {
+        let mut path: Vec<&str> = Vec::new();
+        let mut current = pending.keys().next().copied();
+
+        while let Some(id) = current {
+            if let Some(pos) = path.iter().position(|p| *p == id) {
+                let mut cycle: Vec<String> = path[pos..].iter().map(|s| s.to_string()).collect();
+                cycle.push(id.to_string());
+                return cycle;
+            }
+            path.push(id);
+            current = pending.get(id).and_then(|deps| deps.iter().next().copied());
+        }
+
+        path.into_iter().map(str::to_string).collect()
+    }
+
     /// Find templates by tag
     pub fn find_by_tag(&self, tag: &str) -> Vec<&TemplateDefinition> {
         self.by_tag
@@ -264,6 +435,153 @@ body: "(test.verb)"
         assert!(not_found.is_empty());
     }
 
+    fn composed_template(id: &str, body: &str) -> TemplateDefinition {
+        serde_yaml::from_str(&format!(
+            r#"
+template: {id}
+version: 1
+metadata:
+  name: {id}
+  summary: Composition test
+params:
+  cbu_id:
+    type: cbu_ref
+    required: true
+    source: session
+  name:
+    type: string
+    required: true
+body: |
+{body}
+"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_expand_with_include() {
+        let mut registry = TemplateRegistry::new();
+        registry.register(composed_template(
+            "kyc-setup",
+            "  (kyc.open-case :cbu \"$cbu_id\" :subject \"$name\")",
+        ));
+        registry.register(composed_template(
+            "onboard-director",
+            "  (entity.create :name \"$name\")\n  include: kyc-setup\n  (cbu.assign-role :cbu \"$cbu_id\")",
+        ));
+
+        let cbu_id = uuid::Uuid::new_v4();
+        let mut params = HashMap::new();
+        params.insert("name".to_string(), "Alice".to_string());
+        let result = registry
+            .expand(
+                "onboard-director",
+                &params,
+                &ExpansionContext::with_cbu(cbu_id),
+            )
+            .unwrap();
+
+        let lines: Vec<&str> = result.dsl.lines().collect();
+        assert_eq!(lines[0], "(entity.create :name \"Alice\")");
+        assert_eq!(
+            lines[1],
+            format!("(kyc.open-case :cbu \"{}\" :subject \"Alice\")", cbu_id)
+        );
+        assert!(lines[2].starts_with("(cbu.assign-role"));
+        assert!(result.missing_params.is_empty());
+        assert_eq!(result.template_id, "onboard-director");
+    }
+
+    #[test]
+    fn test_expand_template_resolves_includes() {
+        let mut registry = TemplateRegistry::new();
+        registry.register(composed_template(
+            "kyc-setup",
+            "  (kyc.open-case :subject \"$name\")",
+        ));
+        let held = composed_template("ad-hoc", "  include: kyc-setup");
+
+        let mut params = HashMap::new();
+        params.insert("name".to_string(), "Alice".to_string());
+        let result = registry
+            .expand_template(&held, &params, &ExpansionContext::new())
+            .unwrap();
+
+        assert_eq!(result.dsl.trim(), "(kyc.open-case :subject \"Alice\")");
+        assert_eq!(result.template_id, "ad-hoc");
+        assert!(result.missing_params.iter().any(|p| p.name == "cbu_id"));
+    }
+
+    #[test]
+    fn test_expand_include_cycle() {
+        let mut registry = TemplateRegistry::new();
+        registry.register(composed_template("a", "  include: b"));
+        registry.register(composed_template("b", "  include: a"));
+
+        let err = registry
+            .expand("a", &HashMap::new(), &ExpansionContext::new())
+            .unwrap_err();
+
+        assert!(matches!(&err, TemplateError::IncludeCycle(cycle) if cycle == &["a", "b", "a"]));
+        assert_eq!(err.to_string(), "Template include cycle: a -> b -> a");
+    }
+
+    fn include_graph() -> TemplateRegistry {
+        // onboard-fund → onboard-director → kyc-setup ← screening-only
+        let mut registry = TemplateRegistry::new();
+        registry.register(composed_template("kyc-setup", "  (kyc.open-case)"));
+        registry.register(composed_template(
+            "onboard-director",
+            "  (entity.create)\n  include: kyc-setup",
+        ));
+        registry.register(composed_template(
+            "onboard-fund",
+            "  (cbu.create)\n  include: onboard-director",
+        ));
+        registry.register(composed_template("screening-only", "  include: kyc-setup"));
+        registry
+    }
+
+    #[test]
+    fn test_dependents_of() {
+        let registry = include_graph();
+
+        assert_eq!(
+            registry.dependents_of("kyc-setup"),
+            vec!["onboard-director", "onboard-fund", "screening-only"]
+        );
+        assert_eq!(
+            registry.dependents_of("onboard-director"),
+            vec!["onboard-fund"]
+        );
+        assert!(registry.dependents_of("onboard-fund").is_empty());
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let registry = include_graph();
+
+        assert_eq!(
+            registry.topological_order().unwrap(),
+            vec![
+                "kyc-setup",
+                "onboard-director",
+                "screening-only",
+                "onboard-fund"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_topological_order_cycle() {
+        let mut registry = include_graph();
+        registry.register(composed_template("a", "  include: b"));
+        registry.register(composed_template("b", "  include: a"));
+
+        let err = registry.topological_order().unwrap_err();
+        assert!(matches!(&err, TemplateError::IncludeCycle(cycle) if cycle == &["a", "b", "a"]));
+    }
+
     #[test]
     #[ignore] // TODO: Fix search index - template not being indexed properly
     fn test_search() {
@@ -2,8 +2,16 @@
 //!
 //! All methods map to the corresponding server endpoints.
 //! Error bodies are deserialized to `SemOsError` based on HTTP status.
+//!
+//! Idempotent reads (`resolve_context`, `get_manifest`, `export_snapshot_set`,
+//! `list_tool_specs`) retry with exponential backoff on 502/503/504 and on
+//! transport failures (refused or reset connections, timeouts), so a server
+//! restart doesn't fail the caller. Once retries are exhausted the call fails
+//! with `SemOsError::MigrationPending`, the service-unavailable variant.
+//! Mutating calls are never retried.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use sem_os_core::{error::SemOsError, principal::Principal, proto::*, seeds::SeedBundle};
@@ -13,21 +21,99 @@ use sem_os_policy::context_resolution::{
     ContextResolutionResponse as ResolveContextResponse,
 };
 
+use serde::de::DeserializeOwned;
+
 use crate::{Result, SemOsClient};
 
+/// Retries used by [`HttpClient::new`].
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// First backoff delay used by [`HttpClient::new`]; doubles per retry.
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(200);
+/// Upper bound on a single backoff delay.
+const MAX_DELAY: Duration = Duration::from_secs(5);
+
 pub struct HttpClient {
     base_url: String,
     jwt_token: String,
     client: reqwest::Client,
+    max_retries: u32,
+    base_delay: Duration,
 }
 
 impl HttpClient {
     pub fn new(base_url: impl Into<String>, jwt_token: impl Into<String>) -> Self {
+        Self::with_retry(base_url, jwt_token, DEFAULT_MAX_RETRIES, DEFAULT_BASE_DELAY)
+    }
+
+    /// Client whose idempotent calls retry up to `max_retries` times, waiting
+    /// `base_delay` before the first retry and doubling it each time after.
+    /// `max_retries = 0` disables retries.
+    pub fn with_retry(
+        base_url: impl Into<String>,
+        jwt_token: impl Into<String>,
+        max_retries: u32,
+        base_delay: Duration,
+    ) -> Self {
         Self {
             base_url: base_url.into(),
             jwt_token: jwt_token.into(),
             client: reqwest::Client::new(),
+            max_retries,
+            base_delay,
+        }
+    }
+
+    fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.base_delay.saturating_mul(factor).min(MAX_DELAY)
+    }
+
+    /// Send an idempotent request, retrying transient failures with backoff.
+    ///
+    /// `build` is called once per attempt since a request can't be re-sent.
+    async fn send_idempotent<T, F>(&self, path: &str, build: F) -> Result<T>
+    where
+        T: DeserializeOwned,
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let mut retries = 0;
+        let resp = loop {
+            let outcome = build().bearer_auth(&self.jwt_token).send().await;
+            let transient = match &outcome {
+                Ok(resp) => is_transient_status(resp.status()),
+                Err(e) => is_transient_error(e),
+            };
+            if !transient {
+                break outcome.map_err(|e| SemOsError::Internal(e.into()))?;
+            }
+            if retries == self.max_retries {
+                let last = match outcome {
+                    Ok(resp) => format!("HTTP {}", resp.status().as_u16()),
+                    Err(e) => e.to_string(),
+                };
+                return Err(SemOsError::MigrationPending(format!(
+                    "Semantic OS unavailable: {path} still failing after {} attempts ({last})",
+                    retries + 1
+                )));
+            }
+            retries += 1;
+            let delay = self.backoff(retries);
+            tracing::warn!(
+                path,
+                retries,
+                ?delay,
+                "transient Semantic OS failure, retrying"
+            );
+            tokio::time::sleep(delay).await;
+        };
+
+        if !resp.status().is_success() {
+            return Err(self.handle_error_response(resp).await);
         }
+
+        resp.json::<T>()
+            .await
+            .map_err(|e| SemOsError::Internal(e.into()))
     }
 
     fn url(&self, path: &str) -> String {
@@ -56,6 +142,17 @@ impl HttpClient {
     }
 }
 
+/// Statuses a restarting server or proxy returns; worth retrying.
+fn is_transient_status(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 502..=504)
+}
+
+/// Transport failures a restarting server causes: refused connections,
+/// timeouts, and connections reset while the request or body is in flight.
+fn is_transient_error(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout() || error.is_request() || error.is_body()
+}
+
 #[async_trait]
 impl SemOsClient for HttpClient {
     async fn resolve_context(
@@ -63,61 +160,25 @@ impl SemOsClient for HttpClient {
         _principal: &Principal,
         req: ResolveContextRequest,
     ) -> Result<ResolveContextResponse> {
-        let resp = self
-            .client
-            .post(self.url("/resolve_context"))
-            .bearer_auth(&self.jwt_token)
-            .json(&req)
-            .send()
-            .await
-            .map_err(|e| SemOsError::Internal(e.into()))?;
-
-        if !resp.status().is_success() {
-            return Err(self.handle_error_response(resp).await);
-        }
-
-        resp.json::<ResolveContextResponse>()
-            .await
-            .map_err(|e| SemOsError::Internal(e.into()))
+        self.send_idempotent("/resolve_context", || {
+            self.client.post(self.url("/resolve_context")).json(&req)
+        })
+        .await
     }
 
     async fn get_manifest(&self, snapshot_set_id: &str) -> Result<GetManifestResponse> {
-        let resp = self
-            .client
-            .get(self.url(&format!("/snapshot_sets/{}/manifest", snapshot_set_id)))
-            .bearer_auth(&self.jwt_token)
-            .send()
-            .await
-            .map_err(|e| SemOsError::Internal(e.into()))?;
-
-        if !resp.status().is_success() {
-            return Err(self.handle_error_response(resp).await);
-        }
-
-        resp.json::<GetManifestResponse>()
+        let path = format!("/snapshot_sets/{}/manifest", snapshot_set_id);
+        self.send_idempotent(&path, || self.client.get(self.url(&path)))
             .await
-            .map_err(|e| SemOsError::Internal(e.into()))
     }
 
     async fn export_snapshot_set(
         &self,
         snapshot_set_id: &str,
     ) -> Result<ExportSnapshotSetResponse> {
-        let resp = self
-            .client
-            .get(self.url(&format!("/exports/snapshot_set/{}", snapshot_set_id)))
-            .bearer_auth(&self.jwt_token)
-            .send()
-            .await
-            .map_err(|e| SemOsError::Internal(e.into()))?;
-
-        if !resp.status().is_success() {
-            return Err(self.handle_error_response(resp).await);
-        }
-
-        resp.json::<ExportSnapshotSetResponse>()
+        let path = format!("/exports/snapshot_set/{}", snapshot_set_id);
+        self.send_idempotent(&path, || self.client.get(self.url(&path)))
             .await
-            .map_err(|e| SemOsError::Internal(e.into()))
     }
 
     async fn bootstrap_seed_bundle(
@@ -167,21 +228,8 @@ impl SemOsClient for HttpClient {
     }
 
     async fn list_tool_specs(&self) -> Result<ListToolSpecsResponse> {
-        let resp = self
-            .client
-            .get(self.url("/tools/list"))
-            .bearer_auth(&self.jwt_token)
-            .send()
+        self.send_idempotent("/tools/list", || self.client.get(self.url("/tools/list")))
             .await
-            .map_err(|e| SemOsError::Internal(e.into()))?;
-
-        if !resp.status().is_success() {
-            return Err(self.handle_error_response(resp).await);
-        }
-
-        resp.json::<ListToolSpecsResponse>()
-            .await
-            .map_err(|e| SemOsError::Internal(e.into()))
     }
 
     // ── Changeset / Workbench ──────────────────────────────────
@@ -295,3 +343,93 @@ impl SemOsClient for HttpClient {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Serve `statuses` in order, one per connection, with a tool-spec body on 200.
+    /// A status of 0 closes the connection without replying.
+    async fn flaky_server(statuses: Vec<u16>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+
+        tokio::spawn(async move {
+            for status in statuses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+                if status == 0 {
+                    continue;
+                }
+
+                let body = if status == 200 {
+                    r#"{"tools":[]}"#
+                } else {
+                    r#"{"error":"restarting"}"#
+                };
+                let reply = format!(
+                    "HTTP/1.1 {status} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = socket.write_all(reply.as_bytes()).await;
+            }
+        });
+
+        (format!("http://{addr}"), hits)
+    }
+
+    #[tokio::test]
+    async fn idempotent_call_succeeds_on_third_attempt() {
+        let (url, hits) = flaky_server(vec![503, 503, 200]).await;
+        let client = HttpClient::with_retry(url, "token", 3, Duration::from_millis(5));
+
+        let specs = client
+            .list_tool_specs()
+            .await
+            .expect("third attempt succeeds");
+        assert!(specs.tools.is_empty());
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn exhausted_retries_report_attempts() {
+        let (url, hits) = flaky_server(vec![503, 503, 503]).await;
+        let client = HttpClient::with_retry(url, "token", 2, Duration::from_millis(5));
+
+        let err = client.list_tool_specs().await.unwrap_err();
+        assert!(matches!(err, SemOsError::MigrationPending(_)), "{err}");
+        assert!(err.to_string().contains("after 3 attempts"), "{err}");
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn idempotent_call_retries_connection_reset_mid_request() {
+        let (url, hits) = flaky_server(vec![0, 200]).await;
+        let client = HttpClient::with_retry(url, "token", 3, Duration::from_millis(5));
+
+        let specs = client
+            .list_tool_specs()
+            .await
+            .expect("retry after reset succeeds");
+        assert!(specs.tools.is_empty());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn backoff_doubles_and_caps() {
+        let client = HttpClient::with_retry("http://x", "t", 10, Duration::from_millis(100));
+        assert_eq!(client.backoff(1), Duration::from_millis(100));
+        assert_eq!(client.backoff(2), Duration::from_millis(200));
+        assert_eq!(client.backoff(3), Duration::from_millis(400));
+        assert_eq!(client.backoff(10), MAX_DELAY);
+    }
+}
@@ -4,6 +4,9 @@
 
 pub mod http;
 pub mod inprocess;
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock;
+pub mod tool_call;
 
 use std::sync::Arc;
 
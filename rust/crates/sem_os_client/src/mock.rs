@@ -0,0 +1,375 @@
+//! MockSemOsClient — canned responses for tests (no database, no network).
+//!
+//! Tests register responses for `dispatch_tool` (by tool name and arguments),
+//! `resolve_context` (by request, or a catch-all) and `list_tool_specs`.
+//! Anything not registered falls back to an empty result where the shape is
+//! obvious (tool specs, changesets, manifests, snapshot set exports) and to
+//! `SemOsError::NotFound` otherwise.
+//!
+//! Responses are stored as JSON and deserialized on every call, so the same
+//! registration can be served any number of times.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use sem_os_core::{error::SemOsError, principal::Principal, proto::*, seeds::SeedBundle};
+use sem_os_policy::affinity::AffinityGraph;
+use sem_os_policy::context_resolution::{
+    ContextResolutionRequest as ResolveContextRequest,
+    ContextResolutionResponse as ResolveContextResponse,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{Result, SemOsClient};
+
+type ErrorFn = Arc<dyn Fn() -> SemOsError + Send + Sync>;
+
+/// A registered reply: a serialized response, or a way to build the error.
+#[derive(Clone)]
+enum Canned {
+    Ok(Value),
+    Err(ErrorFn),
+}
+
+impl Canned {
+    fn ok<T: Serialize>(response: &T) -> Self {
+        Canned::Ok(serde_json::to_value(response).expect("mock response must serialize"))
+    }
+
+    fn err(error: impl Fn() -> SemOsError + Send + Sync + 'static) -> Self {
+        Canned::Err(Arc::new(error))
+    }
+
+    fn reply<T: DeserializeOwned>(&self) -> Result<T> {
+        match self {
+            Canned::Ok(value) => {
+                serde_json::from_value(value.clone()).map_err(|e| SemOsError::Internal(e.into()))
+            }
+            Canned::Err(error) => Err(error()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    tools: HashMap<String, Canned>,
+    contexts: HashMap<String, Canned>,
+    any_context: Option<Canned>,
+    tool_specs: Option<Canned>,
+}
+
+/// In-memory `SemOsClient` for tests.
+#[derive(Default)]
+pub struct MockSemOsClient {
+    registry: Mutex<Registry>,
+}
+
+impl MockSemOsClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer `dispatch_tool` calls for `tool_name` with exactly these
+    /// `arguments` with `response`.
+    pub fn with_tool_response(
+        self,
+        tool_name: &str,
+        arguments: Value,
+        response: ToolCallResponse,
+    ) -> Self {
+        let key = Self::tool_key(tool_name, &arguments);
+        self.registry().tools.insert(key, Canned::ok(&response));
+        self
+    }
+
+    /// Fail `dispatch_tool` calls for `tool_name` with exactly these
+    /// `arguments` with the error `error` builds.
+    pub fn with_tool_error(
+        self,
+        tool_name: &str,
+        arguments: Value,
+        error: impl Fn() -> SemOsError + Send + Sync + 'static,
+    ) -> Self {
+        let key = Self::tool_key(tool_name, &arguments);
+        self.registry().tools.insert(key, Canned::err(error));
+        self
+    }
+
+    /// Answer `resolve_context` for exactly this request with `response`.
+    pub fn with_context_response(
+        self,
+        request: &ResolveContextRequest,
+        response: ResolveContextResponse,
+    ) -> Self {
+        let key = Self::context_key(request);
+        self.registry().contexts.insert(key, Canned::ok(&response));
+        self
+    }
+
+    /// Answer `resolve_context` for any request without its own registration.
+    pub fn with_any_context_response(self, response: ResolveContextResponse) -> Self {
+        self.registry().any_context = Some(Canned::ok(&response));
+        self
+    }
+
+    /// Fail `resolve_context` for any request without its own registration.
+    pub fn with_context_error(
+        self,
+        error: impl Fn() -> SemOsError + Send + Sync + 'static,
+    ) -> Self {
+        self.registry().any_context = Some(Canned::err(error));
+        self
+    }
+
+    /// Answer `list_tool_specs` with `response`.
+    pub fn with_tool_specs(self, response: ListToolSpecsResponse) -> Self {
+        self.registry().tool_specs = Some(Canned::ok(&response));
+        self
+    }
+
+    /// Fail `list_tool_specs` with the error `error` builds.
+    pub fn with_tool_specs_error(
+        self,
+        error: impl Fn() -> SemOsError + Send + Sync + 'static,
+    ) -> Self {
+        self.registry().tool_specs = Some(Canned::err(error));
+        self
+    }
+
+    fn registry(&self) -> std::sync::MutexGuard<'_, Registry> {
+        self.registry.lock().expect("mock registry lock poisoned")
+    }
+
+    fn tool_key(tool_name: &str, arguments: &Value) -> String {
+        serde_json::to_string(&(tool_name, arguments)).expect("tool arguments must serialize")
+    }
+
+    fn context_key(request: &ResolveContextRequest) -> String {
+        serde_json::to_string(request).expect("context request must serialize")
+    }
+
+    fn not_registered(what: &str) -> SemOsError {
+        SemOsError::NotFound(format!(
+            "MockSemOsClient: no response registered for {what}"
+        ))
+    }
+}
+
+#[async_trait]
+impl SemOsClient for MockSemOsClient {
+    async fn resolve_context(
+        &self,
+        _principal: &Principal,
+        req: ResolveContextRequest,
+    ) -> Result<ResolveContextResponse> {
+        let key = Self::context_key(&req);
+        let registry = self.registry();
+        match registry
+            .contexts
+            .get(&key)
+            .or(registry.any_context.as_ref())
+        {
+            Some(canned) => canned.reply(),
+            None => Err(Self::not_registered("resolve_context")),
+        }
+    }
+
+    async fn get_manifest(&self, snapshot_set_id: &str) -> Result<GetManifestResponse> {
+        Ok(GetManifestResponse {
+            snapshot_set_id: snapshot_set_id.to_string(),
+            // The Unix epoch
+            published_at: Default::default(),
+            entries: Vec::new(),
+        })
+    }
+
+    async fn export_snapshot_set(
+        &self,
+        snapshot_set_id: &str,
+    ) -> Result<ExportSnapshotSetResponse> {
+        Ok(ExportSnapshotSetResponse {
+            snapshot_set_id: snapshot_set_id.to_string(),
+            snapshots: Vec::new(),
+        })
+    }
+
+    async fn bootstrap_seed_bundle(
+        &self,
+        _principal: &Principal,
+        _bundle: SeedBundle,
+    ) -> Result<BootstrapSeedBundleResponse> {
+        Err(Self::not_registered("bootstrap_seed_bundle"))
+    }
+
+    async fn dispatch_tool(
+        &self,
+        _principal: &Principal,
+        req: ToolCallRequest,
+    ) -> Result<ToolCallResponse> {
+        let key = Self::tool_key(&req.tool_name, &req.arguments);
+        match self.registry().tools.get(&key) {
+            Some(canned) => canned.reply(),
+            None => Err(Self::not_registered(&format!(
+                "tool {} with arguments {}",
+                req.tool_name, req.arguments
+            ))),
+        }
+    }
+
+    async fn list_tool_specs(&self) -> Result<ListToolSpecsResponse> {
+        match &self.registry().tool_specs {
+            Some(canned) => canned.reply(),
+            None => Ok(ListToolSpecsResponse { tools: Vec::new() }),
+        }
+    }
+
+    // ── Changeset / Workbench ──────────────────────────────────
+
+    async fn list_changesets(&self, _query: ListChangesetsQuery) -> Result<ListChangesetsResponse> {
+        Ok(ListChangesetsResponse {
+            changesets: Vec::new(),
+        })
+    }
+
+    async fn changeset_diff(&self, changeset_id: &str) -> Result<ChangesetDiffResponse> {
+        Err(Self::not_registered(&format!(
+            "changeset {changeset_id} diff"
+        )))
+    }
+
+    async fn changeset_impact(&self, changeset_id: &str) -> Result<ChangesetImpactResponse> {
+        Err(Self::not_registered(&format!(
+            "changeset {changeset_id} impact"
+        )))
+    }
+
+    async fn changeset_gate_preview(&self, changeset_id: &str) -> Result<GatePreviewResponse> {
+        Err(Self::not_registered(&format!(
+            "changeset {changeset_id} gate preview"
+        )))
+    }
+
+    async fn publish_changeset(
+        &self,
+        _principal: &Principal,
+        changeset_id: &str,
+    ) -> Result<ChangesetPublishResponse> {
+        Err(Self::not_registered(&format!(
+            "changeset {changeset_id} publish"
+        )))
+    }
+
+    async fn get_affinity_graph(&self) -> Result<Arc<AffinityGraph>> {
+        Err(Self::not_registered("affinity graph"))
+    }
+
+    async fn drain_outbox_for_test(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(fqn: &str) -> Value {
+        serde_json::json!({ "fqn": fqn })
+    }
+
+    fn call(tool_name: &str, fqn: &str) -> ToolCallRequest {
+        ToolCallRequest {
+            tool_name: tool_name.to_string(),
+            arguments: args(fqn),
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_tool_returns_registered_response() {
+        let client = MockSemOsClient::new().with_tool_response(
+            "sem_reg_describe_verb",
+            args("cbu.create"),
+            ToolCallResponse {
+                success: true,
+                data: serde_json::json!({ "fqn": "cbu.create" }),
+                error: None,
+            },
+        );
+
+        let resp = client
+            .dispatch_tool(
+                &Principal::system(),
+                call("sem_reg_describe_verb", "cbu.create"),
+            )
+            .await
+            .expect("registered tool");
+        assert!(resp.success);
+        assert_eq!(resp.data["fqn"], "cbu.create");
+
+        // Served again for repeat calls
+        assert!(client
+            .dispatch_tool(
+                &Principal::system(),
+                call("sem_reg_describe_verb", "cbu.create")
+            )
+            .await
+            .is_ok());
+
+        // Same tool, different arguments: not registered
+        let err = client
+            .dispatch_tool(
+                &Principal::system(),
+                call("sem_reg_describe_verb", "cbu.delete"),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SemOsError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn unregistered_and_failing_tools_error() {
+        let client =
+            MockSemOsClient::new().with_tool_error("sem_reg_publish", args("cbu.create"), || {
+                SemOsError::Conflict("locked".into())
+            });
+
+        let err = client
+            .dispatch_tool(&Principal::system(), call("sem_reg_publish", "cbu.create"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SemOsError::Conflict(_)));
+
+        let err = client
+            .dispatch_tool(&Principal::system(), call("unknown_tool", "cbu.create"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SemOsError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn tool_specs_default_to_empty() {
+        let client = MockSemOsClient::new();
+        let specs = client.list_tool_specs().await.expect("default specs");
+        assert!(specs.tools.is_empty());
+    }
+
+    #[tokio::test]
+    async fn manifest_and_export_default_to_empty() {
+        let client = MockSemOsClient::new();
+
+        let manifest = client
+            .get_manifest("unknown-set")
+            .await
+            .expect("default manifest");
+        assert_eq!(manifest.snapshot_set_id, "unknown-set");
+        assert!(manifest.entries.is_empty());
+
+        let export = client
+            .export_snapshot_set("unknown-set")
+            .await
+            .expect("default export");
+        assert!(export.snapshots.is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+//! Client-side helpers for building and checking `ToolCallRequest`s.
+//!
+//! `dispatch_tool` accepts free-form JSON arguments, so a missing argument
+//! otherwise surfaces deep in the server's dispatcher. Building calls through
+//! [`ToolCallBuilder`] and checking them with
+//! [`ToolCallRequestExt::validate_against_spec`] against a spec from
+//! `list_tool_specs` catches that before the round-trip.
+
+use sem_os_core::{
+    error::SemOsError,
+    proto::{ToolCallRequest, ToolSpec},
+};
+use serde_json::{Map, Value};
+
+use crate::Result;
+
+/// Incrementally assembles a [`ToolCallRequest`] with object arguments.
+#[derive(Debug, Clone)]
+pub struct ToolCallBuilder {
+    tool_name: String,
+    arguments: Map<String, Value>,
+}
+
+impl ToolCallBuilder {
+    pub fn new(tool_name: impl Into<String>) -> Self {
+        Self {
+            tool_name: tool_name.into(),
+            arguments: Map::new(),
+        }
+    }
+
+    /// Set an argument; a repeated key overwrites the earlier value.
+    pub fn arg(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.arguments.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn build(self) -> ToolCallRequest {
+        ToolCallRequest {
+            tool_name: self.tool_name,
+            arguments: Value::Object(self.arguments),
+        }
+    }
+}
+
+/// Builder and spec check for `ToolCallRequest`, which lives in `sem_os_core`.
+pub trait ToolCallRequestExt {
+    /// Start building a call to `tool_name`.
+    fn builder(tool_name: impl Into<String>) -> ToolCallBuilder;
+
+    /// Check this call against `spec`: the tool name must match, arguments
+    /// must be a JSON object (or null when nothing is required), and every
+    /// required parameter must be present and non-null.
+    ///
+    /// Returns `SemOsError::InvalidInput` naming all missing parameters.
+    fn validate_against_spec(&self, spec: &ToolSpec) -> Result<()>;
+}
+
+impl ToolCallRequestExt for ToolCallRequest {
+    fn builder(tool_name: impl Into<String>) -> ToolCallBuilder {
+        ToolCallBuilder::new(tool_name)
+    }
+
+    fn validate_against_spec(&self, spec: &ToolSpec) -> Result<()> {
+        if self.tool_name != spec.name {
+            return Err(SemOsError::InvalidInput(format!(
+                "tool call '{}' checked against spec for '{}'",
+                self.tool_name, spec.name
+            )));
+        }
+
+        let empty = Map::new();
+        let args = match &self.arguments {
+            Value::Object(map) => map,
+            Value::Null => &empty,
+            other => {
+                return Err(SemOsError::InvalidInput(format!(
+                    "tool '{}' arguments must be a JSON object, got {}",
+                    self.tool_name,
+                    json_kind(other)
+                )))
+            }
+        };
+
+        let missing: Vec<&str> = spec
+            .parameters
+            .iter()
+            .filter(|p| p.required && args.get(&p.name).is_none_or(Value::is_null))
+            .map(|p| p.name.as_str())
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(SemOsError::InvalidInput(format!(
+                "tool '{}' is missing required argument(s): {}",
+                self.tool_name,
+                missing.join(", ")
+            )))
+        }
+    }
+}
+
+fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> ToolSpec {
+        serde_json::from_value(serde_json::json!({
+            "name": "sem_reg_describe_verb",
+            "description": "Describe a verb contract",
+            "category": "registry_query",
+            "parameters": [
+                { "name": "fqn", "description": "Verb FQN", "param_type": "string", "required": true },
+                { "name": "as_of", "description": "Snapshot set", "param_type": "uuid", "required": false },
+            ],
+        }))
+        .expect("tool spec fixture")
+    }
+
+    #[test]
+    fn complete_call_validates() {
+        let req = ToolCallRequest::builder("sem_reg_describe_verb")
+            .arg("fqn", "cbu.create")
+            .build();
+        assert_eq!(req.arguments, serde_json::json!({ "fqn": "cbu.create" }));
+        req.validate_against_spec(&spec()).expect("complete call");
+    }
+
+    #[test]
+    fn missing_required_arg_is_rejected() {
+        let req = ToolCallRequest::builder("sem_reg_describe_verb")
+            .arg("as_of", Value::Null)
+            .build();
+        let err = req.validate_against_spec(&spec()).unwrap_err();
+        match err {
+            SemOsError::InvalidInput(msg) => assert!(msg.contains("fqn"), "{msg}"),
+            other => panic!("expected InvalidInput, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn null_required_arg_counts_as_missing() {
+        let req = ToolCallRequest::builder("sem_reg_describe_verb")
+            .arg("fqn", Value::Null)
+            .build();
+        assert!(req.validate_against_spec(&spec()).is_err());
+    }
+
+    #[test]
+    fn mismatched_tool_name_is_rejected() {
+        let req = ToolCallRequest::builder("sem_reg_search")
+            .arg("fqn", "cbu.create")
+            .build();
+        assert!(req.validate_against_spec(&spec()).is_err());
+    }
+}
@@ -9,6 +9,7 @@
 //! - test_context_resolution_determinism — same input = same output
 //! - test_manifest_stability — manifest stable across queries
 //! - test_projection_watermark_advances — outbox → projection → watermark (S2.2)
+//! - test_watermark_api_tracks_outbox_head — current_watermark / is_caught_up
 //!
 //! SC-4 applied: test DB isolation uses CREATE/DROP DATABASE per run.
 #![deny(unreachable_pub)]
@@ -778,6 +779,128 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[ignore] // Requires a running Postgres instance
+    async fn test_changeset_pagination_is_disjoint_and_ordered() {
+        let iso = isolated_db(&admin_url()).await;
+        let pool = iso.pool.clone();
+        let result = std::panic::AssertUnwindSafe(async move {
+            let scope = format!("paging-test-{}", Uuid::new_v4().simple());
+            for owner in ["a", "b", "c", "d", "e"] {
+                sqlx::query(
+                    "INSERT INTO sem_reg.changesets (status, owner_actor_id, scope) VALUES ('draft', $1, $2)",
+                )
+                .bind(owner)
+                .bind(&scope)
+                .execute(&pool)
+                .await
+                .expect("insert changeset");
+            }
+
+            let store = PgChangesetStore::new(pool);
+            let mut seen = Vec::new();
+            let mut offset = 0;
+            loop {
+                let page = store
+                    .list_changesets_page(None, None, Some(&scope), 2, offset)
+                    .await
+                    .expect("list page");
+                assert_eq!(page.total, 5);
+                assert!(page.changesets.len() <= 2);
+                offset += page.changesets.len() as i64;
+                seen.extend(page.changesets);
+                if !page.has_more {
+                    break;
+                }
+            }
+
+            let mut owners: Vec<&str> = seen.iter().map(|c| c.owner_actor_id.as_str()).collect();
+            owners.sort_unstable();
+            assert_eq!(
+                owners,
+                ["a", "b", "c", "d", "e"],
+                "pages cover the full set"
+            );
+            let ids: std::collections::HashSet<_> = seen.iter().map(|c| c.changeset_id).collect();
+            assert_eq!(ids.len(), seen.len(), "pages are disjoint");
+            assert!(
+                seen.windows(2).all(|w| {
+                    (w[0].created_at, w[0].changeset_id) <= (w[1].created_at, w[1].changeset_id)
+                }),
+                "pages follow (created_at, changeset_id) order"
+            );
+        });
+        let outcome = futures::FutureExt::catch_unwind(result).await;
+
+        drop_db(iso).await;
+
+        if let Err(e) = outcome {
+            std::panic::resume_unwind(e);
+        }
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires a running Postgres instance
+    async fn test_audit_query_filters_by_principal() {
+        use sem_os_core::ports::AuditStore;
+        use sem_os_core::types::AuditEntry;
+        use sem_os_postgres::AuditQuery;
+
+        let iso = isolated_db(&admin_url()).await;
+        let pool = iso.pool.clone();
+        let result = std::panic::AssertUnwindSafe(async move {
+            let unique = Uuid::new_v4().simple().to_string();
+            let alice = format!("alice-{unique}");
+            let bob = format!("bob-{unique}");
+            let store = PgAuditStore::new(pool);
+            for (actor, action) in [(&alice, "publish"), (&bob, "publish"), (&alice, "approve")] {
+                let principal = Principal::in_process(actor, vec!["admin".into()]);
+                let entry = AuditEntry {
+                    action: action.into(),
+                    details: serde_json::json!({ "unique": unique }),
+                };
+                store
+                    .append(&principal, entry)
+                    .await
+                    .expect("append audit entry");
+            }
+
+            let entries = store
+                .query(&AuditQuery::for_principal(alice.clone()))
+                .await
+                .expect("audit query");
+            assert_eq!(entries.len(), 2);
+            assert!(entries.iter().all(|e| e.principal == alice));
+            let actions: Vec<&str> = entries.iter().map(|e| e.action.as_str()).collect();
+            assert_eq!(actions, ["publish", "approve"], "ordered by time");
+
+            let narrowed = store
+                .query(&AuditQuery {
+                    action: Some("approve".into()),
+                    ..AuditQuery::for_principal(alice.clone())
+                })
+                .await
+                .expect("audit query by action");
+            assert_eq!(narrowed.len(), 1);
+
+            let none = store
+                .query(&AuditQuery {
+                    since: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                    ..AuditQuery::for_principal(bob)
+                })
+                .await
+                .expect("audit query in the future");
+            assert!(none.is_empty());
+        });
+        let outcome = futures::FutureExt::catch_unwind(result).await;
+
+        drop_db(iso).await;
+
+        if let Err(e) = outcome {
+            std::panic::resume_unwind(e);
+        }
+    }
+
     #[tokio::test]
     async fn test_harness_execution_suite_with_mock() {
         use dsl_runtime::VerbExecutionPort;
@@ -3,11 +3,13 @@
 //! Tests that the outbox → projection pipeline works:
 //! - Watermark advances after drain.
 //! - sem_reg_pub.active_* tables contain expected entries.
+//! - The watermark API reports lag until the outbox is drained.
 
 use sem_os_client::SemOsClient;
 use sem_os_core::principal::Principal;
 use sem_os_core::seeds::*;
 use sem_os_ontology::proof_obligation_def::ProofStrength;
+use sem_os_postgres::{PgOutboxStore, PgProjectionWriter};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -113,6 +115,7 @@ fn make_evidence_strategy_seed(fqn: &str, obligation_fqn: &str) -> EvidenceStrat
 /// The pool is needed to query sem_reg_pub tables directly for assertions.
 pub(crate) async fn run_projection_scenario_suite(client: &dyn SemOsClient, pool: &PgPool) {
     test_projection_watermark_advances(client, pool).await;
+    test_watermark_api_tracks_outbox_head(client, pool).await;
 }
 
 /// Publish a seed bundle, drain outbox, verify watermark advanced and
@@ -294,3 +297,85 @@ async fn test_projection_watermark_advances(client: &dyn SemOsClient, pool: &PgP
 
     tracing::info!("test_projection_watermark_advances: passed (watermark={after_wm})");
 }
+
+/// Publish a bundle without draining and check `is_caught_up` reports the lag;
+/// after draining, `current_watermark` has advanced to the outbox head.
+async fn test_watermark_api_tracks_outbox_head(client: &dyn SemOsClient, pool: &PgPool) {
+    tracing::info!("test_watermark_api_tracks_outbox_head: starting");
+    let principal = test_principal();
+    let projections = PgProjectionWriter::new(pool.clone());
+    let outbox = PgOutboxStore::new(pool.clone());
+
+    let before_wm = projections
+        .current_watermark()
+        .await
+        .expect("watermark read before publish");
+
+    let unique = Uuid::new_v4().simple().to_string();
+    let bundle = SeedBundle {
+        bundle_hash: String::new(),
+        verb_contracts: vec![make_verb_contract_seed(
+            &format!("wm-test.verb-{unique}"),
+            "wm-test",
+            "Watermark test verb",
+        )],
+        macro_defs: vec![],
+        universes: vec![],
+        constellation_families: vec![],
+        constellation_maps: vec![],
+        state_machines: vec![],
+        state_graphs: vec![],
+        dag_taxonomies: vec![],
+        domain_packs: vec![],
+        attributes: vec![],
+        entity_types: vec![],
+        taxonomies: vec![],
+        policies: vec![],
+        views: vec![],
+        derivation_specs: vec![],
+        requirement_profiles: vec![],
+        proof_obligations: vec![],
+        evidence_strategies: vec![],
+    };
+    let bundle = SeedBundle {
+        bundle_hash: SeedBundle::compute_hash(&bundle).expect("test seed bundle hash"),
+        ..bundle
+    };
+    client
+        .bootstrap_seed_bundle(&principal, bundle)
+        .await
+        .expect("bootstrap for watermark API test");
+
+    assert!(
+        !projections
+            .is_caught_up(&outbox)
+            .await
+            .expect("is_caught_up before drain"),
+        "projection should lag the outbox before draining"
+    );
+
+    client
+        .drain_outbox_for_test()
+        .await
+        .expect("drain_outbox_for_test");
+
+    let after_wm = projections
+        .current_watermark()
+        .await
+        .expect("watermark read after drain");
+    assert!(
+        after_wm > before_wm,
+        "watermark should advance: before={before_wm}, after={after_wm}"
+    );
+    assert_eq!(
+        after_wm,
+        outbox.head_seq().await.expect("outbox head"),
+        "watermark should reach the outbox head"
+    );
+    assert!(projections
+        .is_caught_up(&outbox)
+        .await
+        .expect("is_caught_up after drain"));
+
+    tracing::info!("test_watermark_api_tracks_outbox_head: passed (watermark={after_wm})");
+}
@@ -0,0 +1,221 @@
+//! Seed bundle diff — preview what a bootstrap would add, remove, or change.
+//!
+//! Compares two `SeedBundle`s category by category, keyed on FQN. An entry is
+//! "changed" when both bundles carry the FQN but the serialized payloads differ.
+//! Pure comparison; nothing here touches the database.
+
+use std::collections::BTreeMap;
+
+use sem_os_core::seeds::SeedBundle;
+use serde_json::Value;
+
+/// FQN-level differences for one seed category. Each list is sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CategoryDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl CategoryDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn between<'a>(
+        base: impl Iterator<Item = (&'a str, &'a Value)>,
+        target: impl Iterator<Item = (&'a str, &'a Value)>,
+    ) -> Self {
+        let base: BTreeMap<&str, &Value> = base.collect();
+        let target: BTreeMap<&str, &Value> = target.collect();
+
+        let mut diff = Self::default();
+        for (fqn, payload) in &target {
+            match base.get(fqn) {
+                None => diff.added.push((*fqn).to_string()),
+                Some(old) if old != payload => diff.changed.push((*fqn).to_string()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = base
+            .keys()
+            .filter(|fqn| !target.contains_key(*fqn))
+            .map(|fqn| (*fqn).to_string())
+            .collect();
+        diff
+    }
+}
+
+/// Per-category differences between two seed bundles, one field per
+/// `SeedBundle` category.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeedBundleDiff {
+    pub verb_contracts: CategoryDiff,
+    pub macro_defs: CategoryDiff,
+    pub universes: CategoryDiff,
+    pub constellation_families: CategoryDiff,
+    pub constellation_maps: CategoryDiff,
+    pub state_machines: CategoryDiff,
+    pub state_graphs: CategoryDiff,
+    pub dag_taxonomies: CategoryDiff,
+    pub domain_packs: CategoryDiff,
+    pub attributes: CategoryDiff,
+    pub entity_types: CategoryDiff,
+    pub taxonomies: CategoryDiff,
+    pub policies: CategoryDiff,
+    pub views: CategoryDiff,
+    pub derivation_specs: CategoryDiff,
+    pub requirement_profiles: CategoryDiff,
+    pub proof_obligations: CategoryDiff,
+    pub evidence_strategies: CategoryDiff,
+}
+
+impl SeedBundleDiff {
+    /// True when the bundles agree on every category.
+    pub fn is_empty(&self) -> bool {
+        self.categories().iter().all(|(_, diff)| diff.is_empty())
+    }
+
+    /// Categories paired with their names, in a fixed order for reporting.
+    pub fn categories(&self) -> [(&'static str, &CategoryDiff); 18] {
+        [
+            ("verb_contracts", &self.verb_contracts),
+            ("macro_defs", &self.macro_defs),
+            ("universes", &self.universes),
+            ("constellation_families", &self.constellation_families),
+            ("constellation_maps", &self.constellation_maps),
+            ("state_machines", &self.state_machines),
+            ("state_graphs", &self.state_graphs),
+            ("dag_taxonomies", &self.dag_taxonomies),
+            ("domain_packs", &self.domain_packs),
+            ("attributes", &self.attributes),
+            ("entity_types", &self.entity_types),
+            ("taxonomies", &self.taxonomies),
+            ("policies", &self.policies),
+            ("views", &self.views),
+            ("derivation_specs", &self.derivation_specs),
+            ("requirement_profiles", &self.requirement_profiles),
+            ("proof_obligations", &self.proof_obligations),
+            ("evidence_strategies", &self.evidence_strategies),
+        ]
+    }
+}
+
+macro_rules! category {
+    ($base:expr, $target:expr, $field:ident) => {
+        CategoryDiff::between(
+            $base.$field.iter().map(|s| (s.fqn.as_str(), &s.payload)),
+            $target.$field.iter().map(|s| (s.fqn.as_str(), &s.payload)),
+        )
+    };
+}
+
+/// Diff `target` against `base`.
+///
+/// `added` lists FQNs only in `target`, `removed` those only in `base`.
+/// For a bootstrap preview, pass the currently active bundle as `base` and
+/// the bundle about to be bootstrapped as `target`.
+pub fn diff_seed_bundles(base: &SeedBundle, target: &SeedBundle) -> SeedBundleDiff {
+    SeedBundleDiff {
+        verb_contracts: category!(base, target, verb_contracts),
+        macro_defs: category!(base, target, macro_defs),
+        universes: category!(base, target, universes),
+        constellation_families: category!(base, target, constellation_families),
+        constellation_maps: category!(base, target, constellation_maps),
+        state_machines: category!(base, target, state_machines),
+        state_graphs: category!(base, target, state_graphs),
+        dag_taxonomies: category!(base, target, dag_taxonomies),
+        domain_packs: category!(base, target, domain_packs),
+        attributes: category!(base, target, attributes),
+        entity_types: category!(base, target, entity_types),
+        taxonomies: category!(base, target, taxonomies),
+        policies: category!(base, target, policies),
+        views: category!(base, target, views),
+        derivation_specs: category!(base, target, derivation_specs),
+        requirement_profiles: category!(base, target, requirement_profiles),
+        proof_obligations: category!(base, target, proof_obligations),
+        evidence_strategies: category!(base, target, evidence_strategies),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dsl_core::VerbsConfig;
+    use sem_os_core::seeds::{AttributeSeed, StateGraphSeed, VerbContractSeed};
+    use std::collections::HashMap;
+
+    fn bundle() -> SeedBundle {
+        crate::build_seed_bundle(&VerbsConfig {
+            version: "1.0".into(),
+            domains: HashMap::new(),
+        })
+    }
+
+    #[test]
+    fn test_identical_bundles_have_empty_diff() {
+        let diff = diff_seed_bundles(&bundle(), &bundle());
+        assert!(diff.is_empty(), "unexpected diff: {diff:?}");
+    }
+
+    #[test]
+    fn test_added_verb_contract() {
+        let base = bundle();
+        let mut target = bundle();
+        target.verb_contracts.push(VerbContractSeed {
+            fqn: "cbu.archive".into(),
+            payload: serde_json::json!({ "fqn": "cbu.archive" }),
+        });
+
+        let diff = diff_seed_bundles(&base, &target);
+        assert_eq!(diff.verb_contracts.added, vec!["cbu.archive".to_string()]);
+        assert!(diff.verb_contracts.removed.is_empty());
+
+        // Reversed direction reports it as removed.
+        let reverse = diff_seed_bundles(&target, &base);
+        assert_eq!(
+            reverse.verb_contracts.removed,
+            vec!["cbu.archive".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_changed_attribute_payload() {
+        let attribute = |label: &str| AttributeSeed {
+            fqn: "cbu.risk_rating".into(),
+            payload: serde_json::json!({ "fqn": "cbu.risk_rating", "name": label }),
+        };
+        let mut base = bundle();
+        base.attributes.push(attribute("Risk rating"));
+        let mut target = bundle();
+        target.attributes.push(attribute("Risk rating (CDD)"));
+
+        let diff = diff_seed_bundles(&base, &target);
+        assert_eq!(diff.attributes.changed, vec!["cbu.risk_rating".to_string()]);
+        assert!(diff.attributes.added.is_empty());
+        assert!(diff.attributes.removed.is_empty());
+        assert!(diff.verb_contracts.is_empty());
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_removed_state_graph() {
+        let mut base = bundle();
+        base.state_graphs.push(StateGraphSeed {
+            fqn: "graph.kyc_case".into(),
+            payload: serde_json::json!({ "fqn": "graph.kyc_case" }),
+        });
+        let target = bundle();
+
+        let diff = diff_seed_bundles(&base, &target);
+        assert_eq!(
+            diff.state_graphs.removed,
+            vec!["graph.kyc_case".to_string()]
+        );
+        assert!(diff
+            .categories()
+            .iter()
+            .filter(|(name, _)| *name != "state_graphs")
+            .all(|(_, category)| category.is_empty()));
+    }
+}
@@ -6,8 +6,10 @@
 //! - `scanner` — verb-first YAML scanner (pure conversion functions)
 //! - `seeds` — taxonomy, view, policy, derivation spec seed builders
 //! - `onboarding` — request validation and default generation
+//! - `bundle_diff` — FQN-level diff between two seed bundles (bootstrap preview)
 #![deny(unreachable_pub)]
 
+pub mod bundle_diff;
 pub mod metadata;
 pub mod onboarding;
 mod pipeline_seeds;
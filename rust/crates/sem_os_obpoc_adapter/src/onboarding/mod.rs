@@ -12,4 +12,7 @@ pub use defaults::{
     default_taxonomy_fqns_for_entity_type, default_verb_contracts_for_entity_type,
     default_view_fqns_for_entity_type, membership_rule_for_entity_in_taxonomy,
 };
-pub use validators::{validate_request, OnboardingRequest};
+pub use validators::{
+    validate_request, validate_request_report, FieldError, FieldErrorCode, OnboardingRequest,
+    OnboardingValidationReport,
+};
@@ -3,7 +3,11 @@
 //! Ensures structural correctness before any snapshots are published.
 //! All functions are **pure** (no DB, no I/O).
 
-use anyhow::{bail, Result};
+use std::collections::HashSet;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
 
 use sem_os_ontology::{
     attribute_def::AttributeDefBody, entity_type_def::EntityTypeDefBody,
@@ -23,109 +27,199 @@ pub struct OnboardingRequest {
     pub created_by: String,
 }
 
-/// Validate the onboarding request for structural correctness.
-pub fn validate_request(request: &OnboardingRequest) -> Result<()> {
-    validate_entity_type(request)?;
-    validate_attributes(request)?;
-    validate_verb_contracts(request)?;
-    validate_evidence_requirements(request)?;
-    Ok(())
+/// Why a field failed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldErrorCode {
+    /// The field is empty but must be set.
+    Required,
+    /// An FQN lacks its `domain.` prefix.
+    NotDomainQualified,
+    /// An FQN repeats one earlier in the same list.
+    Duplicate,
 }
 
-fn validate_entity_type(request: &OnboardingRequest) -> Result<()> {
-    let et = &request.entity_type;
+/// A validation failure tied to one field of the request.
+///
+/// `field` is a path into the request, e.g. `entity_type.name` or
+/// `attributes[2].fqn`, so a form can highlight the offending input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: FieldErrorCode,
+    pub message: String,
+}
 
-    if et.fqn.is_empty() {
-        bail!("Entity type FQN is required");
-    }
-    if !et.fqn.contains('.') {
-        bail!(
-            "Entity type FQN must be domain-qualified (e.g., 'entity.fund'), got '{}'",
-            et.fqn
-        );
-    }
-    if et.name.is_empty() {
-        bail!("Entity type name is required");
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
     }
-    if et.domain.is_empty() {
-        bail!("Entity type domain is required");
+}
+
+/// Every field error found in a request, in request order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct OnboardingValidationReport {
+    pub errors: Vec<FieldError>,
+}
+
+impl OnboardingValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
     }
-    if et.description.is_empty() {
-        bail!("Entity type description is required");
+
+    /// Errors reported against `field`.
+    pub fn errors_for<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a FieldError> {
+        self.errors.iter().filter(move |e| e.field == field)
     }
 
-    Ok(())
-}
+    fn push(&mut self, field: impl Into<String>, code: FieldErrorCode, message: String) {
+        self.errors.push(FieldError {
+            field: field.into(),
+            code,
+            message,
+        });
+    }
 
-fn validate_attributes(request: &OnboardingRequest) -> Result<()> {
-    for attr in &request.attributes {
-        if attr.fqn.is_empty() {
-            bail!("Attribute FQN is required");
-        }
-        if !attr.fqn.contains('.') {
-            bail!(
-                "Attribute FQN must be domain-qualified (e.g., 'cbu.name'), got '{}'",
-                attr.fqn
+    /// Check a required FQN field: present and domain-qualified.
+    fn check_fqn(&mut self, field: String, fqn: &str, what: &str, example: &str) {
+        if fqn.is_empty() {
+            self.push(
+                field,
+                FieldErrorCode::Required,
+                format!("{what} FQN is required"),
+            );
+        } else if !fqn.contains('.') {
+            self.push(
+                field,
+                FieldErrorCode::NotDomainQualified,
+                format!("{what} FQN must be domain-qualified (e.g., '{example}'), got '{fqn}'"),
             );
-        }
-        if attr.name.is_empty() {
-            bail!("Attribute name is required for '{}'", attr.fqn);
         }
     }
+}
 
-    let mut seen = std::collections::HashSet::new();
-    for attr in &request.attributes {
-        if !seen.insert(&attr.fqn) {
-            bail!("Duplicate attribute FQN: '{}'", attr.fqn);
-        }
+/// Validate the onboarding request, stopping at the first problem.
+///
+/// Use [`validate_request_report`] to collect every field error.
+pub fn validate_request(request: &OnboardingRequest) -> Result<()> {
+    match validate_request_report(request).errors.into_iter().next() {
+        Some(first) => Err(anyhow!(first.message)),
+        None => Ok(()),
     }
+}
 
-    Ok(())
+/// Validate the onboarding request for structural correctness, reporting
+/// every failing field rather than just the first.
+pub fn validate_request_report(request: &OnboardingRequest) -> OnboardingValidationReport {
+    let mut report = OnboardingValidationReport::default();
+    validate_entity_type(request, &mut report);
+    validate_attributes(request, &mut report);
+    validate_verb_contracts(request, &mut report);
+    validate_evidence_requirements(request, &mut report);
+    report
 }
 
-fn validate_verb_contracts(request: &OnboardingRequest) -> Result<()> {
-    for vc in &request.verb_contracts {
-        if vc.fqn.is_empty() {
-            bail!("Verb contract FQN is required");
-        }
-        if !vc.fqn.contains('.') {
-            bail!(
-                "Verb contract FQN must be domain-qualified (e.g., 'cbu.create'), got '{}'",
-                vc.fqn
+fn validate_entity_type(request: &OnboardingRequest, report: &mut OnboardingValidationReport) {
+    let et = &request.entity_type;
+
+    report.check_fqn(
+        "entity_type.fqn".into(),
+        &et.fqn,
+        "Entity type",
+        "entity.fund",
+    );
+    for (field, value) in [
+        ("name", &et.name),
+        ("domain", &et.domain),
+        ("description", &et.description),
+    ] {
+        if value.is_empty() {
+            report.push(
+                format!("entity_type.{field}"),
+                FieldErrorCode::Required,
+                format!("Entity type {field} is required"),
             );
         }
-        if vc.domain.is_empty() {
-            bail!("Verb contract domain is required for '{}'", vc.fqn);
+    }
+}
+
+fn validate_attributes(request: &OnboardingRequest, report: &mut OnboardingValidationReport) {
+    let mut seen = HashSet::new();
+    for (i, attr) in request.attributes.iter().enumerate() {
+        report.check_fqn(
+            format!("attributes[{i}].fqn"),
+            &attr.fqn,
+            "Attribute",
+            "cbu.name",
+        );
+        if attr.name.is_empty() {
+            report.push(
+                format!("attributes[{i}].name"),
+                FieldErrorCode::Required,
+                format!("Attribute name is required for '{}'", attr.fqn),
+            );
         }
-        if vc.action.is_empty() {
-            bail!("Verb contract action is required for '{}'", vc.fqn);
+        if !attr.fqn.is_empty() && !seen.insert(&attr.fqn) {
+            report.push(
+                format!("attributes[{i}].fqn"),
+                FieldErrorCode::Duplicate,
+                format!("Duplicate attribute FQN: '{}'", attr.fqn),
+            );
         }
     }
+}
 
-    let mut seen = std::collections::HashSet::new();
-    for vc in &request.verb_contracts {
-        if !seen.insert(&vc.fqn) {
-            bail!("Duplicate verb contract FQN: '{}'", vc.fqn);
+fn validate_verb_contracts(request: &OnboardingRequest, report: &mut OnboardingValidationReport) {
+    let mut seen = HashSet::new();
+    for (i, vc) in request.verb_contracts.iter().enumerate() {
+        report.check_fqn(
+            format!("verb_contracts[{i}].fqn"),
+            &vc.fqn,
+            "Verb contract",
+            "cbu.create",
+        );
+        for (field, value) in [("domain", &vc.domain), ("action", &vc.action)] {
+            if value.is_empty() {
+                report.push(
+                    format!("verb_contracts[{i}].{field}"),
+                    FieldErrorCode::Required,
+                    format!("Verb contract {field} is required for '{}'", vc.fqn),
+                );
+            }
+        }
+        if !vc.fqn.is_empty() && !seen.insert(&vc.fqn) {
+            report.push(
+                format!("verb_contracts[{i}].fqn"),
+                FieldErrorCode::Duplicate,
+                format!("Duplicate verb contract FQN: '{}'", vc.fqn),
+            );
         }
     }
-
-    Ok(())
 }
 
-fn validate_evidence_requirements(request: &OnboardingRequest) -> Result<()> {
-    for er in &request.evidence_requirements {
+fn validate_evidence_requirements(
+    request: &OnboardingRequest,
+    report: &mut OnboardingValidationReport,
+) {
+    for (i, er) in request.evidence_requirements.iter().enumerate() {
         if er.fqn.is_empty() {
-            bail!("Evidence requirement FQN is required");
+            report.push(
+                format!("evidence_requirements[{i}].fqn"),
+                FieldErrorCode::Required,
+                "Evidence requirement FQN is required".to_string(),
+            );
         }
         if er.target_entity_type.is_empty() {
-            bail!(
-                "Evidence requirement target_entity_type is required for '{}'",
-                er.fqn
+            report.push(
+                format!("evidence_requirements[{i}].target_entity_type"),
+                FieldErrorCode::Required,
+                format!(
+                    "Evidence requirement target_entity_type is required for '{}'",
+                    er.fqn
+                ),
             );
         }
     }
-
-    Ok(())
 }
 
 #[cfg(test)]
@@ -165,6 +259,29 @@ mod tests {
         }
     }
 
+    fn test_attribute() -> AttributeDefBody {
+        AttributeDefBody {
+            fqn: "test.name".to_string(),
+            name: "Name".to_string(),
+            description: "A name".to_string(),
+            domain: "test".to_string(),
+            data_type: AttributeDataType::String,
+            source: None,
+            constraints: None,
+            sinks: vec![],
+            evidence_grade: sem_os_core::types::EvidenceGrade::None,
+            category: None,
+            validation_rules: None,
+            applicability: None,
+            is_required: None,
+            default_value: None,
+            group_id: None,
+            is_derived: None,
+            derivation_spec_fqn: None,
+            visibility: None,
+        }
+    }
+
     #[test]
     fn test_valid_minimal_request() {
         let req = minimal_request();
@@ -249,4 +366,43 @@ mod tests {
         req.attributes = vec![attr];
         assert!(validate_request(&req).is_ok());
     }
+
+    #[test]
+    fn test_report_lists_each_bad_field() {
+        let mut req = minimal_request();
+        req.entity_type.name = String::new();
+        req.attributes = vec![AttributeDefBody {
+            fqn: "name".to_string(),
+            ..test_attribute()
+        }];
+
+        let report = validate_request_report(&req);
+        assert!(!report.is_valid());
+        assert_eq!(report.errors.len(), 2, "{:?}", report.errors);
+
+        let name = report.errors_for("entity_type.name").next().unwrap();
+        assert_eq!(name.code, FieldErrorCode::Required);
+
+        let fqn = report.errors_for("attributes[0].fqn").next().unwrap();
+        assert_eq!(fqn.code, FieldErrorCode::NotDomainQualified);
+        assert!(fqn.message.contains("got 'name'"));
+    }
+
+    #[test]
+    fn test_report_valid_request() {
+        let report = validate_request_report(&minimal_request());
+        assert!(report.is_valid());
+        assert!(report.errors.is_empty());
+    }
+
+    #[test]
+    fn test_report_flags_duplicate_on_second_occurrence() {
+        let mut req = minimal_request();
+        req.attributes = vec![test_attribute(), test_attribute()];
+
+        let report = validate_request_report(&req);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].field, "attributes[1].fqn");
+        assert_eq!(report.errors[0].code, FieldErrorCode::Duplicate);
+    }
 }
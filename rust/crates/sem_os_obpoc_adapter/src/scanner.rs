@@ -5,7 +5,7 @@
 //! orchestrator remains in `ob-poc/src/sem_reg/scanner.rs` and delegates
 //! to these converters.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use dsl_core::{
     ActionClass as DslActionClass, ArgConfig, CrudOperation, HarmClass as DslHarmClass, VerbConfig,
@@ -365,6 +365,11 @@ fn infer_action_class(verb_name: &str, config: &VerbConfig) -> ActionClass {
 }
 
 /// Infer entity types from verb argument lookup configurations.
+///
+/// Each inferred type also collects the attributes of the verbs that produce
+/// it: column-mapped (`maps_to`) required args become `required_attributes`,
+/// the rest `optional_attributes`. Attributes are unioned across producing
+/// verbs; one that is required by any verb is never listed as optional.
 pub fn infer_entity_types_from_verbs(verbs_config: &VerbsConfig) -> Vec<EntityTypeDefBody> {
     let mut seen: BTreeMap<String, EntityTypeDefBody> = BTreeMap::new();
 
@@ -409,9 +414,47 @@ pub fn infer_entity_types_from_verbs(verbs_config: &VerbsConfig) -> Vec<EntityTy
         }
     }
 
+    let produced = produced_type_attributes(verbs_config);
+    for et in seen.values_mut() {
+        let type_name = et.fqn.split_once('.').map_or(et.fqn.as_str(), |(_, t)| t);
+        if let Some((required, optional)) = produced.get(type_name) {
+            et.required_attributes = required.iter().cloned().collect();
+            et.optional_attributes = optional.difference(required).cloned().collect();
+        }
+    }
+
     seen.into_values().collect()
 }
 
+/// Produced type → (required, optional) attribute FQNs from its producing verbs.
+///
+/// Only args with `maps_to` count — they land on the produced entity's own
+/// table. Attribute FQNs match [`infer_attributes_from_verbs`].
+fn produced_type_attributes(
+    verbs_config: &VerbsConfig,
+) -> BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> {
+    let mut produced: BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> = BTreeMap::new();
+
+    for (domain, domain_config) in &verbs_config.domains {
+        for verb_config in domain_config.verbs.values() {
+            let Some(produces) = &verb_config.produces else {
+                continue;
+            };
+            let (required, optional) = produced.entry(produces.produced_type.clone()).or_default();
+            for arg in verb_config.args.iter().filter(|a| a.maps_to.is_some()) {
+                let fqn = format!("{}.{}", domain, arg.name);
+                if arg.required {
+                    required.insert(fqn);
+                } else {
+                    optional.insert(fqn);
+                }
+            }
+        }
+    }
+
+    produced
+}
+
 /// Infer attributes from verb argument definitions.
 ///
 /// Accepts the inferred entity type defs so that attribute sources can resolve
@@ -1108,6 +1151,97 @@ mod tests {
         assert!(juris.is_some());
     }
 
+    fn mapped_arg(name: &str, required: bool) -> ArgConfig {
+        ArgConfig {
+            name: name.into(),
+            arg_type: ArgType::String,
+            required,
+            maps_to: Some(name.into()),
+            lookup: None,
+            valid_values: None,
+            default: None,
+            description: None,
+            validation: None,
+            fuzzy_check: None,
+            slot_type: None,
+            preferred_roles: vec![],
+        }
+    }
+
+    fn producing_verb(args: Vec<ArgConfig>) -> VerbConfig {
+        VerbConfig {
+            args,
+            produces: Some(VerbProduces {
+                produced_type: "cbu".into(),
+                subtype: None,
+                subtype_from_arg: None,
+                resolved: false,
+                initial_state: None,
+            }),
+            ..sample_verb_config()
+        }
+    }
+
+    #[test]
+    fn test_infer_entity_type_attributes_merged_across_producers() {
+        let cbu_lookup = ArgConfig {
+            maps_to: None,
+            lookup: Some(LookupConfig {
+                table: "cbus".into(),
+                entity_type: Some("cbu".into()),
+                schema: Some("ob-poc".into()),
+                search_key: SearchKeyConfig::Simple("name".into()),
+                primary_key: "cbu_id".into(),
+                resolution_mode: None,
+                scope_key: None,
+                role_filter: None,
+            }),
+            ..mapped_arg("cbu-id", true)
+        };
+
+        let mut verbs = HashMap::new();
+        verbs.insert(
+            "create".into(),
+            producing_verb(vec![mapped_arg("name", true), mapped_arg("status", false)]),
+        );
+        verbs.insert(
+            "amend".into(),
+            producing_verb(vec![
+                cbu_lookup,
+                mapped_arg("name", false),
+                mapped_arg("description", false),
+            ]),
+        );
+        let mut domains = HashMap::new();
+        domains.insert(
+            "cbu".into(),
+            DomainConfig {
+                description: "CBU domain".into(),
+                verbs,
+                dynamic_verbs: vec![],
+                invocation_hints: vec![],
+            },
+        );
+        let config = VerbsConfig {
+            version: "1.0".into(),
+            domains,
+        };
+
+        let entity_types = infer_entity_types_from_verbs(&config);
+        let cbu = entity_types
+            .iter()
+            .find(|e| e.fqn == "cbu.cbu")
+            .expect("cbu entity type inferred from lookup");
+
+        // name is required by create, so amend's optional name is not repeated;
+        // the lookup arg has no maps_to and contributes nothing.
+        assert_eq!(cbu.required_attributes, vec!["cbu.name".to_string()]);
+        assert_eq!(
+            cbu.optional_attributes,
+            vec!["cbu.description".to_string(), "cbu.status".to_string()]
+        );
+    }
+
     #[test]
     fn test_infer_attributes() {
         let mut domains = HashMap::new();
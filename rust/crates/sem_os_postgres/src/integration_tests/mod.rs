@@ -4,3 +4,5 @@
 mod affinity_integration;
 #[cfg(test)]
 mod capital_ownership_integration;
+#[cfg(test)]
+mod outbox_dead_letter_integration;
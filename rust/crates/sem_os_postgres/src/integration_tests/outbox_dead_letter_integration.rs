@@ -0,0 +1,108 @@
+//! Outbox dead-letter — Integration Tests
+//!
+//! Drives a perpetually-failing event through the same claim/fail decision the
+//! `OutboxDispatcher` makes and asserts it is parked after `max_fails`, skipped
+//! by `claim_next()`, listed by `list_dead_letters()`, and claimable again after
+//! `requeue_dead_letter()`.
+//!
+//! Requires a running PostgreSQL instance with migrations applied and no other
+//! pending `sem_reg.outbox_events` (claim order is global).
+//!
+//! Run with:
+//! ```sh
+//! DATABASE_URL="postgresql:///data_designer" \
+//!   cargo test -p sem_os_postgres outbox_dead_letter -- --ignored --nocapture
+//! ```
+
+#[cfg(feature = "database")]
+mod integration {
+    use anyhow::Result;
+    use chrono::Utc;
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use crate::PgOutboxStore;
+    use sem_os_core::ports::OutboxStore;
+    use sem_os_core::types::{EventId, OutboxEvent, SnapshotSetId};
+
+    const MAX_FAILS: u32 = 3;
+    const CLAIMER: &str = "dead-letter-test";
+
+    async fn store() -> Result<(PgPool, PgOutboxStore)> {
+        let url =
+            std::env::var("DATABASE_URL").unwrap_or_else(|_| "postgresql:///data_designer".into());
+        let pool = PgPool::connect(&url).await?;
+        Ok((pool.clone(), PgOutboxStore::new(pool)))
+    }
+
+    async fn cleanup(pool: &PgPool, event_id: &EventId) -> Result<()> {
+        sqlx::query("DELETE FROM sem_reg.outbox_events WHERE event_id = $1")
+            .bind(event_id.0)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_failing_event_is_dead_lettered_after_max_fails() -> Result<()> {
+        let (pool, outbox) = store().await?;
+        let id = Uuid::new_v4();
+        let event_id = EventId(id);
+        outbox
+            .enqueue(OutboxEvent {
+                event_id: EventId(id),
+                snapshot_set_id: SnapshotSetId(Uuid::new_v4()),
+                outbox_seq: 0,
+                event_type: "test.always_fails".into(),
+                attempt_count: 0,
+                correlation_id: Uuid::new_v4(),
+                payload: serde_json::json!({}),
+                created_at: Utc::now(),
+            })
+            .await?;
+
+        // Same decision as OutboxDispatcher: retry until attempt_count reaches max_fails.
+        for attempt in 1..=MAX_FAILS {
+            let event = outbox
+                .claim_next(CLAIMER)
+                .await?
+                .expect("test event should be claimable");
+            assert_eq!(event.event_id.0, id, "outbox must be drained first");
+            assert_eq!(event.attempt_count, attempt);
+            if event.attempt_count >= MAX_FAILS {
+                outbox
+                    .mark_dead_letter(&event_id, "projection failed")
+                    .await?;
+            } else {
+                outbox
+                    .record_failure(&event_id, "projection failed")
+                    .await?;
+            }
+        }
+
+        assert!(
+            outbox.claim_next(CLAIMER).await?.is_none(),
+            "dead-lettered event must not be claimed"
+        );
+
+        let dead = outbox.list_dead_letters().await?;
+        let parked = dead
+            .iter()
+            .find(|d| d.event.event_id.0 == id)
+            .expect("event should be in the dead-letter set");
+        assert_eq!(parked.event.attempt_count, MAX_FAILS);
+        assert_eq!(parked.last_error.as_deref(), Some("projection failed"));
+
+        assert!(outbox.requeue_dead_letter(&event_id).await?);
+        assert!(!outbox.requeue_dead_letter(&event_id).await?);
+        let event = outbox
+            .claim_next(CLAIMER)
+            .await?
+            .expect("requeued event should be claimable");
+        assert_eq!(event.event_id.0, id);
+        assert_eq!(event.attempt_count, 1);
+
+        cleanup(&pool, &event_id).await
+    }
+}
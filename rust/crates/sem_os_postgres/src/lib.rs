@@ -26,8 +26,9 @@ pub use authoring::{PgAuthoringStore, PgScratchSchemaRunner};
 pub use cleanup::PgCleanupStore;
 pub use ops::{SemOsVerbOp, SemOsVerbOpRegistry};
 pub use store::{
-    PgAuditStore, PgBootstrapAuditStore, PgChangesetStore, PgDomainPackReloadIndexStore,
-    PgEvidenceStore, PgObjectStore, PgOutboxStore, PgProjectionWriter, PgSnapshotStore,
+    AuditQuery, AuditRecord, ChangesetPage, DeadLetter, PgAuditStore, PgBootstrapAuditStore,
+    PgChangesetStore, PgDomainPackReloadIndexStore, PgEvidenceStore, PgObjectStore, PgOutboxStore,
+    PgProjectionWriter, PgSnapshotStore,
 };
 
 use sqlx::PgPool;
@@ -748,6 +748,7 @@ async fn search_entities_internal(
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
         let response = match client.search(request).await {
             Ok(response) => response,
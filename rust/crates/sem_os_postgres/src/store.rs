@@ -775,11 +775,82 @@ pub struct PgChangesetStore {
     pool: PgPool,
 }
 
+/// One page of [`PgChangesetStore::list_changesets_page`].
+#[derive(Debug)]
+pub struct ChangesetPage {
+    pub changesets: Vec<Changeset>,
+    /// Changesets matching the filters across all pages.
+    pub total: i64,
+    pub has_more: bool,
+}
+
 impl PgChangesetStore {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
 
+    /// Filtered changesets ordered by `(created_at, changeset_id)`, so pages
+    /// stay disjoint and stable while new changesets are appended.
+    ///
+    /// Unlike `list_changesets` this is not capped at 200; callers page
+    /// through with `limit`/`offset`.
+    pub async fn list_changesets_page(
+        &self,
+        status: Option<&str>,
+        owner: Option<&str>,
+        scope: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<ChangesetPage> {
+        let total: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*)
+            FROM sem_reg.changesets
+            WHERE ($1::text IS NULL OR status = $1)
+              AND ($2::text IS NULL OR owner_actor_id = $2)
+              AND ($3::text IS NULL OR scope = $3)
+            "#,
+        )
+        .bind(status)
+        .bind(owner)
+        .bind(scope)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| anyhow!(e))?;
+
+        let rows =
+            sqlx::query_as::<_, (Uuid, String, String, String, DateTime<Utc>, DateTime<Utc>)>(
+                r#"
+            SELECT changeset_id, status, owner_actor_id, scope, created_at, updated_at
+            FROM sem_reg.changesets
+            WHERE ($1::text IS NULL OR status = $1)
+              AND ($2::text IS NULL OR owner_actor_id = $2)
+              AND ($3::text IS NULL OR scope = $3)
+            ORDER BY created_at, changeset_id
+            LIMIT $4 OFFSET $5
+            "#,
+            )
+            .bind(status)
+            .bind(owner)
+            .bind(scope)
+            .bind(limit.max(0))
+            .bind(offset.max(0))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| anyhow!(e))?;
+
+        let changesets = rows
+            .into_iter()
+            .map(Self::parse_changeset_row)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let has_more = offset.max(0) + (changesets.len() as i64) < total;
+        Ok(ChangesetPage {
+            changesets,
+            total,
+            has_more,
+        })
+    }
+
     fn parse_changeset_row(
         row: (Uuid, String, String, String, DateTime<Utc>, DateTime<Utc>),
     ) -> std::result::Result<Changeset, SemOsError> {
@@ -1070,10 +1141,82 @@ pub struct PgAuditStore {
     pool: PgPool,
 }
 
+/// Filter for [`PgAuditStore::query`]. Unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct AuditQuery {
+    /// Actor id of the principal that performed the action.
+    pub principal: Option<String>,
+    pub action: Option<String>,
+    /// Inclusive lower bound on the recorded time.
+    pub since: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on the recorded time.
+    pub until: Option<DateTime<Utc>>,
+    /// Maximum entries returned; defaults to [`AuditQuery::DEFAULT_LIMIT`].
+    pub limit: Option<i64>,
+}
+
+impl AuditQuery {
+    pub const DEFAULT_LIMIT: i64 = 500;
+
+    pub fn for_principal(actor_id: impl Into<String>) -> Self {
+        Self {
+            principal: Some(actor_id.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// An audit entry as read back from `sem_reg.decision_records`.
+#[derive(Debug, Clone)]
+pub struct AuditRecord {
+    pub record_id: Uuid,
+    pub principal: String,
+    pub action: String,
+    pub details: serde_json::Value,
+    pub recorded_at: DateTime<Utc>,
+}
+
 impl PgAuditStore {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Audit entries matching `filter`, oldest first.
+    pub async fn query(&self, filter: &AuditQuery) -> Result<Vec<AuditRecord>> {
+        let rows = sqlx::query_as::<_, (Uuid, String, String, serde_json::Value, DateTime<Utc>)>(
+            r#"
+            SELECT decision_id, decided_by, chosen_action, snapshot_manifest, decided_at
+            FROM sem_reg.decision_records
+            WHERE ($1::text IS NULL OR decided_by = $1)
+              AND ($2::text IS NULL OR chosen_action = $2)
+              AND ($3::timestamptz IS NULL OR decided_at >= $3)
+              AND ($4::timestamptz IS NULL OR decided_at < $4)
+            ORDER BY decided_at, decision_id
+            LIMIT $5
+            "#,
+        )
+        .bind(filter.principal.as_deref())
+        .bind(filter.action.as_deref())
+        .bind(filter.since)
+        .bind(filter.until)
+        .bind(filter.limit.unwrap_or(AuditQuery::DEFAULT_LIMIT).max(0))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow!(e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(record_id, principal, action, details, recorded_at)| AuditRecord {
+                    record_id,
+                    principal,
+                    action,
+                    details,
+                    recorded_at,
+                },
+            )
+            .collect())
+    }
 }
 
 #[async_trait]
@@ -1113,10 +1256,97 @@ pub struct PgOutboxStore {
     pool: PgPool,
 }
 
+/// An outbox event parked by [`OutboxStore::mark_dead_letter`].
+#[derive(Debug)]
+pub struct DeadLetter {
+    pub event: OutboxEvent,
+    pub failed_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+}
+
 impl PgOutboxStore {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Highest outbox sequence that is not dead-lettered, or 0 for an empty
+    /// outbox. Dead letters are excluded since they will never be projected.
+    pub async fn head_seq(&self) -> Result<i64> {
+        let head = sqlx::query_scalar::<_, Option<i64>>(
+            r#"
+            SELECT MAX(outbox_seq)
+            FROM sem_reg.outbox_events
+            WHERE failed_at IS NULL
+            "#,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| anyhow!(e))?;
+        Ok(head.unwrap_or(0))
+    }
+
+    /// Dead-lettered events (`failed_at` set, never processed), oldest first.
+    pub async fn list_dead_letters(&self) -> Result<Vec<DeadLetter>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT event_id, outbox_seq, event_type, snapshot_set_id, correlation_id,
+                   attempt_count, payload, created_at, failed_at, last_error
+            FROM sem_reg.outbox_events
+            WHERE failed_at IS NOT NULL
+              AND processed_at IS NULL
+            ORDER BY outbox_seq
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow!(e))?;
+
+        let dead = rows
+            .into_iter()
+            .map(|row| {
+                Ok(DeadLetter {
+                    event: OutboxEvent {
+                        event_id: EventId(row.try_get("event_id")?),
+                        snapshot_set_id: SnapshotSetId(row.try_get("snapshot_set_id")?),
+                        outbox_seq: row.try_get("outbox_seq")?,
+                        event_type: row.try_get("event_type")?,
+                        attempt_count: row.try_get::<i32, _>("attempt_count")? as u32,
+                        correlation_id: row.try_get("correlation_id")?,
+                        payload: row.try_get("payload")?,
+                        created_at: row.try_get("created_at")?,
+                    },
+                    failed_at: row.try_get("failed_at")?,
+                    last_error: row.try_get("last_error")?,
+                })
+            })
+            .collect::<std::result::Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| anyhow!(e))?;
+        Ok(dead)
+    }
+
+    /// Return a dead-lettered event to the queue with a fresh attempt budget.
+    ///
+    /// Returns `false` if `event_id` is not currently dead-lettered.
+    pub async fn requeue_dead_letter(&self, event_id: &EventId) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE sem_reg.outbox_events
+            SET failed_at = NULL,
+                attempt_count = 0,
+                claimed_at = NULL,
+                claimer_id = NULL,
+                claim_timeout_at = NULL
+            WHERE event_id = $1
+              AND failed_at IS NOT NULL
+              AND processed_at IS NULL
+            "#,
+        )
+        .bind(event_id.0)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!(e))?;
+        Ok(result.rows_affected() == 1)
+    }
 }
 
 #[async_trait]
@@ -1316,6 +1546,29 @@ impl PgProjectionWriter {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Last outbox sequence applied to the `active_snapshot_set` projection,
+    /// or 0 if nothing has been projected yet.
+    pub async fn current_watermark(&self) -> Result<i64> {
+        let wm = sqlx::query_scalar::<_, Option<i64>>(
+            r#"
+            SELECT last_outbox_seq
+            FROM sem_reg_pub.projection_watermark
+            WHERE projection_name = 'active_snapshot_set'
+            "#,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow!(e))?
+        .flatten();
+        Ok(wm.unwrap_or(0))
+    }
+
+    /// True when the projection reflects every live outbox event, i.e. the
+    /// watermark has reached [`PgOutboxStore::head_seq`].
+    pub async fn is_caught_up(&self, outbox: &PgOutboxStore) -> Result<bool> {
+        Ok(self.current_watermark().await? >= outbox.head_seq().await?)
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,26 @@
+-- Generation feedback: how users received agent-generated DSL
+-- Append-only: one row per accepted, edited or discarded generation
+
+CREATE TABLE IF NOT EXISTS "ob-poc".generation_feedback (
+    id BIGSERIAL PRIMARY KEY,
+
+    -- Natural-language instruction the DSL was generated from (sanitized)
+    instruction TEXT NOT NULL,
+    -- Case-folded instruction used to group repeats of the same request
+    instruction_key TEXT GENERATED ALWAYS AS (lower(instruction)) STORED,
+
+    generated_dsl TEXT NOT NULL,
+    accepted BOOLEAN NOT NULL,  -- false when the user discarded the generation
+    edited_dsl TEXT,            -- the user's corrected DSL, if they edited it
+    is_correction BOOLEAN NOT NULL,  -- discarded, or edited into something different
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_generation_feedback_created
+    ON "ob-poc".generation_feedback(created_at);
+CREATE INDEX IF NOT EXISTS idx_generation_feedback_instruction
+    ON "ob-poc".generation_feedback(instruction_key);
+
+COMMENT ON TABLE "ob-poc".generation_feedback IS
+'User reactions to generated DSL (accepted, edited, discarded) for pattern learning and fine-tuning exports. Append-only.';
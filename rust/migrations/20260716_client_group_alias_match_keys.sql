@@ -0,0 +1,26 @@
+-- Exact-match keys for client group aliases
+--
+-- Mirrors normalize_alias in ob-semantic-matcher's client_group_resolver:
+-- lowercase, collapse ASCII whitespace runs to one space, trim, and (for the
+-- _bare key) drop ASCII punctuation, the set Rust's is_ascii_punctuation
+-- accepts. Stored so exact matching is an indexed equality lookup.
+
+ALTER TABLE "ob-poc".client_group_alias
+    ADD COLUMN IF NOT EXISTS alias_match_key TEXT GENERATED ALWAYS AS (
+        btrim(regexp_replace(lower(alias), '[ \t\n\f\r]+', ' ', 'g'))
+    ) STORED,
+    ADD COLUMN IF NOT EXISTS alias_match_key_bare TEXT GENERATED ALWAYS AS (
+        btrim(regexp_replace(
+            regexp_replace(lower(alias), '[!-/:-@\[-`{-~]', '', 'g'),
+            '[ \t\n\f\r]+', ' ', 'g'))
+    ) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_cga_alias_match_key
+    ON "ob-poc".client_group_alias(alias_match_key);
+CREATE INDEX IF NOT EXISTS idx_cga_alias_match_key_bare
+    ON "ob-poc".client_group_alias(alias_match_key_bare);
+
+COMMENT ON COLUMN "ob-poc".client_group_alias.alias_match_key IS
+'alias lowercased with whitespace collapsed; matches normalize_alias with strip_punctuation = false';
+COMMENT ON COLUMN "ob-poc".client_group_alias.alias_match_key_bare IS
+'alias_match_key with ASCII punctuation removed; matches normalize_alias with strip_punctuation = true';
@@ -56,7 +56,7 @@ use uuid::Uuid;
 // Re-export all request/response types from agent_types
 pub(crate) use crate::api::agent_types::ExecutionOutcome;
 pub use crate::api::agent_types::{VerbInfo};
-pub(crate) use crate::api::agent_types::{BatchAddProductsRequest, BatchAddProductsResponse, BatchProductResult, CompleteRequest, CompleteResponse, CompleteSubSessionRequest, CompleteSubSessionResponse, CompletionItem, CreateSubSessionRequest, CreateSubSessionResponse, CreateSubSessionType, DomainInfo, DomainsResponse, EntityCandidateResponse, EntityMentionResponse, EvidenceResponse, ExecuteDslRequest, ExtractEntitiesRequest, ExtractEntitiesResponse, GenerateDslRequest, GenerateDslResponse, HealthResponse, MissingArg, OnboardingExecutionResult, OnboardingRequest, OnboardingResponse, ParseDiscriminatorsRequest, ParseDiscriminatorsResponse, ParseDslRequest, ParseDslResponse, ParsedDiscriminators, PipelineStage, RefId, RemainingUnresolvedRef, ReportCorrectionRequest, ReportCorrectionResponse, ResolutionState, ResolutionStats, ResolveByRefIdRequest, ResolveByRefIdResponse, ResolveRefRequest, ResolveRefResponse, SetBindingRequest, SetBindingResponse, SetFocusRequest, SetFocusResponse, SubSessionChatRequest, SubSessionMessage, SubSessionStateResponse, UnresolvedRef, ValidationError, ValidationResult, VerbSurfaceQuery, VocabQuery, VocabResponse, WatchQuery, WatchResponse};
+pub(crate) use crate::api::agent_types::{BatchAddProductsRequest, BatchAddProductsResponse, BatchProductResult, CompleteRequest, CompleteResponse, CompleteSubSessionRequest, CompleteSubSessionResponse, CompletionItem, CreateSubSessionRequest, CreateSubSessionResponse, CreateSubSessionType, DomainInfo, DomainsResponse, EntityCandidateResponse, EntityMentionResponse, EvidenceResponse, ExecuteDslRequest, ExtractEntitiesRequest, ExtractEntitiesResponse, GenerateDslRequest, GenerateDslResponse, HealthResponse, MissingArg, OnboardingExecutionResult, OnboardingRequest, OnboardingResponse, ParseDiscriminatorsRequest, ParseDiscriminatorsResponse, ParseDslRequest, ParseDslResponse, ParsedDiscriminators, PipelineStage, RefId, RemainingUnresolvedRef, ReportCorrectionRequest, ReportCorrectionResponse, ResolutionState, ResolutionStats, ResolveByRefIdRequest, ResolveByRefIdResponse, ResolveRefRequest, ResolveRefResponse, SessionStateQuery, SetBindingRequest, SetBindingResponse, SetFocusRequest, SetFocusResponse, SubSessionChatRequest, SubSessionMessage, SubSessionStateResponse, UnresolvedRef, ValidationError, ValidationResult, VerbSurfaceQuery, VocabQuery, VocabResponse, WatchQuery, WatchResponse};
 
 // ============================================================================
 // State — see agent_state.rs for AgentState and create_agent_router_with_semantic()
@@ -883,6 +883,7 @@ async fn create_session(
     tracing::info!("Workflow focus: {:?}", req.workflow_focus);
 
     let mut session = UnifiedSession::new_for_entity(None, "cbu", None, req.domain_hint.clone());
+    session.id = state.session_manager.next_session_id();
     let session_id = session.id;
     let created_at = session.created_at;
 
@@ -1543,9 +1544,13 @@ async fn resolve_initial_client(
 }
 
 /// GET /api/session/:id - Get session state (creates if not found)
+///
+/// `?history_limit=N` returns only the newest N messages, preceded by one
+/// summary marker for the older ones.
 async fn get_session(
     State(state): State<AgentState>,
     Path(session_id): Path<Uuid>,
+    Query(query): Query<SessionStateQuery>,
 ) -> Json<SessionStateResponse> {
     // Try to get existing session, or create a new one with the requested ID
     let session = {
@@ -1579,13 +1584,21 @@ async fn get_session(
         } else {
             session.messages.iter().cloned().map(|m| m.into()).collect()
         };
+    let message_count = messages.len();
+    let (messages, returned_message_count) = match query.history_limit {
+        Some(limit) if limit < message_count => {
+            (crate::api::session::compact_history(messages, limit), limit)
+        }
+        _ => (messages, message_count),
+    };
 
     Json(SessionStateResponse {
         session_id,
         entity_type: session.entity_type.clone(),
         entity_id: session.entity_id,
         state: session.state.clone().into(),
-        message_count: messages.len(),
+        message_count,
+        returned_message_count,
         combined_dsl: session.run_sheet.combined_dsl(),
         context: session.context.clone(),
         messages,
@@ -1777,7 +1790,8 @@ async fn create_subsession(
     .to_string();
 
     // Create sub-session
-    let child = UnifiedSession::new_subsession(&parent, sub_session_type);
+    let mut child = UnifiedSession::new_subsession(&parent, sub_session_type);
+    child.id = state.session_manager.next_session_id();
     let child_id = child.id;
     let inherited_symbols: Vec<String> = child.inherited_symbols.keys().cloned().collect();
 
@@ -3235,6 +3249,7 @@ async fn clear_session_dsl(
         entity_id,
         state: state_view,
         message_count: messages.len(),
+        returned_message_count: messages.len(),
         combined_dsl: None,
         context,
         messages,
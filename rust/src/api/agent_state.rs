@@ -72,6 +72,27 @@ impl AgentState {
         Arc::new(DeterministicSage)
     }
 
+    /// Session ids are random unless `OB_POC_SESSION_ID_SEED` is set, in which
+    /// case they follow a deterministic sequence (for tests and demos).
+    pub fn build_session_id_generator() -> ob_poc_types::SessionIdGenerator {
+        match std::env::var("OB_POC_SESSION_ID_SEED") {
+            Ok(seed) => match seed.parse::<u64>() {
+                Ok(seed) => {
+                    tracing::info!(seed, "OB_POC_SESSION_ID_SEED set; using seeded session ids");
+                    ob_poc_types::SessionIdGenerator::seeded(seed)
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        error = %error,
+                        "OB_POC_SESSION_ID_SEED is not a u64; using random session ids"
+                    );
+                    ob_poc_types::SessionIdGenerator::default()
+                }
+            },
+            Err(_) => ob_poc_types::SessionIdGenerator::default(),
+        }
+    }
+
     /// Create with semantic verb search (blocks on embedder init ~3-5s)
     ///
     /// This is the primary constructor. Initializes Candle embedder synchronously
@@ -104,7 +125,8 @@ impl AgentState {
         let generation_log = Arc::new(GenerationLogRepository::new(pool.clone()));
         let session_repo = Arc::new(crate::database::SessionRepository::new(pool.clone()));
         let dsl_repo = Arc::new(crate::database::DslRepository::new(pool.clone()));
-        let session_manager = crate::api::session_manager::SessionManager::new(sessions.clone());
+        let session_manager = crate::api::session_manager::SessionManager::new(sessions.clone())
+            .with_session_ids(Arc::new(Self::build_session_id_generator()));
         let sage_engine = Self::build_sage_engine();
 
         // Initialize embedder synchronously (blocks ~3-5s, but only at startup)
@@ -419,6 +441,12 @@ pub async fn create_agent_router_with_semantic_and_repl(
         service_registry,
     )
     .await;
+    if let Some(orchestrator) = &repl_v2_orchestrator {
+        // REPL V2 and agent sessions draw ids from one generator.
+        state.session_manager = state
+            .session_manager
+            .with_session_ids(orchestrator.session_ids());
+    }
     state.repl_v2_orchestrator = repl_v2_orchestrator.clone();
     let router = crate::api::agent_routes::create_agent_router_with_state(state);
     if let Some(orchestrator) = repl_v2_orchestrator {
@@ -191,6 +191,14 @@ pub(crate) fn default_watch_timeout() -> u64 {
     30000
 }
 
+/// Query parameters for session state endpoint
+#[derive(Debug, Deserialize)]
+pub(crate) struct SessionStateQuery {
+    /// Return only the newest N messages, summarising older ones in a marker
+    #[serde(default)]
+    pub history_limit: Option<usize>,
+}
+
 /// Query parameters for verb surface endpoint
 #[derive(Debug, Deserialize)]
 pub(crate) struct VerbSurfaceQuery {
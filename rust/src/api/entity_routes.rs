@@ -156,6 +156,7 @@ async fn search_entities(
         discriminators,
         tenant_id: None,
         cbu_id: None,
+        additional_nicknames: vec![],
     };
 
     let response = client.search(request).await.map_err(|e| {
@@ -397,6 +398,7 @@ async fn search_entities_with_scope(
         discriminators,
         tenant_id: None,
         cbu_id: scope.structure_id.map(|id| id.to_string()), // Pass structure as CBU context
+        additional_nicknames: vec![],
     };
 
     let response = client.search(request).await.map_err(|e| {
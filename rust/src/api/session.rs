@@ -1414,6 +1414,37 @@ pub(crate) enum MessageRole {
     System,
 }
 
+/// Keep the newest `keep_last` messages, replacing everything older with a
+/// single System message that says how many were omitted
+///
+/// The marker takes the timestamp of the newest omitted message so history
+/// stays in time order. Returns `messages` unchanged if nothing is omitted.
+pub(crate) fn compact_history(messages: Vec<ChatMessage>, keep_last: usize) -> Vec<ChatMessage> {
+    let omitted = messages.len().saturating_sub(keep_last);
+    if omitted == 0 {
+        return messages;
+    }
+    let mut messages = messages.into_iter();
+    let older: Vec<ChatMessage> = messages.by_ref().take(omitted).collect();
+    let summary = ChatMessage {
+        id: Uuid::nil(),
+        role: MessageRole::System,
+        content: format!(
+            "[{} earlier message{} omitted]",
+            omitted,
+            if omitted == 1 { "" } else { "s" }
+        ),
+        timestamp: older.last().map_or_else(Utc::now, |m| m.timestamp),
+        intents: None,
+        dsl: None,
+        sage_explain: None,
+        drafter_proposal: None,
+        discovery_bootstrap: None,
+        parked_entries: None,
+    };
+    std::iter::once(summary).chain(messages).collect()
+}
+
 // ============================================================================
 // Session Context
 // ============================================================================
@@ -3001,6 +3032,9 @@ pub(crate) struct SessionStateResponse {
     pub state: SessionState,
     /// Number of messages in the session
     pub message_count: usize,
+    /// Number of those messages included in `messages`; less than
+    /// `message_count` when older history was compacted into a summary marker
+    pub returned_message_count: usize,
     /// Combined DSL (None if no DSL assembled)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub combined_dsl: Option<String>,
@@ -3313,6 +3347,31 @@ mod tests {
         assert_eq!(ctx.resolve_ref("@my_entity"), Some(format!("\"{}\"", id)));
     }
 
+    #[test]
+    fn test_compact_history_keeps_newest_with_one_marker() {
+        let mut session = AgentSession::new(None);
+        for i in 0..5 {
+            session.add_user_message(format!("message {}", i));
+        }
+        let messages = session.messages.clone();
+
+        let compacted = compact_history(messages.clone(), 2);
+        assert_eq!(compacted.len(), 3);
+        let markers: Vec<_> = compacted
+            .iter()
+            .filter(|m| m.role == MessageRole::System)
+            .collect();
+        assert_eq!(markers.len(), 1);
+        assert_eq!(compacted[0].content, "[3 earlier messages omitted]");
+        assert_eq!(compacted[1].content, "message 3");
+        assert_eq!(compacted[2].content, "message 4");
+
+        // Nothing to omit: history is returned as-is, without a marker
+        let untouched = compact_history(messages, 5);
+        assert_eq!(untouched.len(), 5);
+        assert!(untouched.iter().all(|m| m.role == MessageRole::User));
+    }
+
     #[test]
     fn test_add_messages() {
         let mut session = AgentSession::new(None);
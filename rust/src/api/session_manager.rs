@@ -31,6 +31,7 @@
 
 use crate::api::session::SessionStore;
 use crate::session::UnifiedSession;
+use ob_poc_types::SessionIdGenerator;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{watch, RwLock};
@@ -133,6 +134,9 @@ pub(crate) struct SessionManager {
 
     /// Watch channels per session (created on-demand)
     watchers: WatcherMap,
+
+    /// Source of ids for newly created sessions (random unless seeded)
+    session_ids: Arc<SessionIdGenerator>,
 }
 
 impl SessionManager {
@@ -141,9 +145,21 @@ impl SessionManager {
         Self {
             store,
             watchers: Arc::new(RwLock::new(HashMap::new())),
+            session_ids: Arc::new(SessionIdGenerator::default()),
         }
     }
 
+    /// Mint new session ids from `ids` instead of random UUIDs
+    pub(crate) fn with_session_ids(mut self, ids: Arc<SessionIdGenerator>) -> Self {
+        self.session_ids = ids;
+        self
+    }
+
+    /// Mint the id for a new session
+    pub(crate) fn next_session_id(&self) -> Uuid {
+        self.session_ids.next_id()
+    }
+
     /// Get the underlying session store (for backward compatibility)
     pub(crate) fn store(&self) -> &SessionStore {
         &self.store
@@ -337,6 +353,7 @@ impl Clone for SessionManager {
         Self {
             store: self.store.clone(),
             watchers: self.watchers.clone(),
+            session_ids: self.session_ids.clone(),
         }
     }
 }
@@ -573,6 +590,23 @@ mod tests {
         assert!(!manager.exists(id).await);
     }
 
+    #[tokio::test]
+    async fn test_seeded_session_ids() {
+        let manager = SessionManager::new(create_session_store())
+            .with_session_ids(Arc::new(SessionIdGenerator::seeded(42)));
+
+        for expected in [
+            "4d9b3f1e-c9cf-4b1b-b46e-c5e8f3ece91f",
+            "7eb3b394-ac9e-4c29-a791-839b6c9caf25",
+        ] {
+            let mut session = UnifiedSession::new();
+            session.id = manager.next_session_id();
+            manager.insert_session(session).await;
+            assert!(manager.exists(expected.parse().unwrap()).await);
+        }
+        assert_eq!(manager.session_count().await, 2);
+    }
+
     #[tokio::test]
     async fn test_session_update_with_callback() {
         let store = create_session_store();
@@ -52,7 +52,7 @@ use uuid::Uuid;
 
 use ob_poc::dsl_v2::config::loader::ConfigLoader;
 use ob_poc::dsl_v2::execution::{DslExecutor, ExecutionContext, RuntimeVerbRegistry};
-use ob_poc::templates::{ExpansionContext, TemplateExpander, TemplateRegistry};
+use ob_poc::templates::{ExpansionContext, TemplateRegistry};
 
 /// Batch Test Harness for Template Execution
 #[derive(Parser, Debug)]
@@ -319,7 +319,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let context = ExpansionContext::new();
 
         // Expand template
-        let expansion = TemplateExpander::expand(template, &params, &context);
+        let expansion = registry.expand_template(template, &params, &context)?;
 
         if !expansion.missing_params.is_empty() {
             let missing: Vec<_> = expansion.missing_params.iter().map(|p| &p.name).collect();
@@ -26,7 +26,7 @@ use dsl_runtime::{VerbExecutionContext, VerbExecutionOutcome};
 use crate::dsl_v2::execution::DslExecutor;
 use crate::dsl_v2::executor::ExecutionContext;
 use crate::dsl_v2::runtime_registry::runtime_registry;
-use crate::templates::{ExpansionContext, TemplateExpander};
+use crate::templates::{ExpansionContext, ExpansionResult, TemplateRegistry};
 
 // =============================================================================
 // Result types
@@ -112,15 +112,16 @@ impl SemOsVerbOp for TemplateInvoke {
     }
 }
 
-async fn template_invoke_impl(
-    template_id: String,
-    explicit_params: HashMap<String, String>,
-    ctx: &mut ExecutionContext,
-    pool: &sqlx::PgPool,
-) -> Result<TemplateInvokeResult> {
-    let registry = runtime_registry();
-    let template = registry
-        .get_template(&template_id)
+/// Expand `template_id` for `template.invoke`, resolving `include:` lines
+/// through `templates` and rejecting expansions with missing params.
+fn expand_invocation(
+    templates: &TemplateRegistry,
+    template_id: &str,
+    explicit_params: &HashMap<String, String>,
+    ctx: &ExecutionContext,
+) -> Result<ExpansionResult> {
+    let template = templates
+        .get(template_id)
         .ok_or_else(|| anyhow!("Template not found: {}", template_id))?;
 
     let exp_ctx = ExpansionContext {
@@ -130,7 +131,9 @@ async fn template_invoke_impl(
         binding_types: ctx.effective_symbol_types(),
     };
 
-    let expansion = TemplateExpander::expand(template, &explicit_params, &exp_ctx);
+    let expansion = templates
+        .expand_template(template, explicit_params, &exp_ctx)
+        .map_err(|e| anyhow!("Template '{}' expansion failed: {}", template_id, e))?;
 
     if !expansion.missing_params.is_empty() {
         let missing_names: Vec<String> = expansion
@@ -145,6 +148,22 @@ async fn template_invoke_impl(
         ));
     }
 
+    Ok(expansion)
+}
+
+async fn template_invoke_impl(
+    template_id: String,
+    explicit_params: HashMap<String, String>,
+    ctx: &mut ExecutionContext,
+    pool: &sqlx::PgPool,
+) -> Result<TemplateInvokeResult> {
+    let expansion = expand_invocation(
+        runtime_registry().templates(),
+        &template_id,
+        &explicit_params,
+        ctx,
+    )?;
+
     tracing::debug!(
         "template.invoke: expanded '{}' to {} bytes DSL",
         template_id,
@@ -298,6 +317,7 @@ async fn template_batch_impl(
         symbol_types: ctx.symbol_types.clone(),
         parent_symbols: ctx.parent_symbols.clone(),
         parent_symbol_types: ctx.parent_symbol_types.clone(),
+        scopes: ctx.scopes.clone(),
         batch_index: ctx.batch_index,
         audit_user: ctx.audit_user.clone(),
         transaction_id: ctx.transaction_id,
@@ -330,8 +350,13 @@ async fn template_batch_impl(
         envelope_handle: ctx.envelope_handle,
     };
 
-    let batch_executor =
-        BatchExecutor::new(pool.clone(), template.clone(), shared_params, parent_ctx);
+    let batch_executor = BatchExecutor::new(
+        pool.clone(),
+        registry.templates(),
+        template.clone(),
+        shared_params,
+        parent_ctx,
+    );
 
     let batch_result = batch_executor
         .execute_batch(items.clone(), &bind_param, on_error, limit)
@@ -448,4 +473,59 @@ mod tests {
         assert_eq!(result.success_count, 8);
         assert_eq!(result.cbu_ids().len(), 2);
     }
+
+    fn include_registry() -> TemplateRegistry {
+        let mut registry = TemplateRegistry::new();
+        for (id, body) in [
+            (
+                "kyc-setup",
+                "  (kyc-case.create :cbu-id \"$cbu_id\" :case-type \"NEW_CLIENT\")",
+            ),
+            (
+                "onboard-with-kyc",
+                "  (cbu.create :name \"$name\" :as @cbu)\n  include: kyc-setup",
+            ),
+        ] {
+            let yaml = format!(
+                "template: {id}\nversion: 1\nmetadata:\n  name: {id}\n  summary: test\n\
+                 params:\n  cbu_id:\n    type: cbu_ref\n    required: true\n    source: session\n  \
+                 name:\n    type: string\n    required: true\nbody: |\n{body}\n"
+            );
+            registry.register(serde_yaml::from_str(&yaml).unwrap());
+        }
+        registry
+    }
+
+    #[test]
+    fn test_expand_invocation_resolves_includes() {
+        let cbu_id = Uuid::new_v4();
+        let mut ctx = ExecutionContext::new();
+        ctx.bind("cbu", cbu_id);
+        let mut params = HashMap::new();
+        params.insert("name".to_string(), "Acme Fund".to_string());
+
+        let expansion =
+            expand_invocation(&include_registry(), "onboard-with-kyc", &params, &ctx).unwrap();
+
+        assert!(!expansion.dsl.contains("include:"));
+        assert!(expansion.dsl.contains(&format!(":cbu-id \"{}\"", cbu_id)));
+        let program = crate::dsl_v2::parser::parse_program(&expansion.dsl).unwrap();
+        assert_eq!(program.statements.len(), 2);
+    }
+
+    #[test]
+    fn test_expand_invocation_reports_missing_params_from_includes() {
+        let mut params = HashMap::new();
+        params.insert("name".to_string(), "Acme Fund".to_string());
+
+        let err = expand_invocation(
+            &include_registry(),
+            "onboard-with-kyc",
+            &params,
+            &ExecutionContext::new(),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("missing required params: cbu_id"));
+    }
 }
@@ -25,7 +25,7 @@ use super::executor::DslExecutor;
 use super::executor::ExecutionContext;
 #[cfg(feature = "database")]
 use super::parser::parse_program;
-use crate::templates::{ExpansionContext, TemplateDefinition, TemplateExpander};
+use crate::templates::{ExpansionContext, TemplateDefinition, TemplateRegistry};
 
 /// Error handling mode for batch execution
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -222,6 +222,8 @@ impl BatchExecutionResult {
 #[cfg(feature = "database")]
 pub(crate) struct BatchExecutor {
     pool: PgPool,
+    /// Registry used to resolve `include:` lines in the template body
+    templates: &'static TemplateRegistry,
     template: TemplateDefinition,
     shared_params: HashMap<String, String>,
     parent_context: ExecutionContext,
@@ -232,12 +234,14 @@ impl BatchExecutor {
     /// Create a new batch executor
     pub(crate) fn new(
         pool: PgPool,
+        templates: &'static TemplateRegistry,
         template: TemplateDefinition,
         shared_params: HashMap<String, String>,
         parent_context: ExecutionContext,
     ) -> Self {
         Self {
             pool,
+            templates,
             template,
             shared_params,
             parent_context,
@@ -365,7 +369,10 @@ impl BatchExecutor {
         };
 
         // 3. EXPAND template
-        let expansion = TemplateExpander::expand(&self.template, &params, &exp_ctx);
+        let expansion = self
+            .templates
+            .expand_template(&self.template, &params, &exp_ctx)
+            .map_err(|e| anyhow!("Template expansion failed: {}", e))?;
 
         if !expansion.missing_params.is_empty() {
             let missing: Vec<_> = expansion
@@ -5,11 +5,12 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Instant;
 use uuid::Uuid;
 
-/// Result of executing a single DSL step
+/// What a single DSL step did
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub enum StepResult {
+pub enum DslStepOutcome {
     /// Entity was created, here's the PK
     Created { pk: Uuid, entity_type: String },
 
@@ -54,42 +55,42 @@ pub enum StepResult {
     Skipped { reason: String },
 }
 
-impl StepResult {
+impl DslStepOutcome {
     /// Extract PK if this result produced one (for binding)
     pub fn produced_pk(&self) -> Option<Uuid> {
         match self {
-            StepResult::Created { pk, .. } => Some(*pk),
-            StepResult::Updated { pk, .. } => Some(*pk),
-            StepResult::Read { pk, .. } => Some(*pk),
-            StepResult::Custom { produced_pk, .. } => *produced_pk,
-            StepResult::Deleted { .. }
-            | StepResult::NoOp
-            | StepResult::Skipped { .. }
-            | StepResult::List { .. }
-            | StepResult::Linked { .. } => None,
+            DslStepOutcome::Created { pk, .. } => Some(*pk),
+            DslStepOutcome::Updated { pk, .. } => Some(*pk),
+            DslStepOutcome::Read { pk, .. } => Some(*pk),
+            DslStepOutcome::Custom { produced_pk, .. } => *produced_pk,
+            DslStepOutcome::Deleted { .. }
+            | DslStepOutcome::NoOp
+            | DslStepOutcome::Skipped { .. }
+            | DslStepOutcome::List { .. }
+            | DslStepOutcome::Linked { .. } => None,
         }
     }
 
     /// Get entity type if applicable
     pub fn entity_type(&self) -> Option<&str> {
         match self {
-            StepResult::Created { entity_type, .. }
-            | StepResult::Updated { entity_type, .. }
-            | StepResult::Deleted { entity_type, .. }
-            | StepResult::Read { entity_type, .. }
-            | StepResult::List { entity_type, .. } => Some(entity_type),
+            DslStepOutcome::Created { entity_type, .. }
+            | DslStepOutcome::Updated { entity_type, .. }
+            | DslStepOutcome::Deleted { entity_type, .. }
+            | DslStepOutcome::Read { entity_type, .. }
+            | DslStepOutcome::List { entity_type, .. } => Some(entity_type),
             _ => None,
         }
     }
 
     /// Check if this is a create operation
     pub fn is_create(&self) -> bool {
-        matches!(self, StepResult::Created { .. })
+        matches!(self, DslStepOutcome::Created { .. })
     }
 
     /// Check if this is an update operation
     pub fn is_update(&self) -> bool {
-        matches!(self, StepResult::Updated { .. })
+        matches!(self, DslStepOutcome::Updated { .. })
     }
 
     /// Check if this result can produce a binding
@@ -98,6 +99,90 @@ impl StepResult {
     }
 }
 
+/// Result of executing a single DSL step: its outcome plus timing
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StepResult {
+    /// What the step did
+    pub outcome: DslStepOutcome,
+    /// Wall-clock time spent on the step, including retries
+    pub duration_ms: u64,
+    /// How many times the step was attempted (1 = no retries)
+    pub attempts: u32,
+}
+
+impl From<DslStepOutcome> for StepResult {
+    fn from(outcome: DslStepOutcome) -> Self {
+        Self {
+            outcome,
+            duration_ms: 0,
+            attempts: 1,
+        }
+    }
+}
+
+impl StepResult {
+    /// Record the time elapsed since `start` as this step's duration
+    pub fn with_timing(mut self, start: Instant) -> Self {
+        self.duration_ms = start.elapsed().as_millis() as u64;
+        self
+    }
+
+    /// Record how many attempts the step took
+    pub fn with_attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts;
+        self
+    }
+
+    /// Whether the step needed more than one attempt
+    pub fn was_retried(&self) -> bool {
+        self.attempts > 1
+    }
+
+    /// Extract PK if this result produced one (for binding)
+    pub fn produced_pk(&self) -> Option<Uuid> {
+        self.outcome.produced_pk()
+    }
+
+    /// Get entity type if applicable
+    pub fn entity_type(&self) -> Option<&str> {
+        self.outcome.entity_type()
+    }
+
+    /// Check if this is a create operation
+    pub fn is_create(&self) -> bool {
+        self.outcome.is_create()
+    }
+
+    /// Check if this is an update operation
+    pub fn is_update(&self) -> bool {
+        self.outcome.is_update()
+    }
+
+    /// Check if this result can produce a binding
+    pub fn can_bind(&self) -> bool {
+        self.outcome.can_bind()
+    }
+}
+
+/// Step counts for a finished (possibly partially failed) execution
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionSummary {
+    /// Steps that were attempted
+    pub total: usize,
+    /// Steps that completed
+    pub succeeded: usize,
+    /// Steps that recorded an error
+    pub failed: usize,
+    /// Lowest step index that failed, if any
+    pub first_failure_index: Option<usize>,
+}
+
+impl std::fmt::Display for ExecutionSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} of {} steps succeeded", self.succeeded, self.total)
+    }
+}
+
 /// Accumulated results from executing a plan
 #[derive(Clone, Debug, Default)]
 pub(crate) struct ExecutionResults {
@@ -117,7 +202,13 @@ impl ExecutionResults {
     }
 
     /// Record a successful step result
-    pub(crate) fn record_step(&mut self, step_index: usize, result: StepResult, binding: Option<&str>) {
+    pub(crate) fn record_step(
+        &mut self,
+        step_index: usize,
+        result: impl Into<StepResult>,
+        binding: Option<&str>,
+    ) {
+        let result = result.into();
         // Record binding if provided and result produced a PK
         if let Some(bind_name) = binding {
             if let Some(pk) = result.produced_pk() {
@@ -136,6 +227,22 @@ impl ExecutionResults {
         self.errors.push((step_index, error.into()));
     }
 
+    /// Step counts, for rendering e.g. "3 of 5 steps succeeded" after a
+    /// run that continued past errors
+    pub(crate) fn summary(&self) -> ExecutionSummary {
+        ExecutionSummary {
+            total: self.step_results.len() + self.errors.len(),
+            succeeded: self.step_results.len(),
+            failed: self.errors.len(),
+            first_failure_index: self.errors.iter().map(|(index, _)| *index).min(),
+        }
+    }
+
+    /// Whether every attempted step succeeded
+    pub(crate) fn all_succeeded(&self) -> bool {
+        self.errors.is_empty()
+    }
+
     /// Check if execution was successful (no errors)
     pub(crate) fn is_success(&self) -> bool {
         self.errors.is_empty()
@@ -166,6 +273,25 @@ impl ExecutionResults {
         self.binding_types.get(name).map(|s| s.as_str())
     }
 
+    /// Total time spent across all recorded steps
+    pub(crate) fn total_duration_ms(&self) -> u64 {
+        self.step_results.iter().map(|(_, r)| r.duration_ms).sum()
+    }
+
+    /// The step that took longest (first one wins on a tie)
+    pub(crate) fn slowest_step(&self) -> Option<&StepResult> {
+        self.step_results
+            .iter()
+            .map(|(_, r)| r)
+            .reduce(|slowest, r| {
+                if r.duration_ms > slowest.duration_ms {
+                    r
+                } else {
+                    slowest
+                }
+            })
+    }
+
     /// Merge results from another execution
     pub(crate) fn merge(&mut self, other: ExecutionResults) {
         self.step_results.extend(other.step_results);
@@ -175,7 +301,7 @@ impl ExecutionResults {
     }
 
     /// Create a summary string for logging
-    pub(crate) fn summary(&self) -> String {
+    pub(crate) fn describe(&self) -> String {
         let creates = self
             .step_results
             .iter()
@@ -210,14 +336,14 @@ mod tests {
     fn test_step_result_produced_pk() {
         let pk = Uuid::new_v4();
 
-        let created = StepResult::Created {
+        let created = DslStepOutcome::Created {
             pk,
             entity_type: "cbu".to_string(),
         };
         assert_eq!(created.produced_pk(), Some(pk));
         assert!(created.is_create());
 
-        let noop = StepResult::NoOp;
+        let noop = DslStepOutcome::NoOp;
         assert_eq!(noop.produced_pk(), None);
     }
 
@@ -228,7 +354,7 @@ mod tests {
 
         results.record_step(
             0,
-            StepResult::Created {
+            DslStepOutcome::Created {
                 pk,
                 entity_type: "cbu".to_string(),
             },
@@ -255,7 +381,7 @@ mod tests {
         let pk1 = Uuid::new_v4();
         results1.record_step(
             0,
-            StepResult::Created {
+            DslStepOutcome::Created {
                 pk: pk1,
                 entity_type: "cbu".to_string(),
             },
@@ -266,7 +392,7 @@ mod tests {
         let pk2 = Uuid::new_v4();
         results2.record_step(
             1,
-            StepResult::Created {
+            DslStepOutcome::Created {
                 pk: pk2,
                 entity_type: "proper_person".to_string(),
             },
@@ -279,4 +405,84 @@ mod tests {
         assert!(results1.has_binding("fund"));
         assert!(results1.has_binding("person"));
     }
+
+    #[test]
+    fn test_step_result_with_timing() {
+        let start = Instant::now() - std::time::Duration::from_millis(25);
+        let result = StepResult::from(DslStepOutcome::NoOp)
+            .with_timing(start)
+            .with_attempts(2);
+
+        assert!(result.duration_ms >= 25);
+        assert_eq!(result.attempts, 2);
+        assert!(result.was_retried());
+        assert!(!StepResult::from(DslStepOutcome::NoOp).was_retried());
+    }
+
+    #[test]
+    fn test_execution_results_slowest_step() {
+        let mut results = ExecutionResults::new();
+        assert!(results.slowest_step().is_none());
+
+        for (index, duration_ms) in [(0, 40), (1, 120), (2, 120), (3, 5)] {
+            results.record_step(
+                index,
+                StepResult {
+                    outcome: DslStepOutcome::Skipped {
+                        reason: format!("step {}", index),
+                    },
+                    duration_ms,
+                    attempts: 1,
+                },
+                None,
+            );
+        }
+
+        assert_eq!(results.total_duration_ms(), 285);
+        let slowest = results.slowest_step().expect("steps recorded");
+        assert_eq!(slowest.duration_ms, 120);
+        assert!(matches!(
+            &slowest.outcome,
+            DslStepOutcome::Skipped { reason } if reason == "step 1"
+        ));
+    }
+
+    #[test]
+    fn test_execution_summary_all_success() {
+        let mut results = ExecutionResults::new();
+        for index in 0..3 {
+            results.record_step(index, DslStepOutcome::NoOp, None);
+        }
+
+        let summary = results.summary();
+        assert!(results.all_succeeded());
+        assert_eq!(
+            summary,
+            ExecutionSummary {
+                total: 3,
+                succeeded: 3,
+                failed: 0,
+                first_failure_index: None,
+            }
+        );
+        assert_eq!(summary.to_string(), "3 of 3 steps succeeded");
+    }
+
+    #[test]
+    fn test_execution_summary_mixed_run() {
+        let mut results = ExecutionResults::new();
+        results.record_step(0, DslStepOutcome::NoOp, None);
+        results.record_error(3, "later failure");
+        results.record_step(1, DslStepOutcome::NoOp, None);
+        results.record_error(2, "first failure");
+        results.record_step(4, DslStepOutcome::NoOp, None);
+
+        let summary = results.summary();
+        assert!(!results.all_succeeded());
+        assert_eq!(summary.total, 5);
+        assert_eq!(summary.succeeded, 3);
+        assert_eq!(summary.failed, 2);
+        assert_eq!(summary.first_failure_index, Some(2));
+        assert_eq!(summary.to_string(), "3 of 5 steps succeeded");
+    }
 }
@@ -449,6 +449,15 @@ impl AtomicExecutionResult {
     }
 }
 
+/// A nested symbol frame opened by [`ExecutionContext::push_scope`]
+#[derive(Debug, Clone, Default)]
+pub struct SymbolScope {
+    /// Bindings made while this frame was innermost
+    pub symbols: HashMap<String, Uuid>,
+    /// Entity types for this frame's bindings
+    pub symbol_types: HashMap<String, String>,
+}
+
 /// Execution context holding state during DSL execution
 ///
 /// Supports parent/child hierarchy for batch execution where each iteration
@@ -464,6 +473,11 @@ pub struct ExecutionContext {
     pub parent_symbols: HashMap<String, Uuid>,
     /// Parent symbol types
     pub parent_symbol_types: HashMap<String, String>,
+    /// Nested `let` scopes, innermost last
+    ///
+    /// While a scope is open, new bindings land in it and shadow outer ones;
+    /// popping the scope discards them.
+    pub scopes: Vec<SymbolScope>,
     /// JSON bindings for complex data (e.g., GLEIF discovery results)
     /// Used when operations need to pass structured data between verb calls
     pub json_bindings: HashMap<String, JsonValue>,
@@ -617,6 +631,7 @@ impl Default for ExecutionContext {
             symbol_types: HashMap::new(),
             parent_symbols: HashMap::new(),
             parent_symbol_types: HashMap::new(),
+            scopes: Vec::new(),
             json_bindings: HashMap::new(),
             batch_index: None,
             audit_user: None,
@@ -664,16 +679,34 @@ impl ExecutionContext {
         }
     }
 
-    /// Bind a symbol to a UUID value
+    /// Bind a symbol to a UUID value (in the innermost open scope, if any)
     pub fn bind(&mut self, name: &str, value: Uuid) {
-        self.symbols.insert(name.to_string(), value);
+        match self.scopes.last_mut() {
+            Some(scope) => scope.symbols.insert(name.to_string(), value),
+            None => self.symbols.insert(name.to_string(), value),
+        };
     }
 
-    /// Bind a symbol with its entity type
+    /// Bind a symbol with its entity type (in the innermost open scope, if any)
     pub fn bind_typed(&mut self, name: &str, value: Uuid, entity_type: &str) {
-        self.symbols.insert(name.to_string(), value);
-        self.symbol_types
-            .insert(name.to_string(), entity_type.to_string());
+        let (symbols, symbol_types) = match self.scopes.last_mut() {
+            Some(scope) => (&mut scope.symbols, &mut scope.symbol_types),
+            None => (&mut self.symbols, &mut self.symbol_types),
+        };
+        symbols.insert(name.to_string(), value);
+        symbol_types.insert(name.to_string(), entity_type.to_string());
+    }
+
+    /// Open a nested scope, as for a template `let` block
+    pub fn push_scope(&mut self) {
+        self.scopes.push(SymbolScope::default());
+    }
+
+    /// Close the innermost scope, discarding its bindings
+    ///
+    /// No-op when no scope is open; the base symbol table is never popped.
+    pub fn pop_scope(&mut self) {
+        self.scopes.pop();
     }
 
     /// Bind a JSON value to a symbol (for complex data like discovery results)
@@ -691,13 +724,18 @@ impl ExecutionContext {
             .map_err(|e| anyhow::anyhow!("Failed to deserialize JSON binding @{}: {}", name, e))
     }
 
-    /// Resolve a symbol reference, checking local scope first then parent
+    /// Resolve a symbol reference, walking open scopes innermost-first, then
+    /// local symbols, then parent
     pub fn resolve(&self, name: &str) -> Option<Uuid> {
-        // 1. Check local symbols first
+        // 1. Check open scopes, innermost first
+        if let Some(pk) = self.scopes.iter().rev().find_map(|s| s.symbols.get(name)) {
+            return Some(*pk);
+        }
+        // 2. Then local symbols
         if let Some(pk) = self.symbols.get(name) {
             return Some(*pk);
         }
-        // 2. Fall back to parent symbols
+        // 3. Fall back to parent symbols
         if let Some(pk) = self.parent_symbols.get(name) {
             return Some(*pk);
         }
@@ -706,17 +744,23 @@ impl ExecutionContext {
 
     /// Get the entity type for a binding
     pub fn get_binding_type(&self, name: &str) -> Option<&str> {
-        // Check local first, then parent
-        self.symbol_types
-            .get(name)
+        // Check open scopes first, then local, then parent
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|s| s.symbol_types.get(name))
+            .or_else(|| self.symbol_types.get(name))
             .or_else(|| self.parent_symbol_types.get(name))
             .map(|s| s.as_str())
     }
 
-    /// Get all effective bindings (local + parent, local wins on conflict)
+    /// Get all effective bindings (parent < local < open scopes, inner wins on conflict)
     pub fn effective_symbols(&self) -> HashMap<String, Uuid> {
         let mut result = self.parent_symbols.clone();
         result.extend(self.symbols.clone());
+        for scope in &self.scopes {
+            result.extend(scope.symbols.clone());
+        }
         result
     }
 
@@ -724,6 +768,9 @@ impl ExecutionContext {
     pub fn effective_symbol_types(&self) -> HashMap<String, String> {
         let mut result = self.parent_symbol_types.clone();
         result.extend(self.symbol_types.clone());
+        for scope in &self.scopes {
+            result.extend(scope.symbol_types.clone());
+        }
         result
     }
 
@@ -747,6 +794,8 @@ impl ExecutionContext {
             symbol_types: HashMap::new(),
             parent_symbols: self.effective_symbols(),
             parent_symbol_types: self.effective_symbol_types(),
+            // Open scopes are folded into the parent bindings above
+            scopes: Vec::new(),
             json_bindings: self.json_bindings.clone(),
             batch_index: Some(index),
             audit_user: self.audit_user.clone(),
@@ -3386,6 +3435,36 @@ mod tests {
         assert_eq!(ctx.resolve("nonexistent"), None);
     }
 
+    #[test]
+    fn test_execution_context_inner_scope_binding_dropped_on_pop() {
+        let mut ctx = ExecutionContext::new();
+        ctx.push_scope();
+        ctx.bind_typed("inner", Uuid::new_v4(), "entity");
+        assert!(ctx.resolve("inner").is_some());
+        assert_eq!(ctx.get_binding_type("inner"), Some("entity"));
+
+        ctx.pop_scope();
+        assert_eq!(ctx.resolve("inner"), None);
+        assert_eq!(ctx.get_binding_type("inner"), None);
+        assert!(!ctx.symbols.contains_key("inner"));
+    }
+
+    #[test]
+    fn test_execution_context_inner_scope_shadows_outer() {
+        let mut ctx = ExecutionContext::new();
+        let outer = Uuid::new_v4();
+        let inner = Uuid::new_v4();
+        ctx.bind("cbu", outer);
+
+        ctx.push_scope();
+        ctx.bind("cbu", inner);
+        assert_eq!(ctx.resolve("cbu"), Some(inner));
+        assert_eq!(ctx.effective_symbols().get("cbu"), Some(&inner));
+
+        ctx.pop_scope();
+        assert_eq!(ctx.resolve("cbu"), Some(outer));
+    }
+
     // ── T0.2 (EOP-PLAN-CONTROLPLANE-001, closes C-027 divergence) ──────────
     //
     // Table-driven coverage of the five `LifecycleFailOpenClass`es crossed
@@ -3439,6 +3518,7 @@ mod tests {
                 lifecycle,
                 policy: None,
                 phase_tags: vec![],
+                invocation_phrases: vec![],
             }
         }
 
@@ -3822,6 +3822,7 @@ impl GenericCrudExecutor {
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let response = client
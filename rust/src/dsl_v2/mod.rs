@@ -168,7 +168,7 @@ pub(crate) use display_nouns::{
 };
 pub(crate) use domain_context::{ActiveDomain, DomainContext, IterationContext};
 pub(crate) use enrichment::{enrich_program, EnrichmentError, EnrichmentResult};
-pub use execution_result::{StepResult};
+pub use execution_result::{DslStepOutcome, ExecutionSummary, StepResult};
 pub(crate) use execution_result::{ExecutionResults};
 #[cfg(feature = "database")]
 pub(crate) use idempotency::{compute_idempotency_key, IdempotencyManager};
@@ -350,7 +350,7 @@ impl ToolHandlers {
 
     /// Expand template for current batch item
     pub(super) async fn batch_expand_current(&self, args: Value) -> Result<Value> {
-        use crate::templates::{ExpansionContext, TemplateExpander, TemplateRegistry};
+        use crate::templates::{ExpansionContext, TemplateRegistry};
         use std::path::Path;
 
         let sessions = self.require_sessions()?;
@@ -420,7 +420,7 @@ impl ToolHandlers {
 
         // Expand template
         let expansion_ctx = ExpansionContext::new();
-        let result = TemplateExpander::expand(template, &params, &expansion_ctx);
+        let result = registry.expand_template(template, &params, &expansion_ctx)?;
 
         Ok(json!({
             "dsl": result.dsl,
@@ -424,6 +424,7 @@ impl ToolHandlers {
             discriminators: std::collections::HashMap::new(),
             tenant_id: None,
             cbu_id: None,
+            additional_nicknames: vec![],
         };
 
         let response = client
@@ -1694,6 +1695,8 @@ impl ToolHandlers {
                 match_method: ob_semantic_matcher::MatchMethod::Semantic,
                 category: "mcp".to_string(),
                 is_agent_bound: true,
+                tier: ob_semantic_matcher::MatcherConfig::default().tier(v.score),
+                matched_code: None,
             });
 
             match feedback_svc
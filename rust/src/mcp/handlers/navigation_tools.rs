@@ -461,7 +461,7 @@ impl ToolHandlers {
         }
 
         // Expand template
-        let result = TemplateExpander::expand(template, &explicit_params, &context);
+        let result = registry.expand_template(template, &explicit_params, &context)?;
 
         // Format missing params prompt if any
         let prompt = if result.missing_params.is_empty() {
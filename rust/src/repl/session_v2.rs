@@ -136,7 +136,11 @@ impl ReplSessionV2 {
     /// assert!(matches!(session.state, ReplStateV2::ScopeGate { .. }));
     /// ```
     pub(crate) fn new() -> Self {
-        let id = Uuid::new_v4();
+        Self::with_id(Uuid::new_v4())
+    }
+
+    /// Create a new session in `ScopeGate` with a caller-minted id.
+    pub(crate) fn with_id(id: Uuid) -> Self {
         let now = Utc::now();
         Self {
             id,
@@ -243,6 +243,8 @@ pub struct ReplOrchestratorV2 {
     /// abandoned sessions that are never revisited. A background sweeper or
     /// hard capacity policy remains follow-up work.
     session_ttl: chrono::Duration,
+    /// Source of ids for new sessions, shared with the agent `SessionManager`.
+    session_ids: Arc<ob_poc_types::SessionIdGenerator>,
     executor: Arc<dyn DslExecutor>,
     executor_v2: Option<Arc<dyn DslExecutorV2>>,
     /// Phase 5: Session persistence for durable execution / human gates.
@@ -319,6 +321,7 @@ impl ReplOrchestratorV2 {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             persistence_versions: Arc::new(RwLock::new(HashMap::new())),
             session_ttl: chrono::Duration::hours(24),
+            session_ids: Arc::new(ob_poc_types::SessionIdGenerator::default()),
             executor,
             executor_v2: None,
             #[cfg(feature = "database")]
@@ -615,6 +618,17 @@ impl ReplOrchestratorV2 {
         self
     }
 
+    /// Mint new session ids from `ids` instead of random UUIDs.
+    pub fn with_session_ids(mut self, ids: Arc<ob_poc_types::SessionIdGenerator>) -> Self {
+        self.session_ids = ids;
+        self
+    }
+
+    /// The generator new sessions take their ids from.
+    pub fn session_ids(&self) -> Arc<ob_poc_types::SessionIdGenerator> {
+        self.session_ids.clone()
+    }
+
     /// Access the pack router (useful for tests and introspection).
     pub fn pack_router(&self) -> &PackRouter {
         &self.pack_router
@@ -658,8 +672,8 @@ impl ReplOrchestratorV2 {
 
     /// Create a new session and return its ID.
     pub async fn create_session(&self) -> Uuid {
-        let mut session = ReplSessionV2::new();
-        let id = session.id;
+        let id = self.session_ids.next_id();
+        let mut session = ReplSessionV2::with_id(id);
         session.push_message(
             MessageRole::Assistant,
             crate::api::session::WELCOME_MESSAGE.to_string(),
@@ -671,8 +685,7 @@ impl ReplOrchestratorV2 {
 
     /// Create a session with a specific ID (for unified pipeline routing).
     pub async fn create_session_with_id(&self, id: Uuid) {
-        let mut session = ReplSessionV2::new();
-        session.id = id;
+        let mut session = ReplSessionV2::with_id(id);
         session.push_message(
             MessageRole::Assistant,
             crate::api::session::WELCOME_MESSAGE.to_string(),
@@ -689,8 +702,7 @@ impl ReplOrchestratorV2 {
         is_test: bool,
         initial_workspace: Option<WorkspaceKind>,
     ) {
-        let mut session = ReplSessionV2::new();
-        session.id = id;
+        let mut session = ReplSessionV2::with_id(id);
         session.state = state;
         session.is_test_session = is_test;
         if let Some(ws) = initial_workspace {
@@ -11669,6 +11681,22 @@ definition_of_done:
         assert_eq!(repl_halt_phase(&session, &response), Some(2));
     }
 
+    #[tokio::test]
+    async fn test_create_session_mints_ids_from_shared_generator() {
+        let ids = Arc::new(ob_poc_types::SessionIdGenerator::seeded(7));
+        let orch = ReplOrchestratorV2::new(PackRouter::new(vec![]), Arc::new(NullDslExecutor))
+            .with_session_ids(ids.clone());
+
+        let expected = ob_poc_types::SessionIdGenerator::seeded(7);
+        let id = orch.create_session().await;
+        assert_eq!(id, expected.next_id());
+
+        let session = orch.get_session(id).await.expect("session");
+        assert_eq!(session.runbook.session_id, id);
+        // The caller's handle advances the same sequence.
+        assert_eq!(ids.next_id(), expected.next_id());
+    }
+
     #[test]
     fn test_phase2_gate_response_uses_sem_os_deny_all_message() {
         let orch = ReplOrchestratorV2::new(PackRouter::new(vec![]), Arc::new(NullDslExecutor));
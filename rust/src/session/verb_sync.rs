@@ -1055,6 +1055,7 @@ mod tests {
             lifecycle: None,
             policy: None,
             phase_tags: vec![],
+            invocation_phrases: vec![],
         };
 
         let hash1 = test_compute_hash(&verb);
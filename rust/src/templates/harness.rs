@@ -18,7 +18,7 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::{ExpansionContext, TemplateExpander, TemplateRegistry};
+use super::{ExpansionContext, TemplateRegistry};
 use crate::dsl_v2::planning::{analyse_and_plan, compile, PlanningInput};
 use crate::dsl_v2::syntax::parse_program;
 
@@ -378,7 +378,21 @@ pub async fn run_harness(
         };
 
         // Expand template
-        let expansion = TemplateExpander::expand(template, &explicit_params, &context);
+        let expansion =
+            match template_registry.expand_template(template, &explicit_params, &context) {
+                Ok(expansion) => expansion,
+                Err(e) => {
+                    stats.expansion_incomplete += 1;
+                    stats.execution_skipped += 1;
+                    results.push(expansion_failed(
+                        template_id,
+                        template_name,
+                        primary_entity_type,
+                        e,
+                    ));
+                    continue;
+                }
+            };
 
         let expansion_complete = expansion.missing_params.is_empty();
         if expansion_complete {
@@ -589,7 +603,7 @@ pub async fn run_harness_from_registry() -> Result<HarnessResult, super::Templat
     };
 
     for template in template_registry.list() {
-        let result = test_single_template(template, &sample_params);
+        let result = test_single_template(&template_registry, template, &sample_params);
 
         // Update stats
         stats.total_templates += 1;
@@ -633,8 +647,39 @@ fn get_verb_registry_arc() -> Arc<crate::dsl_v2::runtime_registry::RuntimeVerbRe
     Arc::new(RuntimeVerbRegistry::from_config(&config))
 }
 
+/// Result for a template whose body could not be expanded at all
+fn expansion_failed(
+    template_id: String,
+    template_name: String,
+    primary_entity_type: Option<String>,
+    error: super::TemplateError,
+) -> TemplateTestResult {
+    TemplateTestResult {
+        template_id,
+        template_name,
+        primary_entity_type,
+        expansion_success: false,
+        expansion_complete: false,
+        missing_params: Vec::new(),
+        dsl: None,
+        parse_success: false,
+        parse_error: None,
+        compile_success: false,
+        compile_error: None,
+        op_count: 0,
+        plan_success: false,
+        plan_error: None,
+        was_reordered: false,
+        diagnostics: vec![error.to_string()],
+        execution_success: None,
+        execution_error: None,
+        bindings: HashMap::new(),
+    }
+}
+
 /// Test a single template through the full pipeline
 fn test_single_template(
+    template_registry: &TemplateRegistry,
     template: &super::TemplateDefinition,
     sample_params: &HashMap<String, HashMap<String, String>>,
 ) -> TemplateTestResult {
@@ -657,7 +702,10 @@ fn test_single_template(
         binding_types: HashMap::new(),
     };
 
-    let expansion = TemplateExpander::expand(template, &explicit_params, &context);
+    let expansion = match template_registry.expand_template(template, &explicit_params, &context) {
+        Ok(expansion) => expansion,
+        Err(e) => return expansion_failed(template_id, template_name, primary_entity_type, e),
+    };
     let expansion_complete = expansion.missing_params.is_empty();
     let missing_params: Vec<String> = expansion
         .missing_params